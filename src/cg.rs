@@ -1,4 +1,4 @@
-use crate::geom::{vector::*, matrix::*};
+use crate::{Vector, Matrix, VectorD};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
@@ -14,6 +14,7 @@ impl Float2 {
 }
 impl Default for Float2 { fn default() -> Self { Self::new(0.0, 0.0) } }
 impl From<Vector<2>> for Float2 { fn from(other: Vector<2>) -> Self { Self { x: other[0], y: other[1] } } }
+impl From<VectorD<2>> for Float2 { fn from(other: VectorD<2>) -> Self { Self::from(other.to_f32()) } }
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Float3 {
@@ -29,6 +30,7 @@ impl Float3 {
 }
 impl Default for Float3 { fn default() -> Self { Self::new(0.0, 0.0, 0.0) } }
 impl From<Vector<3>> for Float3 { fn from(other: Vector<3>) -> Self { Self { x: other[0], y: other[1], z: other[2] } } }
+impl From<VectorD<3>> for Float3 { fn from(other: VectorD<3>) -> Self { Self::from(other.to_f32()) } }
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Float4 {
@@ -45,3 +47,4 @@ impl Float4 {
 }
 impl Default for Float4 { fn default() -> Self { Self::new(0.0, 0.0, 0.0, 0.0) } }
 impl From<Vector<4>> for Float4 { fn from(other: Vector<4>) -> Self { Self { x: other[0], y: other[1], z: other[2], w: other[3] } } }
+impl From<VectorD<4>> for Float4 { fn from(other: VectorD<4>) -> Self { Self::from(other.to_f32()) } }
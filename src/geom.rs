@@ -2,18 +2,21 @@ pub mod spline;
 pub mod transforms;
 pub mod vector;
 pub mod matrix;
+pub mod dvector;
+pub mod dmatrix;
+pub mod sparse;
 pub mod quaternion;
 
 use std::f32::consts::TAU;
-use crate::num::Magnitude;
+use crate::num::{Magnitude, Scalar};
 
-use crate::geom::{vector::*};
+use crate::Vector;
 
-pub fn cross(a: Vector<3>, b: Vector<3>) -> Vector<3> {
-    let x = a[1]*b[2] - a[2]*b[1];
-    let y = a[2]*b[0] - a[0]*b[2];
-    let z = a[0]*b[1] - a[1]*b[0];
-    Vector::<3>::new([x, y, z])
+pub fn cross<T: Scalar>(a: vector::Vector<T, 3>, b: vector::Vector<T, 3>) -> vector::Vector<T, 3> {
+    let x = a[1].clone()*b[2].clone() - a[2].clone()*b[1].clone();
+    let y = a[2].clone()*b[0].clone() - a[0].clone()*b[2].clone();
+    let z = a[0].clone()*b[1].clone() - a[1].clone()*b[0].clone();
+    vector::Vector::<T, 3>::new([x, y, z])
 }
 
 pub fn circum_centre(a: Vector<2>, b: Vector<2>, c: Vector<2>) -> Vector<2> {
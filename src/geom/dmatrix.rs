@@ -0,0 +1,280 @@
+use std::ops::{Add, Sub, Mul, Index, IndexMut};
+use std::fmt;
+use std::fmt::Display;
+use crate::num::Scalar;
+use super::matrix::Matrix;
+use super::dvector::DVector;
+
+///Error for converting between dynamically and statically sized types, or building a DMatrix from data of the wrong
+///size.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DimensionError {
+    ///Wrong number of (rows, columns) - holds the expected and found dimensions.
+    Shape { expected: (usize, usize), found: (usize, usize) },
+    ///Wrong number of components - holds the expected and found lengths.
+    Length { expected: usize, found: usize },
+    ///A row of a different length to the first - holds its index, the expected columns and its length.
+    RaggedRow { row: usize, expected: usize, found: usize },
+}
+impl Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DimensionError::Shape { expected, found } => write!(f, "expected dimensions {:?}, found {:?}", expected, found),
+            DimensionError::Length { expected, found } => write!(f, "expected {} components, found {}", expected, found),
+            DimensionError::RaggedRow { row, expected, found } => write!(f, "expected row {} to have {} columns, found {}", row, expected, found),
+        }
+    }
+}
+impl std::error::Error for DimensionError {}
+
+///Dynamically sized Matrix Type - heap backed, with rows and columns chosen at runtime, components are any Scalar type
+///T. Use Matrix when the dimensions are known at compile time. Stored in row-major format, indexable by usize indices -
+///row then column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DMatrix<T> {
+    rows: usize,
+    cols: usize,
+    ///Row-major Vec of T data, of length rows*cols.
+    data: Vec<T>,
+}
+
+impl<T: Scalar> DMatrix<T> {
+    ///Returns a new DMatrix from row-major data - Err if the data isn't rows*cols long.
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, DimensionError> {
+        match data.len() == rows*cols {
+            true => Ok(Self { rows, cols, data }),
+            false => Err(DimensionError::Length { expected: rows*cols, found: data.len() }),
+        }
+    }
+    ///Returns a new DMatrix from a Vec of rows - Err if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, DimensionError> {
+        let cols = rows.first().map_or(0, |r| r.len());
+        if let Some((row, r)) = rows.iter().enumerate().find(|(_, r)| r.len() != cols) {
+            return Err(DimensionError::RaggedRow { row, expected: cols, found: r.len() });
+        }
+        Ok(Self { rows: rows.len(), cols, data: rows.into_iter().flatten().collect() })
+    }
+    ///Returns a DMatrix with every component zero.
+    pub fn zeros(rows: usize, cols: usize) -> Self { Self { rows, cols, data: vec![T::default(); rows*cols] } }
+    ///Returns the n x n identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut out = Self::zeros(n, n);
+        for i in 0..n { out[i][i] = T::identity(); }
+        out
+    }
+    ///Returns the number of rows.
+    pub fn num_rows(&self) -> usize { self.rows }
+    ///Returns the number of columns.
+    pub fn num_cols(&self) -> usize { self.cols }
+    ///Returns true if the matrix has as many rows as columns.
+    pub fn is_square(&self) -> bool { self.rows == self.cols }
+    ///Returns the row-major data as a slice.
+    pub fn as_slice(&self) -> &[T] { &self.data }
+    ///Returns a single row of the matrix as a DVector, indexed by usize.
+    pub fn row(&self, index: usize) -> DVector<T> {
+        if index >= self.rows { panic!("Out of bounds! Tried to access Row {}/{}", index, self.rows); }
+        DVector::new(self[index].to_vec())
+    }
+    ///Returns a single column of the matrix as a DVector, indexed by usize.
+    pub fn col(&self, index: usize) -> DVector<T> {
+        if index >= self.cols { panic!("Out of bounds! Tried to access Column {}/{}", index, self.cols); }
+        DVector::new((0..self.rows).map(|i| self[i][index].clone()).collect())
+    }
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.cols).flat_map(|j| (0..self.rows).map(move |i| self[i][j].clone())).collect();
+        Self { rows: self.cols, cols: self.rows, data }
+    }
+    ///Matrix multiplication - None unless this matrix has as many columns as the other has rows.
+    pub fn multiply(&self, other: &Self) -> Option<Self> {
+        if self.cols != other.rows { return None; }
+        let mut out = Self::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                for j in 0..other.cols { out[i][j] += self[i][k].clone()*other[k][j].clone(); }
+            }
+        }
+        Some(out)
+    }
+    ///Matrix-vector multiplication - None unless the vector has one component per column.
+    pub fn multiply_vec(&self, other: &DVector<T>) -> Option<DVector<T>> {
+        if self.cols != other.len() { return None; }
+        Some(DVector::new((0..self.rows).map(|i| {
+            self[i].iter().zip(other.data.iter()).fold(T::default(), |a, (x, y)| a + x.clone()*y.clone())
+        }).collect()))
+    }
+    ///Returns a copy of the matrix with every component converted to another scalar type.
+    pub fn convert<U: Scalar + From<T>>(&self) -> DMatrix<U> {
+        DMatrix { rows: self.rows, cols: self.cols, data: self.data.iter().map(|x| U::from(x.clone())).collect() }
+    }
+    ///LU decomposition - may not succeed, tries to triangulate into two triangular matrices, L and U. None if the matrix
+    ///isn't square or needs pivoting.
+    pub fn lu(&self) -> Option<(Self, Self)> {
+        let (l, u, _, _) = self.eliminate(false)?;
+        //Only the last pivot may be zero - every other one is divided by.
+        match (0..self.rows.saturating_sub(1)).all(|i| u[i][i] != T::default()) {
+            true => Some((l, u)),
+            false => None,
+        }
+    }
+    ///LUP decomposition with partial pivoting on absolute values - returns (L, U, P) with P*A = L*U. None if the
+    ///matrix isn't square or is singular, meaning a pivot is exactly zero.
+    pub fn lup(&self) -> Option<(Self, Self, Self)> {
+        let (l, u, perm, _) = self.eliminate(true)?;
+        if (0..self.rows).any(|i| u[i][i] == T::default()) { return None; }
+        let mut p = Self::zeros(self.rows, self.rows);
+        for (i, j) in perm.into_iter().enumerate() { p[i][j] = T::identity(); }
+        Some((l, u, p))
+    }
+    ///Calculates the determinant using LUP decomposition - None if the matrix isn't square.
+    pub fn lup_det(&self) -> Option<T> {
+        let (_, u, _, parity) = self.eliminate(true)?;
+        let det = (0..self.rows).fold(T::identity(), |d, i| d*u[i][i].clone());
+        match parity {
+            true => Some(-det),
+            false => Some(det),
+        }
+    }
+    ///Determinant - None if the matrix isn't square. Uses LUP decomposition, as cofactor expansion is far too slow for
+    ///the sizes DMatrix is meant for.
+    pub fn det(&self) -> Option<T> { self.lup_det() }
+    ///Inverse matrix - None if the matrix isn't square or is singular.
+    pub fn inverse(&self) -> Option<Self> { self.lup_inverse() }
+    ///Forward substitution - solves for Lx = b where L is a unit lower triangular matrix.
+    ///Takes target vector b and calculates x, where the matrix is L.
+    pub fn forward_sub(&self, target: DVector<T>) -> DVector<T> {
+        let mut out = target;
+        for i in 0..out.len() {
+            for j in 0..i {
+                let v = self[i][j].clone()*out[j].clone();
+                out[i] -= v;
+            }
+        }
+        out
+    }
+    ///Backward substitution - solves for Ux = b where U is an upper triangular matrix.
+    ///Takes target vector b and calculates x, where the matrix is U.
+    pub fn back_sub(&self, target: DVector<T>) -> DVector<T> {
+        let mut out = target;
+        for i in (0..out.len()).rev() {
+            for j in i+1..out.len() {
+                let v = self[i][j].clone()*out[j].clone();
+                out[i] -= v;
+            }
+            out[i] /= self[i][i].clone();
+        }
+        out
+    }
+    ///LUP substitution - uses LUP decomposition to solve for Ax = b, where A is the matrix calling this, b is the target
+    ///vector provided as an argument, and x is returned - None if the matrix isn't square, is singular, or b has the
+    ///wrong length.
+    pub fn lup_sub(&self, target: DVector<T>) -> Option<DVector<T>> {
+        if target.len() != self.rows { return None; }
+        let (l, u, p) = self.lup()?;
+        Some(u.back_sub(l.forward_sub(p.multiply_vec(&target)?)))
+    }
+    ///Attempts to calculate an inverse matrix for this matrix using LUP substitution - None if the matrix isn't square
+    ///or is singular.
+    pub fn lup_inverse(&self) -> Option<Self> {
+        let (l, u, p) = self.lup()?;
+        let n = self.rows;
+        let mut out = Self::zeros(n, n);
+        for j in 0..n {
+            let col = u.back_sub(l.forward_sub(p.col(j)));
+            for i in 0..n { out[i][j] = col[i].clone(); }
+        }
+        Some(out)
+    }
+    ///Gaussian elimination shared by the decompositions - returns (L, U, row permutation, odd parity), or None if the
+    ///matrix isn't square. Without pivoting it stops at the first zero pivot, leaving U incomplete.
+    fn eliminate(&self, pivot: bool) -> Option<(Self, Self, Vec<usize>, bool)> {
+        if !self.is_square() { return None; }
+        let n = self.rows;
+        let (mut l, mut u) = (Self::zeros(n, n), self.clone());
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut parity = false;
+        for k in 0..n {
+            if pivot {
+                let best = (k..n).fold(k, |b, i| if u[i][k].absolute() > u[b][k].absolute() { i } else { b });
+                if best != k {
+                    u.swap_rows(k, best);
+                    l.swap_rows(k, best);
+                    perm.swap(k, best);
+                    parity = !parity;
+                }
+            }
+            l[k][k] = T::identity();
+            if u[k][k] == T::default() {
+                match pivot {
+                    true => continue,
+                    false => break,
+                }
+            }
+            for i in k+1..n {
+                let f = u[i][k].clone()/u[k][k].clone();
+                for j in k+1..n {
+                    let v = f.clone()*u[k][j].clone();
+                    u[i][j] -= v;
+                }
+                u[i][k] = T::default();
+                l[i][k] = f;
+            }
+        }
+        Some((l, u, perm, parity))
+    }
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.cols { self.data.swap(a*self.cols + j, b*self.cols + j); }
+    }
+}
+impl<T: Scalar, const R: usize, const C: usize> From<Matrix<T, R, C>> for DMatrix<T> {
+    fn from(m: Matrix<T, R, C>) -> Self { Self { rows: R, cols: C, data: m.data.into_iter().flatten().collect() } }
+}
+impl<T: Scalar, const R: usize, const C: usize> TryFrom<DMatrix<T>> for Matrix<T, R, C> {
+    type Error = DimensionError;
+    fn try_from(m: DMatrix<T>) -> Result<Self, DimensionError> {
+        if m.rows != R || m.cols != C { return Err(DimensionError::Shape { expected: (R, C), found: (m.rows, m.cols) }); }
+        let mut data = m.data.into_iter();
+        Ok(Matrix::new(std::array::from_fn(|_| std::array::from_fn(|_| data.next().unwrap()))))
+    }
+}
+
+impl<T: Scalar> Display for DMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<&[T]> = (0..self.rows).map(|i| &self[i]).collect();
+        write!(f, "{:?}", rows)
+    }
+}
+impl<T> Index<usize> for DMatrix<T> {
+    type Output = [T];
+    fn index(&self, index: usize) -> &[T] { &self.data[index*self.cols..(index + 1)*self.cols] }
+}
+impl<T> IndexMut<usize> for DMatrix<T> {
+    fn index_mut(&mut self, index: usize) -> &mut [T] { &mut self.data[index*self.cols..(index + 1)*self.cols] }
+}
+impl<T: Scalar> Add<DMatrix<T>> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn add(self, other: DMatrix<T>) -> DMatrix<T> {
+        if (self.rows, self.cols) != (other.rows, other.cols) { panic!("Mismatched DMatrix dimensions"); }
+        DMatrix { rows: self.rows, cols: self.cols, data: self.data.into_iter().zip(other.data).map(|(a, b)| a + b).collect() }
+    }
+}
+impl<T: Scalar> Sub<DMatrix<T>> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn sub(self, other: DMatrix<T>) -> DMatrix<T> {
+        if (self.rows, self.cols) != (other.rows, other.cols) { panic!("Mismatched DMatrix dimensions"); }
+        DMatrix { rows: self.rows, cols: self.cols, data: self.data.into_iter().zip(other.data).map(|(a, b)| a - b).collect() }
+    }
+}
+impl<T: Scalar> Mul<DMatrix<T>> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn mul(self, other: DMatrix<T>) -> DMatrix<T> { self.multiply(&other).expect("Mismatched DMatrix dimensions") }
+}
+impl<T: Scalar> Mul<DVector<T>> for DMatrix<T> {
+    type Output = DVector<T>;
+    fn mul(self, other: DVector<T>) -> DVector<T> { self.multiply_vec(&other).expect("Mismatched DMatrix and DVector dimensions") }
+}
+impl<T: Scalar> Mul<T> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn mul(self, other: T) -> DMatrix<T> {
+        DMatrix { rows: self.rows, cols: self.cols, data: self.data.into_iter().map(|a| a*other.clone()).collect() }
+    }
+}
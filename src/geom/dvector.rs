@@ -0,0 +1,104 @@
+use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+use std::fmt;
+use std::fmt::Display;
+use crate::num::{Scalar, Sqroot, Magnitude};
+use super::vector::Vector;
+use super::dmatrix::DimensionError;
+
+///Dynamically sized Vector Type - heap backed, with a length chosen at runtime, components are any Scalar type T. Use
+///Vector when the length is known at compile time. Indexable by usize index of component.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct DVector<T> {
+    ///Vec of T components.
+    pub data: Vec<T>,
+}
+
+impl<T: Scalar> DVector<T> {
+    ///Returns a new DVector from a Vec of T.
+    pub fn new(data: Vec<T>) -> Self { Self { data } }
+    ///Returns a DVector of the given length with every component zero.
+    pub fn zeros(len: usize) -> Self { Self { data: vec![T::default(); len] } }
+    ///Returns the number of components.
+    pub fn len(&self) -> usize { self.data.len() }
+    ///Returns true if the DVector has no components.
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+    ///Dot product with another DVector - panics if the lengths differ.
+    pub fn dot(&self, other: &Self) -> T {
+        self.check_len(other);
+        self.data.iter().zip(other.data.iter()).fold(T::default(), |a, (x, y)| a + x.clone()*y.clone())
+    }
+    ///Utility function for adding vectors - used to implement std::ops. Panics if the lengths differ.
+    pub fn vec_add(&self, other: &Self) -> Self {
+        self.check_len(other);
+        Self::new(self.data.iter().zip(other.data.iter()).map(|(x, y)| x.clone() + y.clone()).collect())
+    }
+    ///Utility function for subtracting vectors - used to implement std::ops. Panics if the lengths differ.
+    pub fn vec_sub(&self, other: &Self) -> Self {
+        self.check_len(other);
+        Self::new(self.data.iter().zip(other.data.iter()).map(|(x, y)| x.clone() - y.clone()).collect())
+    }
+    ///Utility function for multiplying every component by a scalar - used to implement std::ops.
+    pub fn scalar_mul(&self, other: T) -> Self { Self::new(self.data.iter().map(|x| x.clone()*other.clone()).collect()) }
+    ///Sum of all components.
+    pub fn sum(&self) -> T { self.data.iter().fold(T::default(), |a, x| a + x.clone()) }
+    ///Sum of the squares of all components.
+    pub fn sq_sum(&self) -> T { self.dot(self) }
+    ///Returns a copy of the vector with every component converted to another scalar type.
+    pub fn convert<U: Scalar + From<T>>(&self) -> DVector<U> { DVector::new(self.data.iter().map(|x| U::from(x.clone())).collect()) }
+    fn check_len(&self, other: &Self) {
+        if self.len() != other.len() { panic!("Mismatched DVector lengths {} and {}", self.len(), other.len()); }
+    }
+}
+impl<T: Scalar, const L: usize> From<Vector<T, L>> for DVector<T> {
+    fn from(v: Vector<T, L>) -> Self { Self::new(v.data.to_vec()) }
+}
+impl<T: Scalar, const L: usize> TryFrom<DVector<T>> for Vector<T, L> {
+    type Error = DimensionError;
+    fn try_from(v: DVector<T>) -> Result<Self, DimensionError> {
+        let found = v.len();
+        match v.data.try_into() {
+            Ok(data) => Ok(Vector::new(data)),
+            Err(_) => Err(DimensionError::Length { expected: L, found }),
+        }
+    }
+}
+impl<T: Scalar> From<Vec<T>> for DVector<T> {
+    fn from(data: Vec<T>) -> Self { Self::new(data) }
+}
+impl<T: Scalar> Magnitude for DVector<T> {
+    type Output = <T as Sqroot>::Output;
+    fn mag(&self) -> Self::Output { self.sq_sum().sqroot() }
+}
+
+impl<T: Scalar> Display for DVector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.data)
+    }
+}
+impl<T> Index<usize> for DVector<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T { &self.data[index] }
+}
+impl<T> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T { &mut self.data[index] }
+}
+impl<T: Scalar> Add<DVector<T>> for DVector<T> {
+    type Output = DVector<T>;
+    fn add(self, other: DVector<T>) -> Self { self.vec_add(&other) }
+}
+impl<T: Scalar> Sub<DVector<T>> for DVector<T> {
+    type Output = DVector<T>;
+    fn sub(self, other: DVector<T>) -> Self { self.vec_sub(&other) }
+}
+impl<T: Scalar> Mul<T> for DVector<T> {
+    type Output = DVector<T>;
+    fn mul(self, other: T) -> Self { self.scalar_mul(other) }
+}
+impl<T: Scalar> Div<T> for DVector<T> {
+    type Output = DVector<T>;
+    fn div(self, other: T) -> Self { self * (T::identity()/other) }
+}
+impl<T: Scalar> Neg for DVector<T> {
+    type Output = DVector<T>;
+    fn neg(self) -> Self { Self::new(self.data.into_iter().map(|x| -x).collect()) }
+}
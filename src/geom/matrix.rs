@@ -1,19 +1,54 @@
-use std::ops::{Mul, Index, IndexMut};
-use std::fmt; 
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign, Index, IndexMut};
+use std::fmt;
 use std::fmt::Display;
-use crate::num::{Identity, rational::*, Absolute};
+use crate::num::{Identity, Scalar, Float, rational::*, quadratic::QuadraticSurd, nested::NestedSurd, trig};
 use super::vector::*;
+use super::transforms::EulerAxis;
 
-///Matrix Type - R rows, C columns, components are f32. Stored in row-major format,
+///Matrix Type - R rows, C columns, components are any Scalar type T (f32, f64, r32, r64...). Stored in row-major format,
 ///indexable by usize indices - row then column.
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Matrix<const R: usize, const C: usize> {
-    ///2D Array of f32 data.
-    pub data: [[f32; C]; R],
-}
-impl<const R: usize, const C: usize> Matrix<R, C> {
-    ///Returns a new Matrix with R rows and C columns from a correctly shaped array of f32.
-    pub fn new(data: [[f32; C]; R]) -> Self { Self { data } }
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    ///2D Array of T data.
+    pub data: [[T; C]; R],
+}
+///Precise Matrix Type using rational components - R rows, C columns, components are r32. Stored in row-major format,
+///indexable by usize indices - row then column.
+pub type MatrixPrecise<const R: usize, const C: usize> = Matrix<r32, R, C>;
+///Exact Matrix Type using arbitrary precision rational components - R rows, C columns, components are rbig, so
+///determinants and inverses never overflow. Stored in row-major format, indexable by usize indices - row then column.
+pub type MatrixBig<const R: usize, const C: usize> = Matrix<rbig, R, C>;
+///Matrix Type with quadratic surd components a + b*sqrt(n) - used for exact rotations, whose sines and cosines are
+///irrational. R rows, C columns, components are QuadraticSurd<r64>.
+pub type MatrixQuadratic<const R: usize, const C: usize> = Matrix<QuadraticSurd<r64>, R, C>;
+///Matrix Type with nested surd components a + b*sqrt(r), a, b and r in a quadratic field - used for exact rotations by
+///multiples of pi/5, whose sines aren't in a quadratic field. R rows, C columns, components are NestedSurd<r64>.
+pub type MatrixNested<const R: usize, const C: usize> = Matrix<NestedSurd<r64>, R, C>;
+
+///Reasons a symmetric decomposition (Cholesky or LDL^T) can fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecompositionError {
+    ///The matrix is not symmetric.
+    NotSymmetric,
+    ///The pivot at this index was not positive, so the matrix is not positive definite.
+    NotPositiveDefinite(usize),
+    ///The pivot at this index was zero, so the matrix is singular or needs pivoting to factorise.
+    ZeroPivot(usize),
+}
+impl Display for DecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotSymmetric => write!(f, "matrix is not symmetric"),
+            Self::NotPositiveDefinite(i) => write!(f, "matrix is not positive definite (pivot {})", i),
+            Self::ZeroPivot(i) => write!(f, "zero pivot at {}", i),
+        }
+    }
+}
+impl std::error::Error for DecompositionError {}
+
+impl<T: Scalar, const R: usize, const C: usize> Matrix<T, R, C> {
+    ///Returns a new Matrix with R rows and C columns from a correctly shaped array of T.
+    pub fn new(data: [[T; C]; R]) -> Self { Self { data } }
     ///Returns R, the number of rows in this Matrix Type (i.e. returns 3 for a Matrix<3, 4>).
     pub fn num_rows() -> usize { R }
     ///Returns R, the length of a column in this Matrix Type (i.e. returns 3 for a Matrix<3, 4>).
@@ -22,79 +57,61 @@ impl<const R: usize, const C: usize> Matrix<R, C> {
     pub fn num_cols() -> usize { C }
     ///Returns C, the length of a row in this Matrix Type (i.e. returns 4 for a Matrix<3, 4>).
     pub fn row_len() -> usize { R }
-    ///Returns a single row of the Matrix as a Vector<C>, indexed by usize.
-    pub fn row(&self, index: usize) -> Vector<C> {
+    ///Returns a single row of the Matrix as a Vector<T, C>, indexed by usize.
+    pub fn row(&self, index: usize) -> Vector<T, C> {
         if index >= R { panic!("Out of bounds! Tried to access Row {}/{}", index, R); }
-        Vector::<C> { data: self.data[index] }
+        Vector::<T, C> { data: self.data[index].clone() }
     }
-    ///Returns a single column of the Matrix as a Vector<R>, indexed by usize.
-    pub fn col(&self, index: usize) -> Vector<R> {
+    ///Returns a single column of the Matrix as a Vector<T, R>, indexed by usize.
+    pub fn col(&self, index: usize) -> Vector<T, R> {
         if index >= C { panic!("Out of bounds! Tried to access Column {}/{}", index, C); }
-        let mut column = [0.0; R];
-        for i in 0..R {
-            column[i] = self.data[i][index];
-        }
-        Vector::<R> { data: column }
+        let column = std::array::from_fn(|i| self.data[i][index].clone());
+        Vector::<T, R> { data: column }
     }
     ///Utility function for multiplying matrices - as per standard matrix multiplication, to multiply
     ///two matrices of dimensions (A, B) and (C, D), B must equal C. Used for implementing std::ops.
-    pub fn multiply<const D: usize>(&self, other: Matrix<C, D>) -> Matrix<R, D> {
-        let mut out = [[0.0; D]; R];
-        for i in 0..R {
-            for j in 0..D {
-                out[i][j] = self.row(i).dot(other.col(j));
-            }
-        }
-        Matrix::<R, D> { data: out }
+    pub fn multiply<const D: usize>(&self, other: Matrix<T, C, D>) -> Matrix<T, R, D> {
+        let out = std::array::from_fn(|i| std::array::from_fn(|j| self.row(i).dot(other.col(j))));
+        Matrix::<T, R, D> { data: out }
     }
-    pub fn transpose(&self) -> Matrix<C, R> {
-        let mut data = [[0.0; R]; C];
-        for i in 0..R {
-            for j in 0..C {
-                data[j][i] = self.data[i][j];
-            }
-        }
-        Matrix::<C, R>::new(data)
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        let data = std::array::from_fn(|j| std::array::from_fn(|i| self.data[i][j].clone()));
+        Matrix::<T, C, R>::new(data)
     }
-    pub fn change_dimensions<const R0: usize, const C0: usize>(&self) -> Matrix<R0, C0> {
-        let mut data = [[0.0; C0]; R0];
-
-        for row in 0..R0 {
-            if row < R {
-                for col in 0..C0 {
-                    if col < C {
-                        data[row][col] = self.data[row][col];
-                    }
-                }
+    pub fn change_dimensions<const R0: usize, const C0: usize>(&self) -> Matrix<T, R0, C0> {
+        let data = std::array::from_fn(|row| std::array::from_fn(|col| {
+            match row < R && col < C {
+                true => self.data[row][col].clone(),
+                false => T::default(),
             }
-        }
+        }));
 
-        Matrix::<R0, C0>::new(data)
+        Matrix::<T, R0, C0>::new(data)
     }
-    pub fn minor(&self, row: usize, col: usize) -> Option<Matrix<R, C>> {
+    pub fn minor(&self, row: usize, col: usize) -> Option<Matrix<T, R, C>> {
         if R <= 1 || C <= 1 || row >= R || col >= C { return None; }
-        let mut data = [[0.0; C]; R];
+        let mut data: [[T; C]; R] = std::array::from_fn(|_| std::array::from_fn(|_| T::default()));
         let (mut rowc, mut colc) = (0, 0);
         for row0 in 0..R {
             if row0 != row {
                 for col0 in 0..C {
                     if col0 != col {
-                        data[rowc][colc] = self.data[row0][col0]; 
-                        colc += 1; 
+                        data[rowc][colc] = self.data[row0][col0].clone();
+                        colc += 1;
                     }
                 }
                 rowc += 1;
-                colc = 0; 
+                colc = 0;
             }
         }
         Some(Self::new(data))
     }
-    pub fn to_data_vec(&self) -> Vec<f32> {
+    pub fn to_data_vec(&self) -> Vec<T> {
         let mut v = Vec::new();
-        for i in 0..R { for j in 0..C { v.push(self.data[i][j]); } }
+        for i in 0..R { for j in 0..C { v.push(self.data[i][j].clone()); } }
         v
-    } 
-    pub fn minor_vec(vec: &Vec<f32>, dim: (usize, usize), index: (usize, usize)) -> Vec<f32> {
+    }
+    pub fn minor_vec(vec: &Vec<T>, dim: (usize, usize), index: (usize, usize)) -> Vec<T> {
         if dim.0 == 0 || dim.1 == 0 || index.0 >= dim.0 || index.1 >= dim.1 { return Vec::new(); }
         else if dim.0 == 1 && dim.1 == 1 { return vec.clone(); }
         let mut data = Vec::new();
@@ -103,407 +120,647 @@ impl<const R: usize, const C: usize> Matrix<R, C> {
                 for col0 in 0..dim.1 {
                     if col0 != index.1 {
                         let index0 = row0 * dim.0 + col0;
-                        data.push(vec[index0]);
+                        data.push(vec[index0].clone());
                     }
                 }
             }
         }
         data
     }
+    ///Returns a copy of the matrix with every component converted to another scalar type.
+    pub fn convert<U: Scalar + From<T>>(&self) -> Matrix<U, R, C> {
+        let data = std::array::from_fn(|i| std::array::from_fn(|j| U::from(self.data[i][j].clone())));
+        Matrix::<U, R, C>::new(data)
+    }
+    ///Applies a function to every component, returning a new matrix of the results.
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> Matrix<U, R, C> {
+        Matrix { data: std::array::from_fn(|i| std::array::from_fn(|j| f(self.data[i][j].clone()))) }
+    }
+    ///Combines the matching components of two matrices with a function, returning a new matrix of the results.
+    pub fn zip_map<U: Clone, V, F: Fn(T, U) -> V>(&self, other: &Matrix<U, R, C>, f: F) -> Matrix<V, R, C> {
+        Matrix { data: std::array::from_fn(|i| std::array::from_fn(|j| f(self.data[i][j].clone(), other.data[i][j].clone()))) }
+    }
+    ///Folds every component into an accumulator, in row-major order.
+    pub fn fold<A, F: FnMut(A, &T) -> A>(&self, init: A, f: F) -> A { self.data.iter().flatten().fold(init, f) }
+    ///Hadamard product - the component-wise product of two matrices of the same shape.
+    pub fn hadamard(&self, other: &Self) -> Self { self.zip_map(other, |a, b| a * b) }
+    ///Frobenius norm - the square root of the sum of squared components. Lossy for rational types.
+    pub fn frobenius_norm(&self) -> T { self.fold(T::default(), |s, x| s + x.clone()*x.clone()).scalar_sqrt() }
+    ///1-norm - the largest absolute column sum.
+    pub fn norm_1(&self) -> T { self.transpose().norm_inf() }
+    ///Infinity norm - the largest absolute row sum.
+    pub fn norm_inf(&self) -> T {
+        self.data.iter().map(|row| row.iter().fold(T::default(), |s, x| s + x.absolute()))
+            .fold(T::default(), |m, x| if x > m { x } else { m })
+    }
+    ///Kronecker product - the block matrix whose (i, j) block is self[i][j]*other. The output dimensions RO and CO must
+    ///be R*R2 and C*C2, which can't be expressed with const generics yet, so they are checked at runtime.
+    pub fn kronecker<const R2: usize, const C2: usize, const RO: usize, const CO: usize>(&self, other: &Matrix<T, R2, C2>) -> Matrix<T, RO, CO> {
+        if RO != R*R2 || CO != C*C2 { panic!("Kronecker product of {}x{} and {}x{} is {}x{}, not {}x{}", R, C, R2, C2, R*R2, C*C2, RO, CO); }
+        Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| {
+            self.data[i/R2][j/C2].clone() * other.data[i % R2][j % C2].clone()
+        })))
+    }
+    ///Swaps two rows in place.
+    pub fn swap_rows(&mut self, a: usize, b: usize) { self.data.swap(a, b); }
+    ///Swaps two columns in place.
+    pub fn swap_cols(&mut self, a: usize, b: usize) { for row in self.data.iter_mut() { row.swap(a, b); } }
+    ///Returns an iterator over the rows as Vectors.
+    pub fn rows(&self) -> impl Iterator<Item = Vector<T, C>> + '_ { (0..R).map(|i| self.row(i)) }
+    ///Returns an iterator over the columns as Vectors.
+    pub fn cols(&self) -> impl Iterator<Item = Vector<T, R>> + '_ { (0..C).map(|j| self.col(j)) }
 }
-impl<const L: usize> Matrix<L, L> {
-    pub fn det(&self) -> Option<f32> { Self::det_vec(&self.to_data_vec(), L) }
-    pub fn det_vec(vec: &Vec<f32>, dim: usize) -> Option<f32> {
-        let len = vec.len();
-        if len != dim*dim { return None; }
-        if len == 1 { return Some(vec[0]); }
-        else {
-            let mut cofactors = Vec::new();
-            for i in 0..dim {
-                let item = vec[i];
-                let cofactor = Self::minor_vec(vec, (dim, dim), (0, i));
-                cofactors.push((item, Self::det_vec(&cofactor, dim - 1).unwrap()));
-            }
-            let (mut add, mut sum) = (true, 0.0);
-            for cf in cofactors {
-                match add {
-                    true => sum += cf.0*cf.1,
-                    false => sum -= cf.0*cf.1,
-                }
-                add = !add;
-            }
-            Some(sum)
-        }
+impl<const R: usize, const C: usize> From<Matrix<f32, R, C>> for Matrix<r32, R, C> {
+    fn from(v: Matrix<f32, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<r32, R, C>> for Matrix<f32, R, C> {
+    fn from(v: Matrix<r32, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<f32, R, C>> for Matrix<f64, R, C> {
+    fn from(v: Matrix<f32, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<r32, R, C>> for Matrix<rbig, R, C> {
+    fn from(v: Matrix<r32, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<f64, R, C>> for Matrix<rbig, R, C> {
+    fn from(v: Matrix<f64, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<rbig, R, C>> for Matrix<f64, R, C> {
+    fn from(v: Matrix<rbig, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<r32, R, C>> for Matrix<QuadraticSurd<r64>, R, C> {
+    fn from(v: Matrix<r32, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<r32, R, C>> for Matrix<NestedSurd<r64>, R, C> {
+    fn from(v: Matrix<r32, R, C>) -> Self { v.convert() }
+}
+impl<const R: usize, const C: usize> From<Matrix<QuadraticSurd<r64>, R, C>> for Matrix<NestedSurd<r64>, R, C> {
+    fn from(v: Matrix<QuadraticSurd<r64>, R, C>) -> Self { v.convert() }
+}
+impl Matrix<r32, 2, 2> {
+    ///Returns the exact anticlockwise rotation matrix for an angle of angle_pi*pi radians - None unless the angle is a
+    ///multiple of pi/4, pi/6 or pi/10, whose sines and cosines are exact (see trig::cos_pi).
+    pub fn rotation_pi(angle_pi: r32) -> Option<MatrixNested<2, 2>> {
+        let (s, c) = trig::sin_cos_pi(r64::from(angle_pi))?;
+        Some(Matrix::new([[c, -s], [s, c]]))
     }
-    pub fn cofactor(&self) -> Option<Matrix<L, L>> {
-        if L <= 1 { return None; }
-        let mut data = [[0.0; L]; L];
-        let v = self.to_data_vec();
-        for row in 0..L {
-            for col in 0..L {
-                let minor_matrix = Self::minor_vec(&v, (L, L), (row, col));
-                let minor = Self::det_vec(&minor_matrix, L - 1).unwrap();
-                data[row][col] = (-1.0_f32).powf(row as f32 + col as f32)*minor;
+}
+impl Matrix<r32, 3, 3> {
+    ///Returns the exact right-handed rotation matrix around an axis for an angle of angle_pi*pi radians - None unless
+    ///the angle is a multiple of pi/4, pi/6 or pi/10.
+    pub fn rotation_pi(axis: EulerAxis, angle_pi: r32) -> Option<MatrixNested<3, 3>> {
+        let (s, c) = trig::sin_cos_pi(r64::from(angle_pi))?;
+        let (o, i) = (NestedSurd::<r64>::default(), NestedSurd::<r64>::identity());
+        Some(Matrix::new(match axis {
+            EulerAxis::X => [[i, o, o], [o, c, -s], [o, s, c]],
+            EulerAxis::Y => [[c, o, s], [o, i, o], [-s, o, c]],
+            EulerAxis::Z => [[c, -s, o], [s, c, o], [o, o, i]],
+        }))
+    }
+}
+impl<const R: usize, const C: usize> Matrix<r32, R, C> {
+    ///Exact classical Gram-Schmidt - returns a matrix whose columns are mutually orthogonal and span the same spaces as
+    ///the leading columns of this matrix. Columns are not normalised, so every component stays rational; a column that is
+    ///linearly dependent on the ones before it becomes zero.
+    pub fn gram_schmidt(&self) -> Self {
+        let mut cols: Vec<Vector<r32, R>> = Vec::new();
+        for j in 0..C {
+            let mut v = self.col(j);
+            for u in cols.iter() {
+                let uu = u.sq_sum();
+                if uu != r32::default() { v = v - *u * (self.col(j).dot(*u)/uu); }
             }
+            cols.push(v);
         }
-        Some(Matrix::<L, L>::new(data))        
+        Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| cols[j][i])))
     }
-    pub fn adjoint(&self) -> Option<Matrix<L, L>> {
-        match self.cofactor() {
-            Some(m) => Some(m.transpose()),
-            None => None,
+    ///Exact orthonormal Gram-Schmidt - as gram_schmidt, but each column is scaled by the surd 1/|column|. None if the
+    ///columns are linearly dependent.
+    pub fn gram_schmidt_normalised(&self) -> Option<MatrixQuadratic<R, C>> {
+        let g = self.gram_schmidt();
+        let mut cols = Vec::new();
+        for j in 0..C {
+            let col = g.col(j).convert::<r64>();
+            if col.sq_sum() == r64::default() { return None; }
+            cols.push(col.normalised_exact());
         }
+        Some(Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| cols[j][i]))))
     }
-    pub fn inverse(&self) -> Option<Matrix<L, L>> {
-        match self.adjoint() {
-            Some(adjoint) => {
-                let diag = *self * adjoint;
-                let det = diag[0][0];
-                if det.abs() < 0.00001 { return None; }
-                else { return Some(adjoint * (1.0/det)); }
-            },
-            None => None,
-        }
+}
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    ///Householder QR decomposition - returns (Q, R) where Q is an orthogonal R x R matrix and R is upper triangular, with
+    ///Q*R equal to this matrix. Meant for R >= C (square or tall matrices), though any shape decomposes.
+    pub fn qr(&self) -> (Matrix<T, R, R>, Self) {
+        let (q, r, _, _) = self.householder(false);
+        (q, r)
     }
-    pub fn cofactor_vec(vec: &Vec<f32>, dim: usize) -> Option<Vec<f32>> {
-        let len = vec.len();
-        if len != dim*dim { return None; }
-        if len <= 1 { return None; }
-        let mut v = Vec::new();
-        let (mut row, mut col) = (0, 0);
-        for _ in 0..len {
-            let minor_matrix = Self::minor_vec(vec, (L, L), (row, col));
-            col += 1;
-            if col >= dim { col = 0; row += 1; }
-            let minor = Self::det_vec(&minor_matrix, L - 1).unwrap();
-            v.push((-1.0_f32).powf(row as f32 + col as f32)*minor);
+    ///Column pivoted Householder QR - returns (Q, R, P, rank) with Q*R = A*P, where the permutation matrix P brings the
+    ///remaining column of largest norm to the front at each step. This makes the diagonal of R non-increasing in
+    ///magnitude, so the rank is the count of diagonal entries above epsilon * |R[0][0]| * max(R, C).
+    pub fn qr_pivoted(&self) -> (Matrix<T, R, R>, Self, Matrix<T, C, C>, usize) { self.householder(true) }
+    ///Solves the least squares problem - returns the x minimising |Ax - b| using pivoted QR. For a rank deficient matrix
+    ///this is the basic solution, with the components belonging to dependent columns set to zero.
+    pub fn solve_least_squares(&self, b: Vector<T, R>) -> Vector<T, C> {
+        let (q, r, p, rank) = self.qr_pivoted();
+        let c = q.transpose() * b;
+        let mut z = Vector::<T, C>::default();
+        for ii in 0..rank {
+            let i = rank - ii - 1;
+            let mut v = c[i];
+            for j in i+1..rank { v -= r[i][j]*z[j]; }
+            z[i] = v/r[i][i];
         }
-        Some(v)
+        p * z
     }
-    ///LU decomposition - may not succeed, tries to triangulate into two triangular matrices, L and U.
-    pub fn lu(&self) -> Option<(Self, Self)> {
-        let mut l = Self::default(); let mut u = Self::default();
-        for i in 0..L {
-            for k in i..L {
-                let mut sum = 0.0;
-                for j in 0..i {
-                    sum = sum + (l[i][j] * u[j][k]);
+    ///Householder reflections shared by qr and qr_pivoted - returns (Q, R, P, rank).
+    fn householder(&self, pivot: bool) -> (Matrix<T, R, R>, Self, Matrix<T, C, C>, usize) {
+        let (mut q, mut a) = (Matrix::<T, R, R>::identity(), *self);
+        let mut perm: [usize; C] = std::array::from_fn(|i| i);
+        let zero = T::default();
+        for k in 0..R.min(C) {
+            if pivot {
+                let norm = |a: &Self, j: usize| (k..R).fold(zero, |s, i| s + a[i][j]*a[i][j]);
+                let best = (k..C).fold(k, |b, j| if norm(&a, j) > norm(&a, b) { j } else { b });
+                if best != k {
+                    for i in 0..R { a[i].swap(k, best); }
+                    perm.swap(k, best);
                 }
-                u[i][k] = self[i][k] - sum;
             }
-            for k in i..L {
-                if i == k { l[i][i] = 1.0 }
-                else {
-                    let mut sum = 0.0;
-                    for j in 0..i {
-                        sum = sum + (l[k][j] * u[j][i]);
-                    }
-                    if u[i][i] == 0.0 { return None; }
-                    l[k][i] = (self[k][i] - sum)/u[i][i];
-                }
+            let norm = (k..R).fold(zero, |s, i| s + a[i][k]*a[i][k]).scalar_sqrt();
+            if norm == zero { continue; }
+            let alpha = if a[k][k] > zero { -norm } else { norm };
+            let mut v = [zero; R];
+            for i in k..R { v[i] = a[i][k]; }
+            v[k] -= alpha;
+            let vv = (k..R).fold(zero, |s, i| s + v[i]*v[i]);
+            if vv == zero { continue; }
+            let two = T::identity() + T::identity();
+            //Reflect the remaining columns of A, and accumulate Q = Q*H.
+            for j in k..C {
+                let s = two*(k..R).fold(zero, |s, i| s + v[i]*a[i][j])/vv;
+                for i in k..R { a[i][j] -= s*v[i]; }
             }
+            for row in 0..R {
+                let s = two*(k..R).fold(zero, |s, i| s + q[row][i]*v[i])/vv;
+                for i in k..R { q[row][i] -= s*v[i]; }
+            }
+            a[k][k] = alpha;
+            for i in k+1..R { a[i][k] = zero; }
         }
-        Some((l, u))
+        let p = Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| match perm[j] == i {
+            true => T::identity(),
+            false => zero,
+        })));
+        let tol = match R.min(C) {
+            0 => zero,
+            _ => T::epsilon() * a[0][0].absolute() * T::from(R.max(C) as f32),
+        };
+        let rank = (0..R.min(C)).filter(|i| a[*i][*i].absolute() > tol).count();
+        (q, a, p, rank)
     }
-    ///LUP decomposition - may not succeed, tries to decompose into two triangular matrices
-    ///L and U, with a permutation matrix P.
-    pub fn lup(&self) -> Option<(Self, Self, Self)> {
-        let mut p = Self::identity();
-        let mut check = [false; L];
-        for i in 0..L {
-            let col = self.col(i);
-            let mut max = (0, 0.0);
-            for j in 0..L {
-                if !check[j] {
-                    if check[max.0] || col[j] > max.1 { max.0 = j; max.1 = col[j]; }
+}
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    ///Singular value decomposition by one-sided Jacobi rotations - returns (U, S, V^T) where U and V are orthogonal, S
+    ///is zero except for the singular values on its diagonal in descending order, and U*S*V^T equals this matrix.
+    pub fn svd(&self) -> (Matrix<T, R, R>, Self, Matrix<T, C, C>) {
+        if R < C {
+            //A^T = U S V^T, so A = V S^T U^T.
+            let (u, s, vt) = self.transpose().svd();
+            return (vt.transpose(), s.transpose(), u.transpose());
+        }
+        let zero = T::default();
+        let (mut w, mut v) = (*self, Matrix::<T, C, C>::identity());
+        for _ in 0..64 {
+            let mut rotated = false;
+            for p in 0..C {
+                for q in p+1..C {
+                    let (alpha, beta) = (w.col(p).sq_sum(), w.col(q).sq_sum());
+                    let gamma = w.col(p).dot(w.col(q));
+                    if gamma == zero || gamma.absolute() <= T::epsilon() * (alpha*beta).scalar_sqrt() { continue; }
+                    rotated = true;
+                    //Rotate columns p and q of W = A*V until they are orthogonal.
+                    let zeta = (beta - alpha)/(gamma + gamma);
+                    let t = T::identity()/(zeta.absolute() + (zeta*zeta + T::identity()).scalar_sqrt());
+                    let t = if zeta < zero { -t } else { t };
+                    let c = T::identity()/(t*t + T::identity()).scalar_sqrt();
+                    let s = t*c;
+                    for k in 0..R {
+                        let (kp, kq) = (w[k][p], w[k][q]);
+                        w[k][p] = c*kp - s*kq;
+                        w[k][q] = s*kp + c*kq;
+                    }
+                    for k in 0..C {
+                        let (kp, kq) = (v[k][p], v[k][q]);
+                        v[k][p] = c*kp - s*kq;
+                        v[k][q] = s*kp + c*kq;
+                    }
                 }
             }
-            let mut p_row = [0.0; L];
-            p_row[max.0] = 1.0;
-            p[i] = p_row;
-            check[max.0] = true;
+            if !rotated { break; }
         }
-        match (p * *self).lu() {
-            Some(lu) => Some((lu.0, lu.1, p)),
-            None => None,
+        let mut order: [usize; C] = std::array::from_fn(|i| i);
+        let norms: [T; C] = std::array::from_fn(|j| w.col(j).sq_sum().scalar_sqrt());
+        order.sort_by(|i, j| norms[*j].partial_cmp(&norms[*i]).unwrap_or(std::cmp::Ordering::Equal));
+        let mut s = Self::default();
+        let mut cols: Vec<Vector<T, R>> = Vec::new();
+        for (i, j) in order.iter().enumerate() {
+            s[i][i] = norms[*j];
+            if norms[*j] > T::epsilon() * norms[order[0]] { cols.push(w.col(*j)/norms[*j]); }
         }
-    }
-    ///Tries to calculate the determinant using LUP decomposition.
-    pub fn lup_det(&self) -> Option<f32> {
-        match self.lu() {
-            Some(lup) => {
-                let u = lup.1;
-                let mut sum = 1.0;
-                for i in 0..L { sum *= u[i][i]; }
-                Some(sum)
-            },
-            None => None
+        //Columns of U for zero singular values, and beyond C, complete the basis - Gram-Schmidt on the unit vectors.
+        let identity = Matrix::<T, R, R>::identity();
+        for i in 0..R {
+            if cols.len() == R { break; }
+            let mut e = identity.row(i);
+            for _ in 0..2 { for u in cols.iter() { e = e - *u * e.dot(*u); } }
+            let norm = e.sq_sum().scalar_sqrt();
+            if norm > T::from(0.5) { cols.push(e/norm); }
         }
-        
+        let u = Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| cols[j][i])));
+        let vt = Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| v[j][order[i]])));
+        (u, s, vt)
     }
-    ///Forward substitution - solves for Lx = b where L is a lower triangular matrix.
-    ///Takes target vector b and calculates x, where the matrix is L.
-    pub fn forward_sub(&self, target: Vector<L>) -> Vector<L> {
-        let mut out = Vector::<L>::default();
-        out[0] = target[0];
-        for i in 1..L {
-            let row = self.row(i);
-            let v = target[i] - row.dot(out);
-            out[i] = v;
-        }
-        out
+    ///Returns the min(R, C) singular values in descending order.
+    pub fn singular_values(&self) -> Vec<T> {
+        let s = self.svd().1;
+        (0..R.min(C)).map(|i| s[i][i]).collect()
     }
-    ///Backward substitution - solves for Ux = b where U is an upper triangular matrix.
-    ///Takes target vector b and calculates x, where the matrix is U.
-    pub fn back_sub(&self, target: Vector<L>) -> Vector<L> {
-        let mut out = Vector::<L>::default();
-        for ii in 0..L {
-            let i = L - ii - 1;
-            let mut v = target[i];
-            for j in i+1..L {
-                v -= self[i][j]*out[j];
-            }
-            out[i] = v/self[i][i];
-        }
-        out
+    ///Moore-Penrose pseudo-inverse from the SVD - equal to the inverse for a non-singular square matrix, and still
+    ///defined when it is singular or rectangular. Singular values below epsilon * max(R, C) * largest are treated as zero.
+    pub fn pseudo_inverse(&self) -> Matrix<T, C, R> {
+        let (u, s, vt) = self.svd();
+        let tol = match R.min(C) {
+            0 => T::default(),
+            _ => T::epsilon() * T::from(R.max(C) as f32) * s[0][0],
+        };
+        let mut s_inv = Matrix::<T, C, R>::default();
+        for i in 0..R.min(C) { if s[i][i] > tol { s_inv[i][i] = T::identity()/s[i][i]; } }
+        vt.transpose() * s_inv * u.transpose()
     }
-    ///LUP substitution - uses LUP decomposition to solve for Ax = b, where A is the matrix
-    ///calling this, b is the target vector provided as an argument, and x is returned - dependent
-    ///on successful LUP decomposition.
-    pub fn lup_sub(&self, target: Vector<L>) -> Option<Vector<L>> {
-        match self.lup() {
-            Some(lup) => {
-                let p_target = lup.2 * target;
-                let forward = lup.0.forward_sub(p_target);
-                let back = lup.1.back_sub(forward);
-                Some(back)
-            },
-            None => None,
+    ///Numerical rank - the number of singular values greater than tol.
+    pub fn rank(&self, tol: T) -> usize { self.singular_values().iter().filter(|s| **s > tol).count() }
+    ///2-norm condition number - the ratio of the largest to the smallest singular value, infinite for a singular
+    ///matrix. Large values mean solving with this matrix amplifies errors.
+    pub fn condition_number(&self) -> T {
+        let s = self.singular_values();
+        match s.last() {
+            Some(min) if *min == T::default() => T::from(f32::INFINITY),
+            Some(min) => s[0]/ *min,
+            None => T::default(),
         }
     }
-    ///Attempts to calculate an inverse matrix for this matrix using LUP substitution. This will
-    ///fail if a LUP decomposition cannot be found for the matrix.
-    pub fn lup_inverse(&self) -> Option<Matrix<L, L>> {
-        if let Some(d) = self.lup_det() { if d.abs() < 0.00000001 { return None; } }
-        let mut columns = [Vector::<L>::default(); L];
-        let identity = Self::identity();
-        for i in 0..L {
-            let Some(ci) = self.lup_sub(identity.row(i)) else { return None; };
-            columns[i] = ci; //USING ROWS FOR EFFICIENCY - ITS COLUMNS
+    ///Spectral norm (operator 2-norm) - the largest singular value.
+    pub fn spectral_norm(&self) -> T { self.singular_values().first().copied().unwrap_or_default() }
+}
+impl<T: Float, const L: usize> Matrix<T, L, L> {
+    ///Cyclic Jacobi eigenvalue algorithm for symmetric matrices (inertia tensors, covariance matrices...) - returns the
+    ///eigenvalues in ascending order, and an orthonormal matrix whose columns are the matching eigenvectors. Only the
+    ///upper triangle is read, so an unsymmetric matrix is treated as its symmetric upper part.
+    pub fn symmetric_eigen(&self) -> (Vector<T, L>, Self) {
+        let zero = T::default();
+        let mut a = Self::new(std::array::from_fn(|i| std::array::from_fn(|j| match i <= j {
+            true => self[i][j],
+            false => self[j][i],
+        })));
+        let mut v = Self::identity();
+        let tol = T::epsilon() * T::epsilon() * (0..L).fold(zero, |s, i| s + (0..L).fold(zero, |s, j| s + a[i][j]*a[i][j]));
+        for _ in 0..64 {
+            let off = (0..L).fold(zero, |s, i| s + (i+1..L).fold(zero, |s, j| s + a[i][j]*a[i][j]));
+            if off <= tol { break; }
+            for p in 0..L {
+                for q in p+1..L {
+                    if a[p][q] == zero { continue; }
+                    //Rotation angle chosen so that the (p, q) entry becomes zero - t = tan(angle), the smaller root.
+                    let theta = (a[q][q] - a[p][p])/(a[p][q] + a[p][q]);
+                    let t = T::identity()/(theta.absolute() + (theta*theta + T::identity()).scalar_sqrt());
+                    let t = if theta < zero { -t } else { t };
+                    let c = T::identity()/(t*t + T::identity()).scalar_sqrt();
+                    let s = t*c;
+                    for k in 0..L {
+                        let (kp, kq) = (a[k][p], a[k][q]);
+                        a[k][p] = c*kp - s*kq;
+                        a[k][q] = s*kp + c*kq;
+                    }
+                    for k in 0..L {
+                        let (pk, qk) = (a[p][k], a[q][k]);
+                        a[p][k] = c*pk - s*qk;
+                        a[q][k] = s*pk + c*qk;
+                    }
+                    for k in 0..L {
+                        let (kp, kq) = (v[k][p], v[k][q]);
+                        v[k][p] = c*kp - s*kq;
+                        v[k][q] = s*kp + c*kq;
+                    }
+                }
+            }
         }
-        let mut data = [[0.0; L]; L];
-        for i in 0..L {
+        let mut order: [usize; L] = std::array::from_fn(|i| i);
+        order.sort_by(|i, j| a[*i][*i].partial_cmp(&a[*j][*j]).unwrap_or(std::cmp::Ordering::Equal));
+        let values = Vector::new(std::array::from_fn(|i| a[order[i]][order[i]]));
+        let vectors = Self::new(std::array::from_fn(|i| std::array::from_fn(|j| v[i][order[j]])));
+        (values, vectors)
+    }
+    ///Reduces the matrix to upper Hessenberg form (zero below the first subdiagonal) by Householder similarity
+    ///transforms, which preserves the eigenvalues.
+    pub fn hessenberg(&self) -> Self {
+        let (mut a, zero) = (*self, T::default());
+        for k in 0..L.saturating_sub(2) {
+            let norm = (k+1..L).fold(zero, |s, i| s + a[i][k]*a[i][k]).scalar_sqrt();
+            if norm == zero { continue; }
+            let alpha = if a[k+1][k] > zero { -norm } else { norm };
+            let mut v = [zero; L];
+            for i in k+1..L { v[i] = a[i][k]; }
+            v[k+1] -= alpha;
+            let vv = (k+1..L).fold(zero, |s, i| s + v[i]*v[i]);
+            if vv == zero { continue; }
+            let two = T::identity() + T::identity();
             for j in 0..L {
-                data[i][j] = columns[j][i];
+                let s = two*(k+1..L).fold(zero, |s, i| s + v[i]*a[i][j])/vv;
+                for i in k+1..L { a[i][j] -= s*v[i]; }
             }
+            for i in 0..L {
+                let s = two*(k+1..L).fold(zero, |s, j| s + a[i][j]*v[j])/vv;
+                for j in k+1..L { a[i][j] -= s*v[j]; }
+            }
+            for i in k+2..L { a[i][k] = zero; }
         }
-        Some(Self::new(data))
+        a
     }
-}
-
-impl<const A: usize, const B: usize> Display for Matrix<A, B> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut rows = [Vector::<B>::default(); A];
-        for i in 0..A { rows[i] = self.row(i); }
-        write!(f, "{:?}", rows)
+    ///Eigenvalues of a general square matrix by shifted QR iteration on its Hessenberg form - returns them in ascending
+    ///order, or None if any eigenvalue is complex or the iteration fails to converge.
+    pub fn eigenvalues(&self) -> Option<Vector<T, L>> {
+        let (mut a, zero) = (self.hessenberg(), T::default());
+        let mut values = Vec::new();
+        let (mut hi, mut iter) = (L, 0);
+        while hi > 0 {
+            //Find the start of the trailing unreduced block, splitting wherever a subdiagonal entry is negligible.
+            let mut lo = hi - 1;
+            while lo > 0 {
+                let scale = a[lo][lo].absolute() + a[lo-1][lo-1].absolute();
+                if a[lo][lo-1].absolute() <= T::epsilon() * scale || a[lo][lo-1] == zero { break; }
+                lo -= 1;
+            }
+            if lo == hi - 1 {
+                values.push(a[lo][lo]);
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+            if lo == hi - 2 {
+                let (x, y) = Self::eigen2(a[lo][lo], a[lo][lo+1], a[lo+1][lo], a[lo+1][lo+1])?;
+                values.push(x);
+                values.push(y);
+                hi -= 2;
+                iter = 0;
+                continue;
+            }
+            iter += 1;
+            if iter > 64 * L { return None; }
+            let (n, d) = (hi - 1, a[hi-1][hi-1]);
+            //Wilkinson shift - the eigenvalue of the trailing 2x2 block closest to its last entry, with an
+            //exceptional shift every 16 iterations to break cycles.
+            let mu = match (iter % 16 == 0, Self::eigen2(a[n-1][n-1], a[n-1][n], a[n][n-1], d)) {
+                (true, _) => d + a[n][n-1].absolute(),
+                (false, Some((x, y))) => if (x - d).absolute() < (y - d).absolute() { x } else { y },
+                (false, None) => d,
+            };
+            for i in lo..hi { a[i][i] -= mu; }
+            let mut rotations = Vec::new();
+            for k in lo..hi-1 {
+                let (x, y) = (a[k][k], a[k+1][k]);
+                let r = (x*x + y*y).scalar_sqrt();
+                let (c, s) = if r == zero { (T::identity(), zero) } else { (x/r, y/r) };
+                for j in lo..hi {
+                    let (kj, k1j) = (a[k][j], a[k+1][j]);
+                    a[k][j] = c*kj + s*k1j;
+                    a[k+1][j] = c*k1j - s*kj;
+                }
+                rotations.push((c, s));
+            }
+            for (k, (c, s)) in (lo..hi-1).zip(rotations) {
+                for i in lo..hi {
+                    let (ik, ik1) = (a[i][k], a[i][k+1]);
+                    a[i][k] = c*ik + s*ik1;
+                    a[i][k+1] = c*ik1 - s*ik;
+                }
+            }
+            for i in lo..hi { a[i][i] += mu; }
+        }
+        values.sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+        Some(Vector::new(std::array::from_fn(|i| values[i])))
     }
-}
-impl<const A: usize, const B: usize> Default for Matrix<A, B> {
-    fn default() -> Self {
-        Self::new([[0.0; B]; A])
+    ///Eigenvalues of the 2x2 matrix [[a, b], [c, d]] in ascending order - None if they are complex.
+    fn eigen2(a: T, b: T, c: T, d: T) -> Option<(T, T)> {
+        let two = T::identity() + T::identity();
+        let (mean, half) = ((a + d)/two, (a - d)/two);
+        let disc = half*half + b*c;
+        //A repeated eigenvalue can give a discriminant just below zero from rounding.
+        if disc < -T::epsilon() * (mean*mean + half*half + (b*c).absolute()) { return None; }
+        let root = if disc > T::default() { disc.scalar_sqrt() } else { T::default() };
+        Some((mean - root, mean + root))
     }
 }
-impl<const A: usize> Identity for Matrix<A, A> {
-    fn identity() -> Self {
-        let mut data = [[0.0; A]; A];
-        for i in 0..A {
-            data[i][i] = 1.0;
+impl<T: Float, const L: usize> Matrix<T, L, L> {
+    ///Matrix exponential by scaling and squaring with a [6/6] Pade approximant. Closed forms are used for 3x3 skew
+    ///symmetric matrices (giving a rotation, by Rodrigues' formula) and 4x4 twists - a skew symmetric 3x3 block with a
+    ///zero bottom row (giving a rigid transform).
+    pub fn exp(&self) -> Self {
+        if L == 3 {
+            let m: Matrix<T, 3, 3> = self.change_dimensions();
+            if m.is_skew() { return m.exp_so3().change_dimensions(); }
         }
-        Self::new(data)
-    }
-}
-impl<const A: usize, const B: usize> Index<usize> for Matrix<A, B> {
-    type Output = [f32; B];
-    fn index(&self, index: usize) -> &[f32; B] { &self.data[index] }
-}
-impl<const A: usize, const B: usize> IndexMut<usize> for Matrix<A, B> {
-    fn index_mut(&mut self, index: usize) -> &mut [f32; B] { &mut self.data[index] }
-}
-impl<const A: usize, const B: usize, const C: usize> Mul<Matrix<B, C>> for Matrix<A, B> {
-    type Output = Matrix<A, C>;
-    fn mul(self, other: Matrix<B, C>) -> Matrix<A, C> { self.multiply(other) }
-}
-impl<const A: usize, const B: usize> Mul<Vector<B>> for Matrix<A, B> {
-    type Output = Vector<A>;
-    fn mul(self, other: Vector<B>) -> Vector<A> {
-        self.mul(other.as_col()).col(0)
+        if L == 4 {
+            let m: Matrix<T, 4, 4> = self.change_dimensions();
+            if m.is_twist() { return m.exp_se3().change_dimensions(); }
+        }
+        //Scale so the infinity norm is at most 1/2, where the Pade approximant is accurate to double precision.
+        let (half, mut s) = (T::from(0.5), 0);
+        let mut a = *self;
+        while a.norm_inf() > half && s < 1024 {
+            a *= half;
+            s += 1;
+        }
+        let q = 6;
+        let (mut n, mut d) = (Self::identity(), Self::identity());
+        let (mut power, mut c) = (Self::identity(), T::identity());
+        for k in 1..=q {
+            c = c * T::from((q - k + 1) as f32)/T::from((k * (2*q - k + 1)) as f32);
+            power *= a;
+            n += power * c;
+            d += power * if k % 2 == 0 { c } else { -c };
+        }
+        let mut out = d.lup_inverse().expect("Pade denominator of a scaled matrix is always invertible.") * n;
+        for _ in 0..s { out = out * out; }
+        out
     }
-}
-impl<const A: usize, const B: usize> Mul<Matrix<A, B>> for Vector<A> {
-    type Output = Vector<B>;
-    fn mul(self, other: Matrix<A, B>) -> Vector<B> {
-        self.as_row().mul(other).row(0)
+    ///Principal matrix logarithm by inverse scaling and squaring - repeated square roots until the matrix is close to
+    ///the identity, then a Gregory series. None if the matrix is singular or has negative real eigenvalues. Closed
+    ///forms are used for 3x3 rotations and 4x4 rigid transforms, where a rotation by pi gives one of its two logarithms.
+    pub fn log(&self) -> Option<Self> {
+        if L == 3 {
+            let m: Matrix<T, 3, 3> = self.change_dimensions();
+            if m.is_rotation() { return Some(m.log_so3().change_dimensions()); }
+        }
+        if L == 4 {
+            let m: Matrix<T, 4, 4> = self.change_dimensions();
+            if m.is_rigid() { return Some(m.log_se3().change_dimensions()); }
+        }
+        let (identity, mut x, mut k) = (Self::identity(), *self, 0);
+        while (x - identity).norm_inf() > T::from(0.25) {
+            x = x.sqrt()?;
+            k += 1;
+            if k > 64 { return None; }
+        }
+        //log(X) = 2*artanh(Z) = 2*(Z + Z^3/3 + Z^5/5 + ...) for Z = (X - I)(X + I)^-1, where |Z| < 1/7.
+        let z = (x - identity) * (x + identity).lup_inverse()?;
+        let (z2, mut term, mut sum) = (z * z, z, Self::default());
+        for j in 0..24 {
+            sum += term / T::from((2*j + 1) as f32);
+            term *= z2;
+        }
+        Some(sum * T::from(2.0_f32.powi(k + 1)))
     }
-}
-impl<const A: usize, const B: usize> Mul<f32> for Matrix<A, B> {
-    type Output = Matrix<A, B>;
-    fn mul(self, other: f32) -> Matrix<A, B> {
-        let mut data = self.data;
-        for x in 0..A { for y in 0..B { data[x][y] *= other; } }
-        Self::new(data)
+    ///Principal matrix square root by the Denman-Beavers iteration. None if the matrix is singular or has negative real
+    ///eigenvalues, which stop it converging. Closed forms are used for 3x3 rotations and 4x4 rigid transforms - half
+    ///the rotation angle (and the screw motion) of the original.
+    pub fn sqrt(&self) -> Option<Self> {
+        if L == 3 {
+            let m: Matrix<T, 3, 3> = self.change_dimensions();
+            if m.is_rotation() { return Some((m.log_so3() * T::from(0.5)).exp_so3().change_dimensions()); }
+        }
+        if L == 4 {
+            let m: Matrix<T, 4, 4> = self.change_dimensions();
+            if m.is_rigid() { return Some((m.log_se3() * T::from(0.5)).exp_se3().change_dimensions()); }
+        }
+        let (mut y, mut z) = (*self, Self::identity());
+        for _ in 0..100 {
+            let (y_inv, z_inv) = (y.lup_inverse()?, z.lup_inverse()?);
+            let y_next = (y + z_inv) * T::from(0.5);
+            z = (z + y_inv) * T::from(0.5);
+            let change = (y_next - y).norm_inf();
+            y = y_next;
+            if change <= T::epsilon() * y.norm_inf() { return Some(y); }
+        }
+        None
     }
 }
-impl<const A: usize, const B: usize> Mul<Matrix<A, B>> for f32 {
-    type Output = Matrix<A, B>;
-    fn mul(self, other: Matrix<A, B>) -> Matrix<A, B> {
-        let mut data = other.data;
-        for x in 0..A { for y in 0..B { data[x][y] *= self; } }
-        Matrix::<A, B>::new(data)
+impl<T: Float> Matrix<T, 3, 3> {
+    fn is_skew(&self) -> bool {
+        (0..3).all(|i| (0..=i).all(|j| (self[i][j] + self[j][i]).absolute() <= T::epsilon()))
     }
-}
-
-///Precise Matrix Type using rational components - R rows, C columns, components are r32. Stored in row-major format,
-///indexable by usize indices - row then column. 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct MatrixPrecise<const R: usize, const C: usize> {
-    ///2D Array of r32 data.
-    pub data: [[r32; C]; R],
-}
-impl<const R: usize, const C: usize> MatrixPrecise<R, C> {
-    ///Returns a new Matrix with R rows and C columns from a correctly shaped array of r32.
-    pub fn new(data: [[r32; C]; R]) -> Self { Self { data } }
-    ///Returns R, the number of rows in this Matrix Type (i.e. returns 3 for a Matrix<3, 4>).
-    pub fn num_rows() -> usize { R }
-    ///Returns R, the length of a column in this Matrix Type (i.e. returns 3 for a Matrix<3, 4>).
-    pub fn col_len() -> usize { R }
-    ///Returns C, the number of columns in this Matrix Type (i.e. returns 4 for a Matrix<3, 4>).
-    pub fn num_cols() -> usize { C }
-    ///Returns C, the length of a row in this Matrix Type (i.e. returns 4 for a Matrix<3, 4>).
-    pub fn row_len() -> usize { R }
-    ///Returns a single row of the Matrix as a VectorPrecise<C>, indexed by usize.
-    pub fn row(&self, index: usize) -> VectorPrecise<C> {
-        if index >= R { panic!("Out of bounds! Tried to access Row {}/{}", index, R); }
-        VectorPrecise::<C> { data: self.data[index] }
+    fn is_rotation(&self) -> bool {
+        let rtr = self.transpose() * *self;
+        let orthogonal = (0..3).all(|i| (0..3).all(|j| {
+            (rtr[i][j] - if i == j { T::identity() } else { T::default() }).absolute() <= T::epsilon()
+        }));
+        orthogonal && self.det().is_some_and(|d| d > T::default())
     }
-    ///Returns a single column of the Matrix as a VectorPrecise<R>, indexed by usize.
-    pub fn col(&self, index: usize) -> VectorPrecise<R> {
-        if index >= C { panic!("Out of bounds! Tried to access Column {}/{}", index, C); }
-        let mut column = [r32::default(); R];
-        for i in 0..R {
-            column[i] = self.data[i][index];
+    ///Coefficients (sin(t)/t, (1 - cos(t))/t^2, (t - sin(t))/t^3) for the angle t of a skew symmetric matrix, using
+    ///Taylor series for small angles.
+    fn rodrigues_coefficients(theta2: T) -> (T, T, T) {
+        let theta = theta2.scalar_sqrt();
+        match theta2 < T::epsilon() {
+            true => (T::identity() - theta2/T::from(6.0), T::from(0.5) - theta2/T::from(24.0), T::from(1.0/6.0) - theta2/T::from(120.0)),
+            false => (theta.tsin()/theta, (T::identity() - theta.tcos())/theta2, (theta - theta.tsin())/(theta2*theta)),
         }
-        VectorPrecise::<R> { data: column }
     }
-    ///Utility function for multiplying matrices - as per standard matrix multiplication, to multiply
-    ///two matrices of dimensions (A, B) and (C, D), B must equal C. Used for implementing std::ops.
-    pub fn multiply<const D: usize>(&self, other: MatrixPrecise<C, D>) -> MatrixPrecise<R, D> {
-        let mut out = [[r32::default(); D]; R];
-        for i in 0..R {
-            for j in 0..D {
-                out[i][j] = self.row(i).dot(other.col(j));
-            }
-        }
-        MatrixPrecise::<R, D> { data: out }
+    ///Rodrigues' formula - the rotation exp(K) = I + sin(t)/t K + (1 - cos(t))/t^2 K^2 for skew symmetric K.
+    fn exp_so3(&self) -> Self {
+        let theta2 = self[2][1]*self[2][1] + self[0][2]*self[0][2] + self[1][0]*self[1][0];
+        let (a, b, _) = Self::rodrigues_coefficients(theta2);
+        Self::identity() + *self * a + *self * *self * b
     }
-    pub fn transpose(&self) -> MatrixPrecise<C, R> {
-        let mut data = [[r32::default(); R]; C];
-        for i in 0..R {
-            for j in 0..C {
-                data[j][i] = self.data[i][j];
-            }
+    ///Logarithm of a rotation - the skew symmetric matrix of its axis times its angle.
+    fn log_so3(&self) -> Self {
+        let (zero, one, half) = (T::default(), T::identity(), T::from(0.5));
+        let trace = self[0][0] + self[1][1] + self[2][2];
+        let cos = (trace - one)*half;
+        let cos = if cos > one { one } else if cos < -one { -one } else { cos };
+        let theta = cos.tacos();
+        let skew = *self - self.transpose();
+        if cos >= zero {
+            //R - R^T = 2 sin(t) [n]x, well conditioned away from t = pi.
+            let theta2 = theta*theta;
+            let k = if theta2 < T::epsilon() { half + theta2/T::from(12.0) } else { theta/(T::from(2.0)*theta.tsin()) };
+            return skew * k;
         }
-        MatrixPrecise::<C, R>::new(data)
+        //Near t = pi use the symmetric part instead - (R + R^T)/2 - cos(t) I = (1 - cos(t)) n n^T.
+        let b = (*self + self.transpose()) * half;
+        let i = (0..3).fold(0, |m, i| if b[i][i] > b[m][m] { i } else { m });
+        let ni = ((b[i][i] - cos)/(one - cos)).scalar_sqrt();
+        let mut n: [T; 3] = std::array::from_fn(|j| match j == i {
+            true => ni,
+            false => b[i][j]/((one - cos)*ni),
+        });
+        if n[0]*skew[2][1] + n[1]*skew[0][2] + n[2]*skew[1][0] < zero { n = n.map(|x| -x); }
+        Matrix::new([[zero, -n[2], n[1]], [n[2], zero, -n[0]], [-n[1], n[0], zero]]) * theta
     }
-    pub fn change_dimensions<const R0: usize, const C0: usize>(&self) -> MatrixPrecise<R0, C0> {
-        let mut data = [[r32::default(); C0]; R0];
-
-        for row in 0..R0 {
-            if row < R {
-                for col in 0..C0 {
-                    if col < C {
-                        data[row][col] = self.data[row][col];
-                    }
-                }
-            }
-        }
-        MatrixPrecise::<R0, C0>::new(data)
+}
+impl<T: Float> Matrix<T, 4, 4> {
+    fn bottom_row_is(&self, last: T) -> bool {
+        (0..3).all(|j| self[3][j] == T::default()) && self[3][3] == last
     }
-    pub fn minor(&self, row: usize, col: usize) -> Option<MatrixPrecise<R, C>> {
-        if R <= 1 || C <= 1 || row >= R || col >= C { return None; }
-        let mut data = [[r32::default(); C]; R];
-        let (mut rowc, mut colc) = (0, 0);
-        for row0 in 0..R {
-            if row0 != row {
-                for col0 in 0..C {
-                    if col0 != col {
-                        data[rowc][colc] = self.data[row0][col0]; 
-                        colc += 1; 
-                    }
-                }
-                rowc += 1;
-                colc = 0; 
-            }
-        }
-        Some(Self::new(data))
+    fn rotation_block(&self) -> Matrix<T, 3, 3> { self.change_dimensions() }
+    fn translation(&self) -> Vector<T, 3> { Vector::new([self[0][3], self[1][3], self[2][3]]) }
+    fn is_twist(&self) -> bool { self.bottom_row_is(T::default()) && self.rotation_block().is_skew() }
+    fn is_rigid(&self) -> bool { self.bottom_row_is(T::identity()) && self.rotation_block().is_rotation() }
+    ///Builds [[r, t], [0, last]] from a 3x3 block and a translation.
+    fn from_blocks(r: Matrix<T, 3, 3>, t: Vector<T, 3>, last: T) -> Self {
+        let mut out: Self = r.change_dimensions();
+        for i in 0..3 { out[i][3] = t[i]; }
+        out[3][3] = last;
+        out
     }
-    pub fn to_data_vec(&self) -> Vec<r32> {
-        let mut v = Vec::new();
-        for i in 0..R { for j in 0..C { v.push(self.data[i][j]); } }
-        v
-    } 
-    pub fn minor_vec(vec: &Vec<r32>, dim: (usize, usize), index: (usize, usize)) -> Vec<r32> {
-        if dim.0 == 0 || dim.1 == 0 || index.0 >= dim.0 || index.1 >= dim.1 { return Vec::new(); }
-        else if dim.0 == 1 && dim.1 == 1 { return vec.clone(); }
-        let mut data = Vec::new();
-        for row0 in 0..dim.0 {
-            if row0 != index.0 {
-                for col0 in 0..dim.1 {
-                    if col0 != index.1 {
-                        let index0 = row0 * dim.0 + col0;
-                        data.push(vec[index0]);
-                    }
-                }
-            }
-        }
-        data
+    ///Exponential of a twist - the rotation exp(K), with translation V u for V = I + (1 - cos(t))/t^2 K + (t - sin(t))/t^3 K^2.
+    fn exp_se3(&self) -> Self {
+        let k = self.rotation_block();
+        let theta2 = k[2][1]*k[2][1] + k[0][2]*k[0][2] + k[1][0]*k[1][0];
+        let (_, b, c) = Matrix::<T, 3, 3>::rodrigues_coefficients(theta2);
+        let v = Matrix::<T, 3, 3>::identity() + k * b + k * k * c;
+        Self::from_blocks(k.exp_so3(), v * self.translation(), T::identity())
     }
-}
-impl<const R: usize, const C: usize> From<Matrix<R, C>> for MatrixPrecise<R, C> {
-    fn from(v: Matrix<R, C>) -> Self {
-        let mut data = [[r32::default(); C]; R];
-        for i in 0..R {
-            for j in 0..C {
-                data[i][j] = r32::from(v.data[i][j]);
-            }
-        }
-        Self::new(data)
+    ///Logarithm of a rigid transform - the rotation logarithm K, with translation V^-1 t, where
+    ///V^-1 = I - K/2 + (1 - t sin(t)/(2(1 - cos(t))))/t^2 K^2.
+    fn log_se3(&self) -> Self {
+        let k = self.rotation_block().log_so3();
+        let theta2 = k[2][1]*k[2][1] + k[0][2]*k[0][2] + k[1][0]*k[1][0];
+        let theta = theta2.scalar_sqrt();
+        let d = match theta2 < T::epsilon() {
+            true => T::from(1.0/12.0) + theta2/T::from(720.0),
+            false => (T::identity() - theta*theta.tsin()/(T::from(2.0)*(T::identity() - theta.tcos())))/theta2,
+        };
+        let v_inv = Matrix::<T, 3, 3>::identity() - k * T::from(0.5) + k * k * d;
+        Self::from_blocks(k, v_inv * self.translation(), T::default())
     }
 }
-impl<const R: usize, const C: usize> From<MatrixPrecise<R, C>> for Matrix<R, C> {
-    fn from(v: MatrixPrecise<R, C>) -> Self {
+impl<const R: usize, const C: usize> Matrix<f64, R, C> {
+    ///Lossy conversion to a single precision Matrix.
+    pub fn to_f32(&self) -> Matrix<f32, R, C> {
         let mut data = [[0.0; C]; R];
         for i in 0..R {
             for j in 0..C {
-                data[i][j] = f32::from(v.data[i][j]);
+                data[i][j] = self.data[i][j] as f32;
             }
         }
-        Self::new(data)
+        Matrix::<f32, R, C>::new(data)
     }
 }
-impl<const L: usize> MatrixPrecise<L, L> {
-    pub fn det(&self) -> Option<r32> { Self::det_vec(&self.to_data_vec(), L) }
-    pub fn det_vec(vec: &Vec<r32>, dim: usize) -> Option<r32> {
+impl<T: Scalar, const L: usize> Matrix<T, L, L> {
+    ///Sum of the diagonal components.
+    pub fn trace(&self) -> T { (0..L).fold(T::default(), |s, i| s + self.data[i][i].clone()) }
+    pub fn det(&self) -> Option<T> { Self::det_vec(&self.to_data_vec(), L) }
+    pub fn det_vec(vec: &Vec<T>, dim: usize) -> Option<T> {
         let len = vec.len();
         if len != dim*dim { return None; }
-        if len == 1 { return Some(vec[0]); }
+        if len == 1 { return Some(vec[0].clone()); }
         else {
             let mut cofactors = Vec::new();
             for i in 0..dim {
-                let item = vec[i];
+                let item = vec[i].clone();
                 let cofactor = Self::minor_vec(vec, (dim, dim), (0, i));
                 cofactors.push((item, Self::det_vec(&cofactor, dim - 1).unwrap()));
             }
-            let (mut add, mut sum) = (true, r32::default());
+            let (mut add, mut sum) = (true, T::default());
             for cf in cofactors {
                 match add {
                     true => sum += cf.0*cf.1,
@@ -514,37 +771,41 @@ impl<const L: usize> MatrixPrecise<L, L> {
             Some(sum)
         }
     }
-    pub fn cofactor(&self) -> Option<MatrixPrecise<L, L>> {
+    ///Applies the cofactor sign (-1)^(row + col) to a minor.
+    fn cofactor_sign(row: usize, col: usize, minor: T) -> T {
+        match (row + col).is_multiple_of(2) {
+            true => minor,
+            false => -minor,
+        }
+    }
+    pub fn cofactor(&self) -> Option<Matrix<T, L, L>> {
         if L <= 1 { return None; }
-        let mut data = [[r32::default(); L]; L];
         let v = self.to_data_vec();
-        for row in 0..L {
-            for col in 0..L {
-                let minor_matrix = Self::minor_vec(&v, (L, L), (row, col));
-                let minor = Self::det_vec(&minor_matrix, L - 1).unwrap();
-                data[row][col] = r32::from((-1.0_f32).powf(row as f32 + col as f32))*minor;
-            }
-        }
-        Some(MatrixPrecise::<L, L>::new(data))        
+        let data = std::array::from_fn(|row| std::array::from_fn(|col| {
+            let minor_matrix = Self::minor_vec(&v, (L, L), (row, col));
+            let minor = Self::det_vec(&minor_matrix, L - 1).unwrap();
+            Self::cofactor_sign(row, col, minor)
+        }));
+        Some(Matrix::<T, L, L>::new(data))
     }
-    pub fn adjoint(&self) -> Option<MatrixPrecise<L, L>> {
+    pub fn adjoint(&self) -> Option<Matrix<T, L, L>> {
         match self.cofactor() {
             Some(m) => Some(m.transpose()),
             None => None,
         }
     }
-    pub fn inverse(&self) -> Option<MatrixPrecise<L, L>> {
+    pub fn inverse(&self) -> Option<Matrix<T, L, L>> {
         match self.adjoint() {
             Some(adjoint) => {
-                let diag = *self * adjoint;
-                let det = diag[0][0];
-                if det == r32::default() { return None; }
-                else { return Some(adjoint * det.reciprocal().unwrap()); }
+                let diag = self.clone() * adjoint.clone();
+                let det = diag[0][0].clone();
+                if det.absolute() <= T::epsilon() { return None; }
+                else { return Some(adjoint * (T::identity()/det)); }
             },
             None => None,
         }
     }
-    pub fn cofactor_vec(vec: &Vec<r32>, dim: usize) -> Option<Vec<r32>> {
+    pub fn cofactor_vec(vec: &Vec<T>, dim: usize) -> Option<Vec<T>> {
         let len = vec.len();
         if len != dim*dim { return None; }
         if len <= 1 { return None; }
@@ -555,7 +816,7 @@ impl<const L: usize> MatrixPrecise<L, L> {
             col += 1;
             if col >= dim { col = 0; row += 1; }
             let minor = Self::det_vec(&minor_matrix, L - 1).unwrap();
-            v.push(r32::from((-1.0_f32).powf(row as f32 + col as f32))*minor);
+            v.push(Self::cofactor_sign(row, col, minor));
         }
         Some(v)
     }
@@ -564,92 +825,129 @@ impl<const L: usize> MatrixPrecise<L, L> {
         let mut l = Self::default(); let mut u = Self::default();
         for i in 0..L {
             for k in i..L {
-                let mut sum = r32::default();
+                let mut sum = T::default();
                 for j in 0..i {
-                    sum = sum + (l[i][j] * u[j][k]);
+                    sum = sum + (l[i][j].clone() * u[j][k].clone());
                 }
-                u[i][k] = self[i][k] - sum;
+                u[i][k] = self[i][k].clone() - sum;
             }
             for k in i..L {
-                if i == k { l[i][i] = r32::identity() }
+                if i == k { l[i][i] = T::identity() }
                 else {
-                    let mut sum = r32::default();
+                    let mut sum = T::default();
                     for j in 0..i {
-                        sum = sum + (l[k][j] * u[j][i]);
+                        sum = sum + (l[k][j].clone() * u[j][i].clone());
                     }
-                    if u[i][i] == r32::default() { return None; }
-                    l[k][i] = (self[k][i] - sum)/u[i][i];
+                    if u[i][i] == T::default() { return None; }
+                    l[k][i] = (self[k][i].clone() - sum)/u[i][i].clone();
                 }
             }
         }
         Some((l, u))
     }
-    ///LUP decomposition - may not succeed, tries to decompose into two triangular matrices
-    ///L and U, with a permutation matrix P.
+    ///LUP decomposition with partial pivoting - returns (L, U, P) with P*A = L*U, where L is unit lower triangular, U is
+    ///upper triangular and P is a permutation matrix. None if the matrix is singular.
     pub fn lup(&self) -> Option<(Self, Self, Self)> {
-        let mut p = Self::identity();
-        let mut check = [false; L];
-        for i in 0..L {
-            let col = self.col(i);
-            let mut max = (0, r32::default());
-            for j in 0..L {
-                if !check[j] {
-                    if check[max.0] || col[j] > max.1 { max.0 = j; max.1 = col[j]; }
-                }
-            }
-            let mut p_row = [r32::default(); L];
-            p_row[max.0] = r32::identity();
-            p[i] = p_row;
-            check[max.0] = true;
-        }
-        match (p * *self).lu() {
-            Some(lu) => Some((lu.0, lu.1, p)),
-            None => None,
+        let lup = self.lup_pivoted(Pivoting::Partial);
+        match lup.is_singular() {
+            true => None,
+            false => Some((lup.l, lup.u, lup.p)),
         }
     }
-    ///Tries to calculate the determinant using LUP decomposition.
-    pub fn lup_det(&self) -> Option<r32> {
-        match self.lu() {
-            Some(lu) => {
-                let u = lu.1;
-                let mut sum = r32::identity();
-                for i in 0..L { sum *= u[i][i]; }
-                Some(sum)
-            },
-            None => None
+    ///LU decomposition by Gaussian elimination, choosing each pivot by absolute value - the largest in the current column
+    ///for partial pivoting, or in the whole remaining submatrix for full pivoting. Always succeeds, with singular
+    ///matrices giving a zero smallest pivot. Full pivoting is more stable for nearly rank deficient matrices.
+    pub fn lup_pivoted(&self, pivoting: Pivoting) -> LupDecomposition<T, L> {
+        let (mut a, mut l) = (self.clone(), Self::default());
+        let (mut rows, mut cols): ([usize; L], [usize; L]) = (std::array::from_fn(|i| i), std::array::from_fn(|i| i));
+        let mut parity = 1;
+        let zero = T::default();
+        for k in 0..L {
+            let (mut pi, mut pj, mut max) = (k, k, zero.clone());
+            let last_col = match pivoting {
+                Pivoting::Partial => k + 1,
+                Pivoting::Full => L,
+            };
+            for i in k..L {
+                for j in k..last_col {
+                    if a[i][j].absolute() > max { (pi, pj, max) = (i, j, a[i][j].absolute()); }
+                }
+            }
+            if pi != k {
+                a.data.swap(k, pi);
+                l.data.swap(k, pi);
+                rows.swap(k, pi);
+                parity = -parity;
+            }
+            if pj != k {
+                for i in 0..L { a[i].swap(k, pj); }
+                cols.swap(k, pj);
+                parity = -parity;
+            }
+            l[k][k] = T::identity();
+            //The pivot is the largest remaining value, so if it is zero the column below it is already eliminated.
+            if max == zero { continue; }
+            for i in k+1..L {
+                let f = a[i][k].clone()/a[k][k].clone();
+                for j in k+1..L {
+                    let v = f.clone()*a[k][j].clone();
+                    a[i][j] -= v;
+                }
+                a[i][k] = zero.clone();
+                l[i][k] = f;
+            }
         }
-        
+        let max_abs = |m: &Self| m.data.iter().flatten().fold(zero.clone(), |s, x| if x.absolute() > s { x.absolute() } else { s });
+        let scale = max_abs(self);
+        let growth = match scale == zero {
+            true => T::identity(),
+            false => max_abs(&a)/scale.clone(),
+        };
+        let min_pivot = (0..L).map(|i| a[i][i].absolute()).fold(None, |m: Option<T>, x| match m {
+            Some(m) if m <= x => Some(m),
+            _ => Some(x),
+        }).unwrap_or(T::identity());
+        let permutation = |perm: [usize; L], transpose: bool| Self::new(std::array::from_fn(|i| std::array::from_fn(|j| {
+            match (transpose && perm[j] == i) || (!transpose && perm[i] == j) {
+                true => T::identity(),
+                false => zero.clone(),
+            }
+        })));
+        LupDecomposition { l, u: a, p: permutation(rows, false), q: permutation(cols, true), parity, growth, min_pivot, scale }
     }
+    ///Calculates the determinant using LUP decomposition - the product of the pivots, negated for an odd permutation.
+    ///Zero for a singular matrix, so this is always Some.
+    pub fn lup_det(&self) -> Option<T> { Some(self.lup_pivoted(Pivoting::Partial).det()) }
     ///Forward substitution - solves for Lx = b where L is a lower triangular matrix.
     ///Takes target vector b and calculates x, where the matrix is L.
-    pub fn forward_sub(&self, target: VectorPrecise<L>) -> VectorPrecise<L> {
-        let mut out = VectorPrecise::<L>::default();
-        out[0] = target[0];
+    pub fn forward_sub(&self, target: Vector<T, L>) -> Vector<T, L> {
+        let mut out = Vector::<T, L>::default();
+        out[0] = target[0].clone();
         for i in 1..L {
             let row = self.row(i);
-            let v = target[i] - row.dot(out);
+            let v = target[i].clone() - row.dot(out.clone());
             out[i] = v;
         }
         out
     }
     ///Backward substitution - solves for Ux = b where U is an upper triangular matrix.
     ///Takes target vector b and calculates x, where the matrix is U.
-    pub fn back_sub(&self, target: VectorPrecise<L>) -> VectorPrecise<L> {
-        let mut out = VectorPrecise::<L>::default();
+    pub fn back_sub(&self, target: Vector<T, L>) -> Vector<T, L> {
+        let mut out = Vector::<T, L>::default();
         for ii in 0..L {
             let i = L - ii - 1;
-            let mut v = target[i];
+            let mut v = target[i].clone();
             for j in i+1..L {
-                v -= self[i][j]*out[j];
+                v -= self[i][j].clone()*out[j].clone();
             }
-            out[i] = v/self[i][i];
+            out[i] = v/self[i][i].clone();
         }
         out
     }
     ///LUP substitution - uses LUP decomposition to solve for Ax = b, where A is the matrix
     ///calling this, b is the target vector provided as an argument, and x is returned - dependent
     ///on successful LUP decomposition.
-    pub fn lup_sub(&self, target: VectorPrecise<L>) -> Option<VectorPrecise<L>> {
+    pub fn lup_sub(&self, target: Vector<T, L>) -> Option<Vector<T, L>> {
         match self.lup() {
             Some(lup) => {
                 let p_target = lup.2 * target;
@@ -661,82 +959,406 @@ impl<const L: usize> MatrixPrecise<L, L> {
         }
     }
     ///Attempts to calculate an inverse matrix for this matrix using LUP substitution. This will
-    ///fail if a LUP decomposition cannot be found for the matrix.
-    pub fn lup_inverse(&self) -> Option<MatrixPrecise<L, L>> {
-        if let Some(d) = self.lup_det() { if d.absolute() < r32::default() { return None; } }
-        let mut columns = [VectorPrecise::<L>::default(); L];
+    ///fail if the matrix is singular. Decomposes once and solves for each column of the identity.
+    pub fn lup_inverse(&self) -> Option<Matrix<T, L, L>> {
+        let lup = self.lup_pivoted(Pivoting::Partial);
+        if lup.is_singular() { return None; }
+        let mut columns: [Vector<T, L>; L] = std::array::from_fn(|_| Vector::<T, L>::default());
         let identity = Self::identity();
         for i in 0..L {
-            let Some(ci) = self.lup_sub(identity.row(i)) else { return None; };
+            let Some(ci) = lup.solve(identity.row(i)) else { return None; };
             columns[i] = ci; //USING ROWS FOR EFFICIENCY - ITS COLUMNS
         }
-        let mut data = [[r32::default(); L]; L];
-        for i in 0..L {
-            for j in 0..L {
-                data[i][j] = columns[j][i];
+        let data = std::array::from_fn(|i| std::array::from_fn(|j| columns[j][i].clone()));
+        Some(Self::new(data))
+    }
+}
+impl<T: Scalar, const L: usize> Matrix<T, L, L> {
+    ///Returns true if the matrix equals its transpose, to within epsilon relative to the compared components.
+    pub fn is_symmetric(&self) -> bool {
+        (0..L).all(|i| (0..i).all(|j| {
+            let (a, b) = (self[i][j].clone(), self[j][i].clone());
+            (a.clone() - b.clone()).absolute() <= T::epsilon() * (a.absolute() + b.absolute())
+        }))
+    }
+    ///LDL^T decomposition of a symmetric matrix - returns (L, D) where L is unit lower triangular and D holds the
+    ///diagonal, so A = L*diag(D)*L^T. Needs no square roots, so it is exact for rational types like MatrixPrecise. Works
+    ///for indefinite matrices too - D is all positive exactly when the matrix is positive definite.
+    pub fn ldlt(&self) -> Result<(Self, Vector<T, L>), DecompositionError> {
+        if !self.is_symmetric() { return Err(DecompositionError::NotSymmetric); }
+        let (mut l, mut d) = (Self::identity(), Vector::<T, L>::default());
+        for j in 0..L {
+            let mut dj = self[j][j].clone();
+            for k in 0..j { dj -= l[j][k].clone() * l[j][k].clone() * d[k].clone(); }
+            if dj == T::default() { return Err(DecompositionError::ZeroPivot(j)); }
+            for i in j+1..L {
+                let mut v = self[i][j].clone();
+                for k in 0..j { v -= l[i][k].clone() * l[j][k].clone() * d[k].clone(); }
+                l[i][j] = v/dj.clone();
             }
+            d[j] = dj;
         }
-        Some(Self::new(data))
+        Ok((l, d))
+    }
+    ///Solves Ax = b for symmetric A using its LDL^T decomposition.
+    pub fn ldlt_solve(&self, target: Vector<T, L>) -> Result<Vector<T, L>, DecompositionError> {
+        let (l, d) = self.ldlt()?;
+        Ok(Self::ldlt_sub(&l, &d, target))
+    }
+    ///Inverts a symmetric matrix using its LDL^T decomposition.
+    pub fn ldlt_inverse(&self) -> Result<Self, DecompositionError> {
+        let (l, d) = self.ldlt()?;
+        let identity = Self::identity();
+        let columns: [Vector<T, L>; L] = std::array::from_fn(|i| Self::ldlt_sub(&l, &d, identity.row(i)));
+        Ok(Self::new(std::array::from_fn(|i| std::array::from_fn(|j| columns[j][i].clone()))))
+    }
+    ///Solves L*diag(D)*L^T x = b by forward substitution, scaling, then back substitution.
+    fn ldlt_sub(l: &Self, d: &Vector<T, L>, target: Vector<T, L>) -> Vector<T, L> {
+        let mut y = l.forward_sub(target);
+        for i in 0..L { y[i] /= d[i].clone(); }
+        l.transpose().back_sub(y)
+    }
+}
+impl<T: Float, const L: usize> Matrix<T, L, L> {
+    ///Cholesky decomposition of a symmetric positive definite matrix - returns the lower triangular L with A = L*L^T.
+    pub fn cholesky(&self) -> Result<Self, DecompositionError> {
+        if !self.is_symmetric() { return Err(DecompositionError::NotSymmetric); }
+        let mut l = Self::default();
+        for j in 0..L {
+            let mut d = self[j][j];
+            for k in 0..j { d -= l[j][k]*l[j][k]; }
+            if d <= T::default() { return Err(DecompositionError::NotPositiveDefinite(j)); }
+            l[j][j] = d.scalar_sqrt();
+            for i in j+1..L {
+                let mut v = self[i][j];
+                for k in 0..j { v -= l[i][k]*l[j][k]; }
+                l[i][j] = v/l[j][j];
+            }
+        }
+        Ok(l)
+    }
+    ///Solves Ax = b for symmetric positive definite A using its Cholesky decomposition.
+    pub fn cholesky_solve(&self, target: Vector<T, L>) -> Result<Vector<T, L>, DecompositionError> {
+        let l = self.cholesky()?;
+        Ok(Self::cholesky_sub(&l, target))
+    }
+    ///Inverts a symmetric positive definite matrix using its Cholesky decomposition.
+    pub fn cholesky_inverse(&self) -> Result<Self, DecompositionError> {
+        let l = self.cholesky()?;
+        let identity = Self::identity();
+        let columns: [Vector<T, L>; L] = std::array::from_fn(|i| Self::cholesky_sub(&l, identity.row(i)));
+        Ok(Self::new(std::array::from_fn(|i| std::array::from_fn(|j| columns[j][i]))))
+    }
+    ///Solves L*L^T x = b by forward then back substitution.
+    fn cholesky_sub(l: &Self, target: Vector<T, L>) -> Vector<T, L> {
+        let mut y = Vector::<T, L>::default();
+        for i in 0..L {
+            let mut v = target[i];
+            for j in 0..i { v -= l[i][j]*y[j]; }
+            y[i] = v/l[i][i];
+        }
+        l.transpose().back_sub(y)
     }
 }
 
-impl<const A: usize, const B: usize> Display for MatrixPrecise<A, B> {
+///Pivoting strategy for LU decomposition - Partial swaps rows only, Full swaps rows and columns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pivoting {
+    Partial,
+    Full,
+}
+///LU decomposition with pivoting - P*A*Q = L*U, with L unit lower triangular, U upper triangular and P, Q permutation
+///matrices (Q is the identity for partial pivoting). Returned by Matrix::lup_pivoted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LupDecomposition<T, const L: usize> {
+    pub l: Matrix<T, L, L>,
+    pub u: Matrix<T, L, L>,
+    ///Row permutation.
+    pub p: Matrix<T, L, L>,
+    ///Column permutation.
+    pub q: Matrix<T, L, L>,
+    ///Sign of the combined permutations - 1 for an even number of swaps, -1 for odd.
+    pub parity: i8,
+    ///Growth factor - the largest magnitude in U over the largest in A. Large values mean elimination lost accuracy.
+    pub growth: T,
+    ///Smallest pivot magnitude - zero for a singular matrix, and small relative to the matrix for a near singular one.
+    pub min_pivot: T,
+    ///Largest magnitude in the decomposed matrix, to judge min_pivot against.
+    pub scale: T,
+}
+impl<T: Scalar, const L: usize> LupDecomposition<T, L> {
+    ///Returns the determinant of the decomposed matrix.
+    pub fn det(&self) -> T {
+        let mut det = T::identity();
+        for i in 0..L { det *= self.u[i][i].clone(); }
+        match self.parity < 0 {
+            true => -det,
+            false => det,
+        }
+    }
+    ///Returns true if a pivot is exactly zero. Near singular matrices are not rejected - compare min_pivot and growth
+    ///against scale to judge how well conditioned the system is.
+    pub fn is_singular(&self) -> bool { self.min_pivot == T::default() }
+    ///Solves Ax = b for the decomposed matrix A - None if it is singular.
+    pub fn solve(&self, target: Vector<T, L>) -> Option<Vector<T, L>> {
+        if self.is_singular() { return None; }
+        let y = self.u.back_sub(self.l.forward_sub(self.p.clone() * target));
+        Some(self.q.clone() * y)
+    }
+}
+
+impl<T: Scalar, const A: usize, const B: usize> Display for Matrix<T, A, B> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut rows = [VectorPrecise::<B>::default(); A];
-        for i in 0..A { rows[i] = self.row(i); }
+        let rows: [Vector<T, B>; A] = std::array::from_fn(|i| self.row(i));
         write!(f, "{:?}", rows)
     }
 }
-impl<const A: usize, const B: usize> Default for MatrixPrecise<A, B> {
+impl<T: Scalar, const A: usize, const B: usize> Default for Matrix<T, A, B> {
     fn default() -> Self {
-        Self::new([[r32::default(); B]; A])
+        Self::new(std::array::from_fn(|_| std::array::from_fn(|_| T::default())))
     }
 }
-impl<const A: usize> Identity for MatrixPrecise<A, A> {
+impl<T: Scalar, const A: usize> Identity for Matrix<T, A, A> {
     fn identity() -> Self {
-        let mut data = [[r32::default(); A]; A];
-        for i in 0..A {
-            data[i][i] = r32::identity();
-        }
+        let data = std::array::from_fn(|i| std::array::from_fn(|j| match i == j {
+            true => T::identity(),
+            false => T::default(),
+        }));
         Self::new(data)
     }
 }
-impl<const A: usize, const B: usize> Index<usize> for MatrixPrecise<A, B> {
-    type Output = [r32; B];
-    fn index(&self, index: usize) -> &[r32; B] { &self.data[index] }
+impl<T, const A: usize, const B: usize> Index<usize> for Matrix<T, A, B> {
+    type Output = [T; B];
+    fn index(&self, index: usize) -> &[T; B] { &self.data[index] }
+}
+impl<T, const A: usize, const B: usize> IndexMut<usize> for Matrix<T, A, B> {
+    fn index_mut(&mut self, index: usize) -> &mut [T; B] { &mut self.data[index] }
+}
+impl<T: Scalar, const A: usize, const B: usize, const C: usize> Mul<Matrix<T, B, C>> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, C>;
+    fn mul(self, other: Matrix<T, B, C>) -> Matrix<T, A, C> { self.multiply(other) }
 }
-impl<const A: usize, const B: usize> IndexMut<usize> for MatrixPrecise<A, B> {
-    fn index_mut(&mut self, index: usize) -> &mut [r32; B] { &mut self.data[index] }
+impl<T: Scalar, const A: usize, const B: usize, const C: usize> Mul<&Matrix<T, B, C>> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, C>;
+    fn mul(self, other: &Matrix<T, B, C>) -> Matrix<T, A, C> { self * other.clone() }
 }
-impl<const A: usize, const B: usize, const C: usize> Mul<MatrixPrecise<B, C>> for MatrixPrecise<A, B> {
-    type Output = MatrixPrecise<A, C>;
-    fn mul(self, other: MatrixPrecise<B, C>) -> MatrixPrecise<A, C> { self.multiply(other) }
+impl<T: Scalar, const A: usize, const B: usize, const C: usize> Mul<Matrix<T, B, C>> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, C>;
+    fn mul(self, other: Matrix<T, B, C>) -> Matrix<T, A, C> { self.clone() * other }
 }
-impl<const A: usize, const B: usize> Mul<VectorPrecise<B>> for MatrixPrecise<A, B> {
-    type Output = VectorPrecise<A>;
-    fn mul(self, other: VectorPrecise<B>) -> VectorPrecise<A> {
+impl<T: Scalar, const A: usize, const B: usize, const C: usize> Mul<&Matrix<T, B, C>> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, C>;
+    fn mul(self, other: &Matrix<T, B, C>) -> Matrix<T, A, C> { self.clone() * other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Mul<Vector<T, B>> for Matrix<T, A, B> {
+    type Output = Vector<T, A>;
+    fn mul(self, other: Vector<T, B>) -> Vector<T, A> {
         self.mul(other.as_col()).col(0)
     }
 }
-impl<const A: usize, const B: usize> Mul<MatrixPrecise<A, B>> for VectorPrecise<A> {
-    type Output = VectorPrecise<B>;
-    fn mul(self, other: MatrixPrecise<A, B>) -> VectorPrecise<B> {
+impl<T: Scalar, const A: usize, const B: usize> Mul<Matrix<T, A, B>> for Vector<T, A> {
+    type Output = Vector<T, B>;
+    fn mul(self, other: Matrix<T, A, B>) -> Vector<T, B> {
         self.as_row().mul(other).row(0)
     }
 }
-impl<const A: usize, const B: usize> Mul<r32> for MatrixPrecise<A, B> {
-    type Output = MatrixPrecise<A, B>;
-    fn mul(self, other: r32) -> MatrixPrecise<A, B> {
+impl<T: Scalar, const A: usize, const B: usize> Mul<T> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn mul(self, other: T) -> Matrix<T, A, B> {
         let mut data = self.data;
-        for x in 0..A { for y in 0..B { data[x][y] *= other; } }
+        for x in 0..A { for y in 0..B { data[x][y] *= other.clone(); } }
         Self::new(data)
     }
 }
-impl<const A: usize, const B: usize> Mul<MatrixPrecise<A, B>> for r32 {
-    type Output = MatrixPrecise<A, B>;
-    fn mul(self, other: MatrixPrecise<A, B>) -> MatrixPrecise<A, B> {
-        let mut data = other.data;
-        for x in 0..A { for y in 0..B { data[x][y] *= self; } }
-        MatrixPrecise::<A, B>::new(data)
-    }
-}
\ No newline at end of file
+impl<T: Scalar, const A: usize, const B: usize> Mul<&T> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn mul(self, other: &T) -> Matrix<T, A, B> { self * other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Mul<T> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn mul(self, other: T) -> Matrix<T, A, B> { self.clone() * other }
+}
+impl<T: Scalar, const A: usize, const B: usize> Mul<&T> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn mul(self, other: &T) -> Matrix<T, A, B> { self.clone() * other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Div<T> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn div(self, other: T) -> Matrix<T, A, B> { self * (T::identity()/other) }
+}
+impl<T: Scalar, const A: usize, const B: usize> Div<&T> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn div(self, other: &T) -> Matrix<T, A, B> { self / other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Div<T> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn div(self, other: T) -> Matrix<T, A, B> { self.clone() / other }
+}
+impl<T: Scalar, const A: usize, const B: usize> Div<&T> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn div(self, other: &T) -> Matrix<T, A, B> { self.clone() / other.clone() }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<f32, A, B>> for f32 {
+    type Output = Matrix<f32, A, B>;
+    fn mul(self, other: Matrix<f32, A, B>) -> Matrix<f32, A, B> { other * self }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<f32, A, B>> for f32 {
+    type Output = Matrix<f32, A, B>;
+    fn mul(self, other: &Matrix<f32, A, B>) -> Matrix<f32, A, B> { self * *other }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<f32, A, B>> for &f32 {
+    type Output = Matrix<f32, A, B>;
+    fn mul(self, other: Matrix<f32, A, B>) -> Matrix<f32, A, B> { *self * other }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<f32, A, B>> for &f32 {
+    type Output = Matrix<f32, A, B>;
+    fn mul(self, other: &Matrix<f32, A, B>) -> Matrix<f32, A, B> { *self * *other }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<f64, A, B>> for f64 {
+    type Output = Matrix<f64, A, B>;
+    fn mul(self, other: Matrix<f64, A, B>) -> Matrix<f64, A, B> { other * self }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<f64, A, B>> for f64 {
+    type Output = Matrix<f64, A, B>;
+    fn mul(self, other: &Matrix<f64, A, B>) -> Matrix<f64, A, B> { self * *other }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<f64, A, B>> for &f64 {
+    type Output = Matrix<f64, A, B>;
+    fn mul(self, other: Matrix<f64, A, B>) -> Matrix<f64, A, B> { *self * other }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<f64, A, B>> for &f64 {
+    type Output = Matrix<f64, A, B>;
+    fn mul(self, other: &Matrix<f64, A, B>) -> Matrix<f64, A, B> { *self * *other }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<r32, A, B>> for r32 {
+    type Output = Matrix<r32, A, B>;
+    fn mul(self, other: Matrix<r32, A, B>) -> Matrix<r32, A, B> { other * self }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<r32, A, B>> for r32 {
+    type Output = Matrix<r32, A, B>;
+    fn mul(self, other: &Matrix<r32, A, B>) -> Matrix<r32, A, B> { self * *other }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<r32, A, B>> for &r32 {
+    type Output = Matrix<r32, A, B>;
+    fn mul(self, other: Matrix<r32, A, B>) -> Matrix<r32, A, B> { *self * other }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<r32, A, B>> for &r32 {
+    type Output = Matrix<r32, A, B>;
+    fn mul(self, other: &Matrix<r32, A, B>) -> Matrix<r32, A, B> { *self * *other }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<r64, A, B>> for r64 {
+    type Output = Matrix<r64, A, B>;
+    fn mul(self, other: Matrix<r64, A, B>) -> Matrix<r64, A, B> { other * self }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<r64, A, B>> for r64 {
+    type Output = Matrix<r64, A, B>;
+    fn mul(self, other: &Matrix<r64, A, B>) -> Matrix<r64, A, B> { self * *other }
+}
+impl<const A: usize, const B: usize> Mul<Matrix<r64, A, B>> for &r64 {
+    type Output = Matrix<r64, A, B>;
+    fn mul(self, other: Matrix<r64, A, B>) -> Matrix<r64, A, B> { *self * other }
+}
+impl<const A: usize, const B: usize> Mul<&Matrix<r64, A, B>> for &r64 {
+    type Output = Matrix<r64, A, B>;
+    fn mul(self, other: &Matrix<r64, A, B>) -> Matrix<r64, A, B> { *self * *other }
+}
+impl<T: Scalar, const A: usize, const B: usize> Add<Matrix<T, A, B>> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn add(self, other: Matrix<T, A, B>) -> Matrix<T, A, B> { self.zip_map(&other, |a, b| a + b) }
+}
+impl<T: Scalar, const A: usize, const B: usize> Add<&Matrix<T, A, B>> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn add(self, other: &Matrix<T, A, B>) -> Matrix<T, A, B> { self + other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Add<Matrix<T, A, B>> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn add(self, other: Matrix<T, A, B>) -> Matrix<T, A, B> { self.clone() + other }
+}
+impl<T: Scalar, const A: usize, const B: usize> Add<&Matrix<T, A, B>> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn add(self, other: &Matrix<T, A, B>) -> Matrix<T, A, B> { self.clone() + other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Sub<Matrix<T, A, B>> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn sub(self, other: Matrix<T, A, B>) -> Matrix<T, A, B> { self.zip_map(&other, |a, b| a - b) }
+}
+impl<T: Scalar, const A: usize, const B: usize> Sub<&Matrix<T, A, B>> for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn sub(self, other: &Matrix<T, A, B>) -> Matrix<T, A, B> { self - other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Sub<Matrix<T, A, B>> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn sub(self, other: Matrix<T, A, B>) -> Matrix<T, A, B> { self.clone() - other }
+}
+impl<T: Scalar, const A: usize, const B: usize> Sub<&Matrix<T, A, B>> for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn sub(self, other: &Matrix<T, A, B>) -> Matrix<T, A, B> { self.clone() - other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> Neg for Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn neg(self) -> Matrix<T, A, B> { self.map(|a| -a) }
+}
+impl<T: Scalar, const A: usize, const B: usize> Neg for &Matrix<T, A, B> {
+    type Output = Matrix<T, A, B>;
+    fn neg(self) -> Matrix<T, A, B> { -self.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> AddAssign<Matrix<T, A, B>> for Matrix<T, A, B> {
+    fn add_assign(&mut self, other: Matrix<T, A, B>) { *self = self.clone() + other }
+}
+impl<T: Scalar, const A: usize, const B: usize> AddAssign<Matrix<T, A, B>> for &mut Matrix<T, A, B> {
+    fn add_assign(&mut self, other: Matrix<T, A, B>) { **self = (**self).clone() + other }
+}
+impl<T: Scalar, const A: usize, const B: usize> AddAssign<&Matrix<T, A, B>> for Matrix<T, A, B> {
+    fn add_assign(&mut self, other: &Matrix<T, A, B>) { *self = self.clone() + other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> AddAssign<&Matrix<T, A, B>> for &mut Matrix<T, A, B> {
+    fn add_assign(&mut self, other: &Matrix<T, A, B>) { **self = (**self).clone() + other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> SubAssign<Matrix<T, A, B>> for Matrix<T, A, B> {
+    fn sub_assign(&mut self, other: Matrix<T, A, B>) { *self = self.clone() - other }
+}
+impl<T: Scalar, const A: usize, const B: usize> SubAssign<Matrix<T, A, B>> for &mut Matrix<T, A, B> {
+    fn sub_assign(&mut self, other: Matrix<T, A, B>) { **self = (**self).clone() - other }
+}
+impl<T: Scalar, const A: usize, const B: usize> SubAssign<&Matrix<T, A, B>> for Matrix<T, A, B> {
+    fn sub_assign(&mut self, other: &Matrix<T, A, B>) { *self = self.clone() - other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> SubAssign<&Matrix<T, A, B>> for &mut Matrix<T, A, B> {
+    fn sub_assign(&mut self, other: &Matrix<T, A, B>) { **self = (**self).clone() - other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<Matrix<T, B, B>> for Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: Matrix<T, B, B>) { *self = self.clone() * other }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<Matrix<T, B, B>> for &mut Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: Matrix<T, B, B>) { **self = (**self).clone() * other }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<&Matrix<T, B, B>> for Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: &Matrix<T, B, B>) { *self = self.clone() * other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<&Matrix<T, B, B>> for &mut Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: &Matrix<T, B, B>) { **self = (**self).clone() * other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<T> for Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: T) { *self = self.clone() * other }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<T> for &mut Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: T) { **self = (**self).clone() * other }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<&T> for Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: &T) { *self = self.clone() * other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> MulAssign<&T> for &mut Matrix<T, A, B> {
+    fn mul_assign(&mut self, other: &T) { **self = (**self).clone() * other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> DivAssign<T> for Matrix<T, A, B> {
+    fn div_assign(&mut self, other: T) { *self = self.clone() / other }
+}
+impl<T: Scalar, const A: usize, const B: usize> DivAssign<T> for &mut Matrix<T, A, B> {
+    fn div_assign(&mut self, other: T) { **self = (**self).clone() / other }
+}
+impl<T: Scalar, const A: usize, const B: usize> DivAssign<&T> for Matrix<T, A, B> {
+    fn div_assign(&mut self, other: &T) { *self = self.clone() / other.clone() }
+}
+impl<T: Scalar, const A: usize, const B: usize> DivAssign<&T> for &mut Matrix<T, A, B> {
+    fn div_assign(&mut self, other: &T) { **self = (**self).clone() / other.clone() }
+}
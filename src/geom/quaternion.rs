@@ -1,94 +1,98 @@
 use std::ops::{Mul, Add, MulAssign, AddAssign};
 
-use crate::{Vector, num::{Identity, Magnitude}, Matrix};
+use crate::num::{Identity, Magnitude, Float};
+use super::{vector::Vector, matrix::Matrix};
 
 use super::transforms::transform_mat::from_trs;
 
 ///Quaternion type - analogous to Vector<4>, although keeps its components individually rather than in an array - convert to and from for
-///quaternion operations vs. vector operations.
+///quaternion operations vs. vector operations. Components are f32 or f64.
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Quaternion {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+pub struct Quaternion<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
 }
 
-impl From<Vector<4>> for Quaternion {
+impl<T: Float> From<Vector<T, 4>> for Quaternion<T> {
     /// Implements Vectors as [x, y, z, w], not [w, x, y, z].
-    fn from(other: Vector<4>) -> Self { Self { x: other[0], y: other[1], z: other[2], w: other[3] } }
+    fn from(other: Vector<T, 4>) -> Self { Self { x: other[0], y: other[1], z: other[2], w: other[3] } }
 }
-impl From<Quaternion> for Vector<4> {
+impl<T: Float> From<Quaternion<T>> for Vector<T, 4> {
     /// Implements Vectors as [x, y, z, w], not [w, x, y, z].
-    fn from(other: Quaternion) -> Self { Self::new([other.x, other.y, other.z, other.w]) }
+    fn from(other: Quaternion<T>) -> Self { Self::new([other.x, other.y, other.z, other.w]) }
 }
-impl Default for Quaternion {
-    fn default() -> Self { Self::new(0.0, 0.0, 0.0, 0.0) }
+impl From<Quaternion<f32>> for Quaternion<f64> {
+    fn from(other: Quaternion<f32>) -> Self { Self::new(other.x as f64, other.y as f64, other.z as f64, other.w as f64) }
 }
-impl Identity for Quaternion {
-    fn identity() -> Self { Self::new(0.0, 0.0, 0.0, 1.0) }
+impl<T: Float> Default for Quaternion<T> {
+    fn default() -> Self { Self::new(T::default(), T::default(), T::default(), T::default()) }
+}
+impl<T: Float> Identity for Quaternion<T> {
+    fn identity() -> Self { Self::new(T::default(), T::default(), T::default(), T::identity()) }
 }
 
-impl Quaternion {
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self { Self { x, y, z, w } }
+impl<T: Float> Quaternion<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Self { Self { x, y, z, w } }
     ///Returns the Conjugate of the Quaternion.
     pub fn conjugate(&self) -> Self { Self::new(-self.x, -self.y, -self.z, self.w) }
     ///Returns a Quaternion from a 3D vector axis and a float angle.
-    pub fn from_axis_angle(axis: Vector<3>, angle: f32) -> Quaternion {
-        let half_angle = angle/2.0;
+    pub fn from_axis_angle(axis: Vector<T, 3>, angle: T) -> Quaternion<T> {
+        let half_angle = angle/T::from(2.0);
         Quaternion::new(
-            axis[0]*half_angle.sin(),
-            axis[1]*half_angle.sin(),
-            axis[2]*half_angle.sin(),
-            half_angle.cos(),
+            axis[0]*half_angle.tsin(),
+            axis[1]*half_angle.tsin(),
+            axis[2]*half_angle.tsin(),
+            half_angle.tcos(),
         )
     }
     ///Returns a 3D Vector axis and a float angle from a Quaternion.
-    pub fn to_axis_angle(&self) -> (Vector<3>, f32) {
-        if self.w == 1.0 {
-            return (Vector::<3>::new([1.0, 0.0, 0.0]), 0.0);
+    pub fn to_axis_angle(&self) -> (Vector<T, 3>, T) {
+        if self.w == T::identity() {
+            return (Vector::<T, 3>::new([T::identity(), T::default(), T::default()]), T::default());
         }
-        let angle = self.w.acos()*2.0;
-        let half_angle = angle/2.0;
-        (Vector::<3>::new([
-            self.x/half_angle.sin(), self.y/half_angle.sin(), self.z/half_angle.sin()
+        let angle = self.w.tacos()*T::from(2.0);
+        let half_angle = angle/T::from(2.0);
+        (Vector::<T, 3>::new([
+            self.x/half_angle.tsin(), self.y/half_angle.tsin(), self.z/half_angle.tsin()
         ]), angle)
     }
     ///Returns a Quaternion from a rotation around the X axis.
-    pub fn from_x_angle(angle: f32) -> Quaternion {
-        let half_angle = angle/2.0;
+    pub fn from_x_angle(angle: T) -> Quaternion<T> {
+        let half_angle = angle/T::from(2.0);
         Quaternion::new(
-            half_angle.sin(),
-            0.0,
-            0.0,
-            half_angle.cos(),
+            half_angle.tsin(),
+            T::default(),
+            T::default(),
+            half_angle.tcos(),
         )
     }
 
     ///Returns a Quaternion from a rotation around the Y axis.
-    pub fn from_y_angle(angle: f32) -> Quaternion {
-        let half_angle = angle/2.0;
+    pub fn from_y_angle(angle: T) -> Quaternion<T> {
+        let half_angle = angle/T::from(2.0);
         Quaternion::new(
-            0.0,
-            half_angle.sin(),
-            0.0,
-            half_angle.cos(),
+            T::default(),
+            half_angle.tsin(),
+            T::default(),
+            half_angle.tcos(),
         )
     }
 
     ///Returns a Quaternion from a rotation around the Z axis.
-    pub fn from_z_angle(angle: f32) -> Quaternion {
-        let half_angle = angle/2.0;
+    pub fn from_z_angle(angle: T) -> Quaternion<T> {
+        let half_angle = angle/T::from(2.0);
         Quaternion::new(
-            0.0,
-            0.0,
-            half_angle.sin(),
-            half_angle.cos(),
+            T::default(),
+            T::default(),
+            half_angle.tsin(),
+            half_angle.tcos(),
         )
     }
 
     ///Returns a Quaternion from an euler rotation (x, y and z angles) and a defined rotation order.
-    pub fn from_euler(x: f32, y: f32, z: f32, axis_order: crate::RotationOrder) -> Quaternion {
+    pub fn from_euler(x: T, y: T, z: T, axis_order: crate::RotationOrder) -> Quaternion<T> {
         let mut quats = [Quaternion::identity(); 3];
         for i in 0..3 { 
             quats[i] = match axis_order.0[i] {
@@ -102,51 +106,56 @@ impl Quaternion {
 
     ///Returns a quaternion defining a look at rotation - takes the vector we are looking from, the vector we are looking towards,
     ///the direction we are currently facing, an upwards vector and a forwards vector.
-    pub fn look_at(from: Vector<3>, to: Vector<3>, facing: Vector<3>, up: Vector<3>, forward: Vector<3>) -> Quaternion {
+    pub fn look_at(from: Vector<T, 3>, to: Vector<T, 3>, facing: Vector<T, 3>, up: Vector<T, 3>, forward: Vector<T, 3>) -> Quaternion<T> {
         let diff = (to - from).normalised();
         let mut rot_axis = super::cross(facing, diff).normalised();
-        if rot_axis.sq_sum() == 0.0 { rot_axis = up }
+        if rot_axis.sq_sum() == T::default() { rot_axis = up }
         let dot = forward.dot(to);
-        let ang = dot.acos();
+        let ang = dot.tacos();
         Self::from_axis_angle(rot_axis, ang)
     }
 
     ///Returns a 3 by 3 rotation matrix from a Quaternion.
-    pub fn quat_to_matrix(&self) -> crate::Matrix<3, 3> {
+    pub fn quat_to_matrix(&self) -> Matrix<T, 3, 3> {
         let q0 = self.w;
         let q1 = self.x;
         let q2 = self.y;
         let q3 = self.z;
-        crate::Matrix::<3, 3>::new([
+        let (one, two) = (T::identity(), T::from(2.0));
+        Matrix::<T, 3, 3>::new([
             [
-                2.0*(q0*q0 + q1*q1) - 1.0,
-                2.0*(q1*q2 - q0*q3),
-                2.0*(q1*q3 + q0*q2)
+                two*(q0*q0 + q1*q1) - one,
+                two*(q1*q2 - q0*q3),
+                two*(q1*q3 + q0*q2)
             ],
             [
-                2.0*(q1*q2 + q0*q3),
-                2.0*(q0*q0 + q2*q2) - 1.0,
-                2.0*(q2*q3 - q0*q1)
+                two*(q1*q2 + q0*q3),
+                two*(q0*q0 + q2*q2) - one,
+                two*(q2*q3 - q0*q1)
             ],
             [
-                2.0*(q1*q3 - q0*q2),
-                2.0*(q2*q3 + q0*q1),
-                2.0*(q0*q0 + q3*q3) - 1.0
+                two*(q1*q3 - q0*q2),
+                two*(q2*q3 + q0*q1),
+                two*(q0*q0 + q3*q3) - one
             ],
         ])
     }
     pub fn normalise(&self) -> Self {
-        self * (1.0/self.mag())
+        self * (T::identity()/self.mag())
     }
 }
-impl Magnitude for Quaternion {
-    type Output = f32;
-    fn mag(&self) -> f32 { Vector::<4>::from(*self).mag() }
+impl Quaternion<f64> {
+    ///Lossy conversion to a single precision Quaternion.
+    pub fn to_f32(&self) -> Quaternion<f32> { Quaternion::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32) }
+}
+impl<T: Float> Magnitude for Quaternion<T> {
+    type Output = T;
+    fn mag(&self) -> T { Vector::<T, 4>::from(*self).mag() }
 }
 
-impl Mul<Quaternion> for Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: Quaternion) -> Self {
+impl<T: Float> Mul<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: Quaternion<T>) -> Self {
         Quaternion::new(
             self.w*other.x - self.z*other.y + self.y*other.z + self.x*other.w,
             self.z*other.x + self.w*other.y - self.x*other.z + self.y*other.w,
@@ -155,140 +164,154 @@ impl Mul<Quaternion> for Quaternion {
         )
     }
 }
-impl Mul<&Quaternion> for Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: &Quaternion) -> Quaternion { self * *other }
+impl<T: Float> Mul<&Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: &Quaternion<T>) -> Quaternion<T> { self * *other }
 }
-impl Mul<Quaternion> for &Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: Quaternion) -> Quaternion { *self * other }
+impl<T: Float> Mul<Quaternion<T>> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: Quaternion<T>) -> Quaternion<T> { *self * other }
 }
-impl Mul<&Quaternion> for &Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: &Quaternion) -> Quaternion { *self * *other }
+impl<T: Float> Mul<&Quaternion<T>> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: &Quaternion<T>) -> Quaternion<T> { *self * *other }
 }
-impl Mul<f32> for Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: f32) -> Self {
+impl<T: Float> Mul<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: T) -> Self {
         Self::new(self.x*other, self.y*other, self.z*other, self.w*other)
     }
 }
-impl Mul<&f32> for Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: &f32) -> Quaternion { self * *other }
+impl<T: Float> Mul<&T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: &T) -> Quaternion<T> { self * *other }
 }
-impl Mul<f32> for &Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: f32) -> Quaternion { *self * other }
+impl<T: Float> Mul<T> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: T) -> Quaternion<T> { *self * other }
 }
-impl Mul<&f32> for &Quaternion {
-    type Output = Quaternion;
-    fn mul(self, other: &f32) -> Quaternion { *self * *other }
+impl<T: Float> Mul<&T> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: &T) -> Quaternion<T> { *self * *other }
 }
-impl Mul<Quaternion> for f32 {
-    type Output = Quaternion;
-    fn mul(self, other: Quaternion) -> Quaternion {
-        Quaternion::new(other.x*self, other.y*self, other.z*self, other.w*self)
-    }
+impl Mul<Quaternion<f32>> for f32 {
+    type Output = Quaternion<f32>;
+    fn mul(self, other: Quaternion<f32>) -> Quaternion<f32> { other * self }
+}
+impl Mul<&Quaternion<f32>> for f32 {
+    type Output = Quaternion<f32>;
+    fn mul(self, other: &Quaternion<f32>) -> Quaternion<f32> { self * *other }
 }
-impl Mul<&Quaternion> for f32 {
-    type Output = Quaternion;
-    fn mul(self, other: &Quaternion) -> Quaternion { self * *other }
+impl Mul<Quaternion<f32>> for &f32 {
+    type Output = Quaternion<f32>;
+    fn mul(self, other: Quaternion<f32>) -> Quaternion<f32> { *self * other }
 }
-impl Mul<Quaternion> for &f32 {
-    type Output = Quaternion;
-    fn mul(self, other: Quaternion) -> Quaternion { *self * other }
+impl Mul<&Quaternion<f32>> for &f32 {
+    type Output = Quaternion<f32>;
+    fn mul(self, other: &Quaternion<f32>) -> Quaternion<f32> { *self * *other }
 }
-impl Mul<&Quaternion> for &f32 {
-    type Output = Quaternion;
-    fn mul(self, other: &Quaternion) -> Quaternion { *self * *other }
+impl Mul<Quaternion<f64>> for f64 {
+    type Output = Quaternion<f64>;
+    fn mul(self, other: Quaternion<f64>) -> Quaternion<f64> { other * self }
 }
-impl Add<Quaternion> for Quaternion {
-    type Output = Quaternion;
-    fn add(self, other: Quaternion) -> Self {
+impl Mul<&Quaternion<f64>> for f64 {
+    type Output = Quaternion<f64>;
+    fn mul(self, other: &Quaternion<f64>) -> Quaternion<f64> { self * *other }
+}
+impl Mul<Quaternion<f64>> for &f64 {
+    type Output = Quaternion<f64>;
+    fn mul(self, other: Quaternion<f64>) -> Quaternion<f64> { *self * other }
+}
+impl Mul<&Quaternion<f64>> for &f64 {
+    type Output = Quaternion<f64>;
+    fn mul(self, other: &Quaternion<f64>) -> Quaternion<f64> { *self * *other }
+}
+impl<T: Float> Add<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn add(self, other: Quaternion<T>) -> Self {
         Quaternion::new(
             self.x + other.x, self.y + other.y, self.z + other.z, self.w + other.w
         )
     }
 }
-impl Add<&Quaternion> for Quaternion {
-    type Output = Quaternion;
-    fn add(self, other: &Quaternion) -> Quaternion { self + *other }
+impl<T: Float> Add<&Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn add(self, other: &Quaternion<T>) -> Quaternion<T> { self + *other }
 }
-impl Add<Quaternion> for &Quaternion {
-    type Output = Quaternion;
-    fn add(self, other: Quaternion) -> Quaternion { *self + other }
+impl<T: Float> Add<Quaternion<T>> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn add(self, other: Quaternion<T>) -> Quaternion<T> { *self + other }
 }
-impl Add<&Quaternion> for &Quaternion {
-    type Output = Quaternion;
-    fn add(self, other: &Quaternion) -> Quaternion { *self + *other }
+impl<T: Float> Add<&Quaternion<T>> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn add(self, other: &Quaternion<T>) -> Quaternion<T> { *self + *other }
 }
-impl MulAssign<Quaternion> for Quaternion {
-    fn mul_assign(&mut self, other: Quaternion) { *self = *self * other }
+impl<T: Float> MulAssign<Quaternion<T>> for Quaternion<T> {
+    fn mul_assign(&mut self, other: Quaternion<T>) { *self = *self * other }
 }
-impl MulAssign<Quaternion> for &mut Quaternion {
-    fn mul_assign(&mut self, other: Quaternion) { **self = **self * other }
+impl<T: Float> MulAssign<Quaternion<T>> for &mut Quaternion<T> {
+    fn mul_assign(&mut self, other: Quaternion<T>) { **self = **self * other }
 }
-impl MulAssign<&Quaternion> for Quaternion {
-    fn mul_assign(&mut self, other: &Quaternion) { *self = *self * other }
+impl<T: Float> MulAssign<&Quaternion<T>> for Quaternion<T> {
+    fn mul_assign(&mut self, other: &Quaternion<T>) { *self = *self * other }
 }
-impl MulAssign<&Quaternion> for &mut Quaternion {
-    fn mul_assign(&mut self, other: &Quaternion) { **self = **self * other }
+impl<T: Float> MulAssign<&Quaternion<T>> for &mut Quaternion<T> {
+    fn mul_assign(&mut self, other: &Quaternion<T>) { **self = **self * other }
 }
-impl MulAssign<f32> for Quaternion {
-    fn mul_assign(&mut self, other: f32) { *self = *self * other }
+impl<T: Float> MulAssign<T> for Quaternion<T> {
+    fn mul_assign(&mut self, other: T) { *self = *self * other }
 }
-impl MulAssign<f32> for &mut Quaternion {
-    fn mul_assign(&mut self, other: f32) { **self = **self * other }
+impl<T: Float> MulAssign<T> for &mut Quaternion<T> {
+    fn mul_assign(&mut self, other: T) { **self = **self * other }
 }
-impl MulAssign<&f32> for Quaternion {
-    fn mul_assign(&mut self, other: &f32) { *self = *self * other }
+impl<T: Float> MulAssign<&T> for Quaternion<T> {
+    fn mul_assign(&mut self, other: &T) { *self = *self * other }
 }
-impl MulAssign<&f32> for &mut Quaternion {
-    fn mul_assign(&mut self, other: &f32) { **self = **self * other }
+impl<T: Float> MulAssign<&T> for &mut Quaternion<T> {
+    fn mul_assign(&mut self, other: &T) { **self = **self * other }
 }
-impl AddAssign<Quaternion> for Quaternion {
-    fn add_assign(&mut self, other: Quaternion) { *self = *self + other }
+impl<T: Float> AddAssign<Quaternion<T>> for Quaternion<T> {
+    fn add_assign(&mut self, other: Quaternion<T>) { *self = *self + other }
 }
-impl AddAssign<Quaternion> for &mut Quaternion {
-    fn add_assign(&mut self, other: Quaternion) { **self = **self + other }
+impl<T: Float> AddAssign<Quaternion<T>> for &mut Quaternion<T> {
+    fn add_assign(&mut self, other: Quaternion<T>) { **self = **self + other }
 }
-impl AddAssign<&Quaternion> for Quaternion {
-    fn add_assign(&mut self, other: &Quaternion) { *self = *self + other }
+impl<T: Float> AddAssign<&Quaternion<T>> for Quaternion<T> {
+    fn add_assign(&mut self, other: &Quaternion<T>) { *self = *self + other }
 }
-impl AddAssign<&Quaternion> for &mut Quaternion {
-    fn add_assign(&mut self, other: &Quaternion) { **self = **self + other }
+impl<T: Float> AddAssign<&Quaternion<T>> for &mut Quaternion<T> {
+    fn add_assign(&mut self, other: &Quaternion<T>) { **self = **self + other }
 }
 
 ///Dual Quaternion type - these can represent all rigid 3D combinations of a translation and a rotation. Useful for 
 ///transformations that do not require scaling, such as camera transformations or skeletal animation. See https://cs.gmu.edu/~jmlien/teaching/cs451/uploads/Main/dual-quaternion.pdf
 ///and https://faculty.sites.iastate.edu/jia/files/inline-files/dual-quaternion.pdf for the resources I used to research + build this.
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct DualQuaternion {
-    pub real: Quaternion,
-    pub dual: Quaternion
+pub struct DualQuaternion<T> {
+    pub real: Quaternion<T>,
+    pub dual: Quaternion<T>
 }
-impl DualQuaternion {
-    pub fn new(data: [f32; 8]) -> Self {
+impl<T: Float> DualQuaternion<T> {
+    pub fn new(data: [T; 8]) -> Self {
         Self { 
             real: Quaternion::new(data[0], data[1], data[2], data[3]), 
             dual: Quaternion::new(data[4], data[5], data[6], data[7])
         }
     }
     ///Returns a new Dual Quaternion from two separate Quaternions.
-    pub fn from_quats(a: Quaternion, b: Quaternion) -> Self {
+    pub fn from_quats(a: Quaternion<T>, b: Quaternion<T>) -> Self {
         Self { real: a, dual: b }
     }
     ///Adds two Dual Quaternions - helper function for implementing ops.
-    pub fn adds(&self, other: DualQuaternion) -> Self {
+    pub fn adds(&self, other: DualQuaternion<T>) -> Self {
         Self { real: self.real + other.real, dual: self.dual + other.dual }
     }
-    ///Multiplies a Dual Quaternion by a Scalar f32 - helper function for implementing ops.
-    pub fn scalar_mul(&self, other: f32) -> Self {
+    ///Multiplies a Dual Quaternion by a Scalar - helper function for implementing ops.
+    pub fn scalar_mul(&self, other: T) -> Self {
         Self { real: self.real * other, dual: self.dual * other }
     }
     ///Multiplies two Dual Quaternions - helper function for implementing ops.
-    pub fn muls(&self, other: DualQuaternion) -> Self {
+    pub fn muls(&self, other: DualQuaternion<T>) -> Self {
         Self {
             real: self.real * other.real,
             dual: self.real * other.dual + self.dual * other.real
@@ -297,7 +320,7 @@ impl DualQuaternion {
     pub fn inverse(&self) -> Self {
         Self::from_quats(
             self.real.conjugate(),
-            -1.0 * self.real.conjugate() * self.dual * self.real.conjugate()
+            self.real.conjugate() * self.dual * self.real.conjugate() * -T::identity()
         ).normalise()
     }
     ///Returns the Conjugate of the Dual Quaternion. Note - there are three different ways of taking the conjugate of a
@@ -306,12 +329,12 @@ impl DualQuaternion {
     pub fn conjugate(&self) -> Self {
         Self {
             real: self.real.conjugate(),
-            dual: -1.0 * self.dual.conjugate()
+            dual: self.dual.conjugate() * -T::identity()
         }
     }
     ///Returns the Dual Conjugate of the Dual Quaternion. For a Dual Quaternion Q + eR, this is Q - eR - i.e. the sign of the dual part is flipped.
     pub fn dual_conjugate(&self) -> Self {
-        Self { real: self.real, dual: -1.0 * self.dual }
+        Self { real: self.real, dual: self.dual * -T::identity() }
     }
     ///Returns the Quaternion Conjugate of the Dual Quaternion. For a Dual Quaternion Q + eR, this is Q* + eR* - i.e. both quaternions are conjugated.
     pub fn quaternion_conjugate(&self) -> Self {
@@ -319,177 +342,203 @@ impl DualQuaternion {
     }
     ///Returns the magnitude of the Dual Quaternion - this is calculated as the product of the Dual Quaternion and its quaternion conjugate.
     ///This returns a dual scalar - a Dual Quaternion is unit if the first part is 1 and the second part is 0.
-    pub fn magnitude(&self) -> (f32, f32) {
-        let v4 = Vector::<4>::from(self.real);
-        let d4 = Vector::<4>::from(self.dual);
-        (v4.mag(), 2.0 * v4.dot(d4))
+    pub fn magnitude(&self) -> (T, T) {
+        let v4 = Vector::<T, 4>::from(self.real);
+        let d4 = Vector::<T, 4>::from(self.dual);
+        (v4.mag(), T::from(2.0) * v4.dot(d4))
     }
     pub fn normalise(&self) -> Self {
         let mag = self.magnitude().0;
-        Self::from_quats(self.real*(1.0/mag), self.dual*(1.0/mag))
+        Self::from_quats(self.real*(T::identity()/mag), self.dual*(T::identity()/mag))
     }
     ///Returns a Dual Quaternion from a Quaternion rotation
-    pub fn from_quat(q: Quaternion) -> Self {
+    pub fn from_quat(q: Quaternion<T>) -> Self {
         Self::from_quats(q, Quaternion::default())
     }
     ///Returns a Dual Quaternion from a Vector<3> translation.
-    pub fn from_translate(t: Vector<3>) -> Self {
+    pub fn from_translate(t: Vector<T, 3>) -> Self {
+        let two = T::from(2.0);
         Self::from_quats(
             Quaternion::identity(),
-            Quaternion::new(t[0]/2.0, t[1]/2.0, t[2]/2.0, 0.0)
+            Quaternion::new(t[0]/two, t[1]/two, t[2]/two, T::default())
         )
     }
     ///Returns a Dual Quaternion representing a Quaternion rotation followed by a Vector<3> translation.
-    pub fn from_rotate_translate(q: Quaternion, t: Vector<3>) -> Self {
+    pub fn from_rotate_translate(q: Quaternion<T>, t: Vector<T, 3>) -> Self {
         Self::from_quats(
             q,
-            0.5 * (Quaternion::new(t[0], t[1], t[2], 1.0) * q)
+            (Quaternion::new(t[0], t[1], t[2], T::identity()) * q) * T::from(0.5)
         )
     }
     ///Returns a Dual Quaternion representing a Vector<3> translation followed by a Quaternion rotation.
-    pub fn from_translate_rotate(t: Vector<3>, q: Quaternion) -> Self {
+    pub fn from_translate_rotate(t: Vector<T, 3>, q: Quaternion<T>) -> Self {
         Self::from_quats(
             q,
-            0.5 * (q * Quaternion::new(t[0], t[1], t[2], 1.0))
+            (q * Quaternion::new(t[0], t[1], t[2], T::identity())) * T::from(0.5)
         )
     }
-    pub fn to_matrix(&self) -> Matrix::<4, 4> { 
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> { 
         let t = self.translation();
         from_trs(
-            Vector::<3>::new([t[0], t[1], t[2]]),
+            Vector::<T, 3>::new([t[0], t[1], t[2]]),
             self.rotation(),
-            Vector::<3>::new([1.0, 1.0, 1.0])
+            Vector::<T, 3>::identity()
         )
     }
     ///Returns the rotation only of a Dual Quaternion.
-    pub fn rotation(&self) -> Quaternion {
+    pub fn rotation(&self) -> Quaternion<T> {
         self.real
     }
     ///Returns the translation only of a Dual Quaternion.
-    pub fn translation(&self) -> Vector<4> {
-        Vector::<4>::from(2.0 * (self.dual * self.real.conjugate()))
+    pub fn translation(&self) -> Vector<T, 4> {
+        Vector::<T, 4>::from((self.dual * self.real.conjugate()) * T::from(2.0))
     }
     ///Applies the Dual Quaternion to a Vector<4> point.
-    pub fn transform(&self, point: Vector<4>) -> Vector<4> {
+    pub fn transform(&self, point: Vector<T, 4>) -> Vector<T, 4> {
         let dual = Self::from_quats(Quaternion::identity(), Quaternion::from(point));
         //eprintln!("Made Dual: {:?}", dual);
         //eprintln!("New Dual: {:?}", self.mul(dual));
         //eprintln!("Conjugate: {:?}", self.conjugate());
         let new = self.mul(dual).mul(self.conjugate());
-        Vector::<4>::from(new.dual)
+        Vector::<T, 4>::from(new.dual)
     }
 }
+impl DualQuaternion<f64> {
+    ///Lossy conversion to a single precision Dual Quaternion.
+    pub fn to_f32(&self) -> DualQuaternion<f32> { DualQuaternion::from_quats(self.real.to_f32(), self.dual.to_f32()) }
+}
+impl From<DualQuaternion<f32>> for DualQuaternion<f64> {
+    fn from(other: DualQuaternion<f32>) -> Self { Self::from_quats(Quaternion::from(other.real), Quaternion::from(other.dual)) }
+}
 
-impl Magnitude for DualQuaternion {
-    type Output = (f32, f32);
-    fn mag(&self) -> (f32, f32) { self.magnitude() }
+impl<T: Float> Magnitude for DualQuaternion<T> {
+    type Output = (T, T);
+    fn mag(&self) -> (T, T) { self.magnitude() }
 }
 
-impl Mul<DualQuaternion> for DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: DualQuaternion) -> Self {
+impl<T: Float> Mul<DualQuaternion<T>> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: DualQuaternion<T>) -> Self {
         self.muls(other)
     }
 }
-impl Mul<&DualQuaternion> for DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: &DualQuaternion) -> DualQuaternion { self * *other }
+impl<T: Float> Mul<&DualQuaternion<T>> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: &DualQuaternion<T>) -> DualQuaternion<T> { self * *other }
 }
-impl Mul<DualQuaternion> for &DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: DualQuaternion) -> DualQuaternion { *self * other }
+impl<T: Float> Mul<DualQuaternion<T>> for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: DualQuaternion<T>) -> DualQuaternion<T> { *self * other }
 }
-impl Mul<&DualQuaternion> for &DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: &DualQuaternion) -> DualQuaternion { *self * *other }
+impl<T: Float> Mul<&DualQuaternion<T>> for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: &DualQuaternion<T>) -> DualQuaternion<T> { *self * *other }
 }
-impl Mul<f32> for DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: f32) -> Self {
+impl<T: Float> Mul<T> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: T) -> Self {
         self.scalar_mul(other)
     }
 }
-impl Mul<&f32> for DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: &f32) -> DualQuaternion { self * *other }
+impl<T: Float> Mul<&T> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: &T) -> DualQuaternion<T> { self * *other }
+}
+impl<T: Float> Mul<T> for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: T) -> DualQuaternion<T> { *self * other }
+}
+impl<T: Float> Mul<&T> for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn mul(self, other: &T) -> DualQuaternion<T> { *self * *other }
+}
+impl Mul<DualQuaternion<f32>> for f32 {
+    type Output = DualQuaternion<f32>;
+    fn mul(self, other: DualQuaternion<f32>) -> DualQuaternion<f32> {
+        other.scalar_mul(self)
+    }
+}
+impl Mul<&DualQuaternion<f32>> for f32 {
+    type Output = DualQuaternion<f32>;
+    fn mul(self, other: &DualQuaternion<f32>) -> DualQuaternion<f32> { self * *other }
 }
-impl Mul<f32> for &DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: f32) -> DualQuaternion { *self * other }
+impl Mul<DualQuaternion<f32>> for &f32 {
+    type Output = DualQuaternion<f32>;
+    fn mul(self, other: DualQuaternion<f32>) -> DualQuaternion<f32> { *self * other }
 }
-impl Mul<&f32> for &DualQuaternion {
-    type Output = DualQuaternion;
-    fn mul(self, other: &f32) -> DualQuaternion { *self * *other }
+impl Mul<&DualQuaternion<f32>> for &f32 {
+    type Output = DualQuaternion<f32>;
+    fn mul(self, other: &DualQuaternion<f32>) -> DualQuaternion<f32> { *self * *other }
 }
-impl Mul<DualQuaternion> for f32 {
-    type Output = DualQuaternion;
-    fn mul(self, other: DualQuaternion) -> DualQuaternion {
+impl Mul<DualQuaternion<f64>> for f64 {
+    type Output = DualQuaternion<f64>;
+    fn mul(self, other: DualQuaternion<f64>) -> DualQuaternion<f64> {
         other.scalar_mul(self)
     }
 }
-impl Mul<&DualQuaternion> for f32 {
-    type Output = DualQuaternion;
-    fn mul(self, other: &DualQuaternion) -> DualQuaternion { self * *other }
+impl Mul<&DualQuaternion<f64>> for f64 {
+    type Output = DualQuaternion<f64>;
+    fn mul(self, other: &DualQuaternion<f64>) -> DualQuaternion<f64> { self * *other }
 }
-impl Mul<DualQuaternion> for &f32 {
-    type Output = DualQuaternion;
-    fn mul(self, other: DualQuaternion) -> DualQuaternion { *self * other }
+impl Mul<DualQuaternion<f64>> for &f64 {
+    type Output = DualQuaternion<f64>;
+    fn mul(self, other: DualQuaternion<f64>) -> DualQuaternion<f64> { *self * other }
 }
-impl Mul<&DualQuaternion> for &f32 {
-    type Output = DualQuaternion;
-    fn mul(self, other: &DualQuaternion) -> DualQuaternion { *self * *other }
+impl Mul<&DualQuaternion<f64>> for &f64 {
+    type Output = DualQuaternion<f64>;
+    fn mul(self, other: &DualQuaternion<f64>) -> DualQuaternion<f64> { *self * *other }
 }
-impl Add<DualQuaternion> for DualQuaternion {
-    type Output = DualQuaternion;
-    fn add(self, other: DualQuaternion) -> Self {
+impl<T: Float> Add<DualQuaternion<T>> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn add(self, other: DualQuaternion<T>) -> Self {
         self.adds(other)
     }
 }
-impl Add<&DualQuaternion> for DualQuaternion {
-    type Output = DualQuaternion;
-    fn add(self, other: &DualQuaternion) -> DualQuaternion { self + *other }
+impl<T: Float> Add<&DualQuaternion<T>> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn add(self, other: &DualQuaternion<T>) -> DualQuaternion<T> { self + *other }
 }
-impl Add<DualQuaternion> for &DualQuaternion {
-    type Output = DualQuaternion;
-    fn add(self, other: DualQuaternion) -> DualQuaternion { *self + other }
+impl<T: Float> Add<DualQuaternion<T>> for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn add(self, other: DualQuaternion<T>) -> DualQuaternion<T> { *self + other }
 }
-impl Add<&DualQuaternion> for &DualQuaternion {
-    type Output = DualQuaternion;
-    fn add(self, other: &DualQuaternion) -> DualQuaternion { *self + *other }
+impl<T: Float> Add<&DualQuaternion<T>> for &DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+    fn add(self, other: &DualQuaternion<T>) -> DualQuaternion<T> { *self + *other }
 }
-impl MulAssign<DualQuaternion> for DualQuaternion {
-    fn mul_assign(&mut self, other: DualQuaternion) { *self = *self * other }
+impl<T: Float> MulAssign<DualQuaternion<T>> for DualQuaternion<T> {
+    fn mul_assign(&mut self, other: DualQuaternion<T>) { *self = *self * other }
 }
-impl MulAssign<DualQuaternion> for &mut DualQuaternion {
-    fn mul_assign(&mut self, other: DualQuaternion) { **self = **self * other }
+impl<T: Float> MulAssign<DualQuaternion<T>> for &mut DualQuaternion<T> {
+    fn mul_assign(&mut self, other: DualQuaternion<T>) { **self = **self * other }
 }
-impl MulAssign<&DualQuaternion> for DualQuaternion {
-    fn mul_assign(&mut self, other: &DualQuaternion) { *self = *self * other }
+impl<T: Float> MulAssign<&DualQuaternion<T>> for DualQuaternion<T> {
+    fn mul_assign(&mut self, other: &DualQuaternion<T>) { *self = *self * other }
 }
-impl MulAssign<&DualQuaternion> for &mut DualQuaternion {
-    fn mul_assign(&mut self, other: &DualQuaternion) { **self = **self * other }
+impl<T: Float> MulAssign<&DualQuaternion<T>> for &mut DualQuaternion<T> {
+    fn mul_assign(&mut self, other: &DualQuaternion<T>) { **self = **self * other }
 }
-impl MulAssign<f32> for DualQuaternion {
-    fn mul_assign(&mut self, other: f32) { *self = *self * other }
+impl<T: Float> MulAssign<T> for DualQuaternion<T> {
+    fn mul_assign(&mut self, other: T) { *self = *self * other }
 }
-impl MulAssign<f32> for &mut DualQuaternion {
-    fn mul_assign(&mut self, other: f32) { **self = **self * other }
+impl<T: Float> MulAssign<T> for &mut DualQuaternion<T> {
+    fn mul_assign(&mut self, other: T) { **self = **self * other }
 }
-impl MulAssign<&f32> for DualQuaternion {
-    fn mul_assign(&mut self, other: &f32) { *self = *self * other }
+impl<T: Float> MulAssign<&T> for DualQuaternion<T> {
+    fn mul_assign(&mut self, other: &T) { *self = *self * other }
 }
-impl MulAssign<&f32> for &mut DualQuaternion {
-    fn mul_assign(&mut self, other: &f32) { **self = **self * other }
+impl<T: Float> MulAssign<&T> for &mut DualQuaternion<T> {
+    fn mul_assign(&mut self, other: &T) { **self = **self * other }
 }
-impl AddAssign<DualQuaternion> for DualQuaternion {
-    fn add_assign(&mut self, other: DualQuaternion) { *self = *self + other }
+impl<T: Float> AddAssign<DualQuaternion<T>> for DualQuaternion<T> {
+    fn add_assign(&mut self, other: DualQuaternion<T>) { *self = *self + other }
 }
-impl AddAssign<DualQuaternion> for &mut DualQuaternion {
-    fn add_assign(&mut self, other: DualQuaternion) { **self = **self + other }
+impl<T: Float> AddAssign<DualQuaternion<T>> for &mut DualQuaternion<T> {
+    fn add_assign(&mut self, other: DualQuaternion<T>) { **self = **self + other }
 }
-impl AddAssign<&DualQuaternion> for DualQuaternion {
-    fn add_assign(&mut self, other: &DualQuaternion) { *self = *self + other }
+impl<T: Float> AddAssign<&DualQuaternion<T>> for DualQuaternion<T> {
+    fn add_assign(&mut self, other: &DualQuaternion<T>) { *self = *self + other }
 }
-impl AddAssign<&DualQuaternion> for &mut DualQuaternion {
-    fn add_assign(&mut self, other: &DualQuaternion) { **self = **self + other }
+impl<T: Float> AddAssign<&DualQuaternion<T>> for &mut DualQuaternion<T> {
+    fn add_assign(&mut self, other: &DualQuaternion<T>) { **self = **self + other }
 }
\ No newline at end of file
@@ -0,0 +1,293 @@
+use std::ops::Mul;
+use crate::num::{Scalar, Float};
+use super::dmatrix::DMatrix;
+use super::dvector::DVector;
+
+///Coordinate format sparse matrix - an unordered list of (row, column, value) triplets, used for assembling a matrix
+///before converting it to CsrMatrix or CscMatrix. Duplicate entries are summed on conversion, so contributions from
+///several elements (e.g. the triangles around a mesh vertex) can be pushed separately.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+///Compressed sparse row matrix - values stored row by row, with row_ptr[i]..row_ptr[i + 1] indexing the column indices
+///and values of row i. Column indices are sorted within each row and never repeated. Fast for matrix-vector products.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CsrMatrix<T> {
+    rows: usize,
+    cols: usize,
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<T>,
+}
+///Compressed sparse column matrix - values stored column by column, with col_ptr[j]..col_ptr[j + 1] indexing the row
+///indices and values of column j. Row indices are sorted within each column and never repeated.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CscMatrix<T> {
+    rows: usize,
+    cols: usize,
+    col_ptr: Vec<usize>,
+    row_idx: Vec<usize>,
+    values: Vec<T>,
+}
+///Result of an iterative solver - the final estimate x, the iterations used, the relative residual |b - Ax|/|b| at
+///that point, and whether it fell below the requested tolerance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IterativeSolution<T> {
+    pub x: DVector<T>,
+    pub iterations: usize,
+    pub residual: T,
+    pub converged: bool,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    ///Returns an empty rows x cols matrix.
+    pub fn new(rows: usize, cols: usize) -> Self { Self { rows, cols, entries: Vec::new() } }
+    ///Adds a value at (row, col) - panics if out of bounds.
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        if row >= self.rows || col >= self.cols {
+            panic!("Out of bounds! Tried to push ({}, {}) to a {}x{} matrix", row, col, self.rows, self.cols);
+        }
+        self.entries.push((row, col, value));
+    }
+    ///Returns the number of stored triplets, including duplicates.
+    pub fn len(&self) -> usize { self.entries.len() }
+    ///Returns true if no triplets have been pushed.
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+    pub fn num_rows(&self) -> usize { self.rows }
+    pub fn num_cols(&self) -> usize { self.cols }
+    ///Converts to compressed sparse row format, summing duplicate entries.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (ptr, idx, values) = Self::compress(self.rows, self.entries.iter().map(|(r, c, v)| (*r, *c, v.clone())).collect());
+        CsrMatrix { rows: self.rows, cols: self.cols, row_ptr: ptr, col_idx: idx, values }
+    }
+    ///Converts to compressed sparse column format, summing duplicate entries.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (ptr, idx, values) = Self::compress(self.cols, self.entries.iter().map(|(r, c, v)| (*c, *r, v.clone())).collect());
+        CscMatrix { rows: self.rows, cols: self.cols, col_ptr: ptr, row_idx: idx, values }
+    }
+    ///Sorts (major, minor, value) triplets and sums duplicates, returning the pointer, index and value arrays.
+    fn compress(major: usize, mut entries: Vec<(usize, usize, T)>) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+        entries.sort_by_key(|e| (e.0, e.1));
+        let (mut ptr, mut idx, mut values) = (vec![0; major + 1], Vec::new(), Vec::<T>::new());
+        let mut last = None;
+        for (a, b, v) in entries {
+            match last == Some((a, b)) {
+                true => *values.last_mut().unwrap() += v,
+                false => {
+                    idx.push(b);
+                    values.push(v);
+                    ptr[a + 1] += 1;
+                }
+            }
+            last = Some((a, b));
+        }
+        for i in 0..major { ptr[i + 1] += ptr[i]; }
+        (ptr, idx, values)
+    }
+}
+impl<T: Scalar> CsrMatrix<T> {
+    pub fn num_rows(&self) -> usize { self.rows }
+    pub fn num_cols(&self) -> usize { self.cols }
+    ///Returns the number of stored (structurally non-zero) entries.
+    pub fn nnz(&self) -> usize { self.values.len() }
+    ///Returns the value at (row, col) - zero if it isn't stored.
+    pub fn get(&self, row: usize, col: usize) -> T {
+        let range = self.row_ptr[row]..self.row_ptr[row + 1];
+        match self.col_idx[range.clone()].binary_search(&col) {
+            Ok(i) => self.values[range.start + i].clone(),
+            Err(_) => T::default(),
+        }
+    }
+    ///Returns the stored (column, value) pairs of a row.
+    pub fn row_entries(&self, row: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.row_ptr[row]..self.row_ptr[row + 1];
+        self.col_idx[range.clone()].iter().copied().zip(self.values[range].iter())
+    }
+    ///Returns the main diagonal as a DVector.
+    pub fn diagonal(&self) -> DVector<T> { DVector::new((0..self.rows.min(self.cols)).map(|i| self.get(i, i)).collect()) }
+    ///Sparse matrix-vector product - panics unless the vector has one component per column.
+    pub fn mul_vec(&self, other: &DVector<T>) -> DVector<T> {
+        if other.len() != self.cols { panic!("Mismatched CsrMatrix and DVector dimensions"); }
+        DVector::new((0..self.rows).map(|i| {
+            self.row_entries(i).fold(T::default(), |a, (j, v)| a + v.clone()*other[j].clone())
+        }).collect())
+    }
+    ///Returns the transpose - the CSR arrays of A are the CSC arrays of A^T, so this is a conversion to CSC.
+    pub fn transpose(&self) -> Self {
+        let t = CscMatrix { rows: self.cols, cols: self.rows, col_ptr: self.row_ptr.clone(), row_idx: self.col_idx.clone(), values: self.values.clone() };
+        t.to_csr()
+    }
+    ///Converts to compressed sparse column format.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let mut coo = CooMatrix::new(self.rows, self.cols);
+        for i in 0..self.rows { for (j, v) in self.row_entries(i) { coo.push(i, j, v.clone()); } }
+        coo.to_csc()
+    }
+    ///Converts to a dense DMatrix.
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut out = DMatrix::zeros(self.rows, self.cols);
+        for i in 0..self.rows { for (j, v) in self.row_entries(i) { out[i][j] = v.clone(); } }
+        out
+    }
+}
+impl<T: Scalar> CscMatrix<T> {
+    pub fn num_rows(&self) -> usize { self.rows }
+    pub fn num_cols(&self) -> usize { self.cols }
+    ///Returns the number of stored (structurally non-zero) entries.
+    pub fn nnz(&self) -> usize { self.values.len() }
+    ///Returns the value at (row, col) - zero if it isn't stored.
+    pub fn get(&self, row: usize, col: usize) -> T {
+        let range = self.col_ptr[col]..self.col_ptr[col + 1];
+        match self.row_idx[range.clone()].binary_search(&row) {
+            Ok(i) => self.values[range.start + i].clone(),
+            Err(_) => T::default(),
+        }
+    }
+    ///Returns the stored (row, value) pairs of a column.
+    pub fn col_entries(&self, col: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.col_ptr[col]..self.col_ptr[col + 1];
+        self.row_idx[range.clone()].iter().copied().zip(self.values[range].iter())
+    }
+    ///Sparse matrix-vector product, scattering each column - panics unless the vector has one component per column.
+    pub fn mul_vec(&self, other: &DVector<T>) -> DVector<T> {
+        if other.len() != self.cols { panic!("Mismatched CscMatrix and DVector dimensions"); }
+        let mut out = DVector::zeros(self.rows);
+        for j in 0..self.cols {
+            for (i, v) in self.col_entries(j) { out[i] += v.clone()*other[j].clone(); }
+        }
+        out
+    }
+    ///Returns the transpose - the CSC arrays of A are the CSR arrays of A^T, so this is a conversion to CSR.
+    pub fn transpose(&self) -> Self {
+        let t = CsrMatrix { rows: self.cols, cols: self.rows, row_ptr: self.col_ptr.clone(), col_idx: self.row_idx.clone(), values: self.values.clone() };
+        t.to_csc()
+    }
+    ///Converts to compressed sparse row format.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let mut coo = CooMatrix::new(self.rows, self.cols);
+        for j in 0..self.cols { for (i, v) in self.col_entries(j) { coo.push(i, j, v.clone()); } }
+        coo.to_csr()
+    }
+    ///Converts to a dense DMatrix.
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut out = DMatrix::zeros(self.rows, self.cols);
+        for j in 0..self.cols { for (i, v) in self.col_entries(j) { out[i][j] = v.clone(); } }
+        out
+    }
+}
+impl<T: Float> CsrMatrix<T> {
+    ///Conjugate Gradient with a Jacobi (diagonal) preconditioner - solves Ax = b for symmetric positive definite A,
+    ///starting from zero. Stops when the relative residual is below tol or after max_iter iterations.
+    pub fn conjugate_gradient(&self, b: &DVector<T>, tol: T, max_iter: usize) -> IterativeSolution<T> {
+        let inv_diag: Vec<T> = self.diagonal().data.into_iter().map(|d| match d == T::default() {
+            true => T::identity(),
+            false => T::identity()/d,
+        }).collect();
+        let precondition = |r: &DVector<T>| DVector::new(r.data.iter().zip(inv_diag.iter()).map(|(r, d)| *r * *d).collect());
+        let b_norm = Self::norm_or_one(b);
+        let mut x = DVector::zeros(self.cols);
+        let mut r = b.clone();
+        let mut z = precondition(&r);
+        let mut p = z.clone();
+        let mut rz = r.dot(&z);
+        for i in 0..max_iter {
+            let residual = r.sq_sum().scalar_sqrt()/b_norm;
+            if residual <= tol { return IterativeSolution { x, iterations: i, residual, converged: true }; }
+            let ap = self.mul_vec(&p);
+            let pap = p.dot(&ap);
+            if pap == T::default() { return self.finish(b, x, i, tol); }
+            let alpha = rz/pap;
+            x = x + p.clone()*alpha;
+            r = r - ap*alpha;
+            z = precondition(&r);
+            let rz_next = r.dot(&z);
+            p = z + p*(rz_next/rz);
+            rz = rz_next;
+        }
+        self.finish(b, x, max_iter, tol)
+    }
+    ///Biconjugate Gradient Stabilised - solves Ax = b for general (non-symmetric) square A, starting from zero. Stops
+    ///when the relative residual is below tol or after max_iter iterations, or early on a breakdown.
+    pub fn bicgstab(&self, b: &DVector<T>, tol: T, max_iter: usize) -> IterativeSolution<T> {
+        let zero = T::default();
+        let b_norm = Self::norm_or_one(b);
+        let mut x = DVector::zeros(self.cols);
+        let mut r = b.clone();
+        let r_hat = r.clone();
+        let (mut rho, mut alpha, mut omega) = (T::identity(), T::identity(), T::identity());
+        let (mut v, mut p) = (DVector::zeros(self.rows), DVector::zeros(self.rows));
+        for i in 0..max_iter {
+            let residual = r.sq_sum().scalar_sqrt()/b_norm;
+            if residual <= tol { return IterativeSolution { x, iterations: i, residual, converged: true }; }
+            let rho_next = r_hat.dot(&r);
+            if rho_next == zero || omega == zero { return self.finish(b, x, i, tol); }
+            let beta = (rho_next/rho)*(alpha/omega);
+            rho = rho_next;
+            p = r.clone() + (p - v.clone()*omega)*beta;
+            v = self.mul_vec(&p);
+            let rv = r_hat.dot(&v);
+            if rv == zero { return self.finish(b, x, i, tol); }
+            alpha = rho/rv;
+            let s = r - v.clone()*alpha;
+            if s.sq_sum().scalar_sqrt()/b_norm <= tol {
+                x = x + p.clone()*alpha;
+                return self.finish(b, x, i + 1, tol);
+            }
+            let t = self.mul_vec(&s);
+            let tt = t.sq_sum();
+            omega = if tt == zero { zero } else { t.dot(&s)/tt };
+            x = x + p.clone()*alpha + s.clone()*omega;
+            r = s - t*omega;
+        }
+        self.finish(b, x, max_iter, tol)
+    }
+    ///Gauss-Seidel iteration - solves Ax = b starting from zero, converging for diagonally dominant or symmetric
+    ///positive definite A. Stops when the relative residual is below tol or after max_iter sweeps.
+    pub fn gauss_seidel(&self, b: &DVector<T>, tol: T, max_iter: usize) -> IterativeSolution<T> {
+        let b_norm = Self::norm_or_one(b);
+        let mut x = DVector::zeros(self.cols);
+        for i in 0..max_iter {
+            let residual = (b.clone() - self.mul_vec(&x)).sq_sum().scalar_sqrt()/b_norm;
+            if residual <= tol { return IterativeSolution { x, iterations: i, residual, converged: true }; }
+            for row in 0..self.rows {
+                let (mut sum, mut diag) = (b[row], T::default());
+                for (j, v) in self.row_entries(row) {
+                    match j == row {
+                        true => diag = *v,
+                        false => sum -= *v * x[j],
+                    }
+                }
+                if diag != T::default() { x[row] = sum/diag; }
+            }
+        }
+        self.finish(b, x, max_iter, tol)
+    }
+    fn norm_or_one(b: &DVector<T>) -> T {
+        let norm = b.sq_sum().scalar_sqrt();
+        if norm == T::default() { T::identity() } else { norm }
+    }
+    fn finish(&self, b: &DVector<T>, x: DVector<T>, iterations: usize, tol: T) -> IterativeSolution<T> {
+        let residual = (b.clone() - self.mul_vec(&x)).sq_sum().scalar_sqrt()/Self::norm_or_one(b);
+        IterativeSolution { x, iterations, residual, converged: residual <= tol }
+    }
+}
+impl<T: Scalar> Mul<&DVector<T>> for &CsrMatrix<T> {
+    type Output = DVector<T>;
+    fn mul(self, other: &DVector<T>) -> DVector<T> { self.mul_vec(other) }
+}
+impl<T: Scalar> Mul<&DVector<T>> for &CscMatrix<T> {
+    type Output = DVector<T>;
+    fn mul(self, other: &DVector<T>) -> DVector<T> { self.mul_vec(other) }
+}
+impl<T: Scalar> From<&DMatrix<T>> for CsrMatrix<T> {
+    fn from(m: &DMatrix<T>) -> Self {
+        let mut coo = CooMatrix::new(m.num_rows(), m.num_cols());
+        for i in 0..m.num_rows() {
+            for j in 0..m.num_cols() { if m[i][j] != T::default() { coo.push(i, j, m[i][j].clone()); } }
+        }
+        coo.to_csr()
+    }
+}
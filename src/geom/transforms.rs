@@ -1,30 +1,30 @@
-use crate::geom::{vector::*, matrix::*};
-use std::f32::consts::PI;
+use super::{vector::Vector, matrix::Matrix};
+use crate::num::Float;
 
 ///Utility functions for generating 4x4 Transformation Matrices.
 pub mod transform_mat {
     use crate::geom::quaternion::Quaternion;
-    use crate::geom::{vector::*, matrix::*};
-    use crate::num::Identity;
+    use crate::geom::{vector::Vector, matrix::Matrix};
+    use crate::num::{Float, Identity};
     ///Returns a transformation matrix based on a 3D vector translation.
-    pub fn from_translate(t: Vector<3>) -> Matrix<4, 4> {
-        let mut mat = Matrix::<4, 4>::identity();
+    pub fn from_translate<T: Float>(t: Vector<T, 3>) -> Matrix<T, 4, 4> {
+        let mut mat = Matrix::<T, 4, 4>::identity();
         mat[0][3] = t[0];
         mat[1][3] = t[1];
         mat[2][3] = t[2];
         mat
     }
     ///Returns a transformation matrix based on a 3D vector scaling.
-    pub fn from_scale(s: Vector<3>) -> Matrix<4, 4> {
-        let mut mat = Matrix::<4, 4>::identity();
+    pub fn from_scale<T: Float>(s: Vector<T, 3>) -> Matrix<T, 4, 4> {
+        let mut mat = Matrix::<T, 4, 4>::identity();
         mat[0][0] = s[0];
         mat[1][1] = s[1];
         mat[2][2] = s[2];
         mat
     }
     ///Returns a transformation matrix based on a Quaternion Rotation.
-    pub fn from_rotate(q: Quaternion) -> Matrix<4, 4> {
-        let mut mat = Matrix::<4, 4>::identity();
+    pub fn from_rotate<T: Float>(q: Quaternion<T>) -> Matrix<T, 4, 4> {
+        let mut mat = Matrix::<T, 4, 4>::identity();
         let rot = q.quat_to_matrix();
         for i in 0..3 {
             for j in 0..3 {
@@ -35,7 +35,7 @@ pub mod transform_mat {
     }
     ///Returns a transformation matrix based on a 3D Translation Vector, a Quaternion Rotation and a
     ///3D Scaling Vector.
-    pub fn from_trs(t: Vector<3>, r: Quaternion, s: Vector<3>) -> Matrix<4, 4> {
+    pub fn from_trs<T: Float>(t: Vector<T, 3>, r: Quaternion<T>, s: Vector<T, 3>) -> Matrix<T, 4, 4> {
         from_translate(t)*from_rotate(r)*from_scale(s)
     }
 }
@@ -59,8 +59,8 @@ impl RotationOrder {
 }
 
 ///Cross product of two 3D Vectors.
-pub fn cross(a: Vector<3>, b: Vector<3>) -> Vector<3> {
-    Vector::<3>::new([
+pub fn cross<T: Float>(a: Vector<T, 3>, b: Vector<T, 3>) -> Vector<T, 3> {
+    Vector::<T, 3>::new([
         a[1]*b[2] - a[2]*b[1],
         a[2]*b[0] - a[0]*b[2],
         a[0]*b[1] - a[1]*b[0]
@@ -68,61 +68,74 @@ pub fn cross(a: Vector<3>, b: Vector<3>) -> Vector<3> {
 }
 
 ///Converts a right-handed Z up coordinate system to a right-handed Y down coordinate system aka Camera Space.
-pub fn to_camera_space() -> Matrix<4, 4> {
-    Matrix::<4, 4>::new(
+pub fn to_camera_space<T: Float>() -> Matrix<T, 4, 4> {
+    let (o, i) = (T::default(), T::identity());
+    Matrix::<T, 4, 4>::new(
         [
-            [1.0, 0.0, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, -1.0, 0.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0]
+            [i, o, o, o],
+            [o, o, i, o],
+            [o, -i, o, o],
+            [o, o, o, i]
         ]
     )
 }
 
 ///Defines a perspective projection.
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct PerspectiveInfo {
-    aspect_ratio: f32,
-    fovy: f32,
-    near: f32,
-    far: f32,
+pub struct PerspectiveInfo<T> {
+    aspect_ratio: T,
+    fovy: T,
+    near: T,
+    far: T,
 }
-impl PerspectiveInfo {
+impl<T: Float> PerspectiveInfo<T> {
     ///Returns perspective info based on aspect_ration, field of view angle and near and far planes.
     pub fn new(
-        aspect_ratio: f32,
-        fovy: f32,
-        near: f32,
-        far: f32,
+        aspect_ratio: T,
+        fovy: T,
+        near: T,
+        far: T,
     ) -> Self
     {
         Self { aspect_ratio, fovy, near, far }
     }
 }
-impl Default for PerspectiveInfo {
-    fn default() -> Self { Self::new(1920.0/1080.0, PI/2.0, 0.001, 100.0) }
+impl<T: Float> Default for PerspectiveInfo<T> {
+    fn default() -> Self { Self::new(T::from(1920.0)/T::from(1080.0), T::pi()/T::from(2.0), T::from(0.001), T::from(100.0)) }
+}
+impl PerspectiveInfo<f64> {
+    ///Lossy conversion to single precision perspective info.
+    pub fn to_f32(&self) -> PerspectiveInfo<f32> {
+        PerspectiveInfo::new(self.aspect_ratio as f32, self.fovy as f32, self.near as f32, self.far as f32)
+    }
+}
+impl From<PerspectiveInfo<f32>> for PerspectiveInfo<f64> {
+    fn from(other: PerspectiveInfo<f32>) -> Self {
+        Self::new(other.aspect_ratio as f64, other.fovy as f64, other.near as f64, other.far as f64)
+    }
 }
 
 ///Returns a 4 by 4 perspective matrix based on perspective info.
-pub fn perspective(p: PerspectiveInfo) -> Matrix<4, 4> {
-    let iar = 1.0/p.aspect_ratio;
-    let t = (p.fovy/2.0).tan();
+pub fn perspective<T: Float>(p: PerspectiveInfo<T>) -> Matrix<T, 4, 4> {
+    let (o, i) = (T::default(), T::identity());
+    let iar = i/p.aspect_ratio;
+    let t = (p.fovy/T::from(2.0)).ttan();
     let d = p.far - p.near;
-    Matrix::<4, 4>::new(
+    Matrix::<T, 4, 4>::new(
         [
-            [iar/t, 0.0, 0.0, 0.0],
-            [0.0, 1.0/t, 0.0, 0.0],
-            [0.0, 0.0, p.far/d, -p.near*d],
-            [0.0, 0.0, 1.0, 0.0]
+            [iar/t, o, o, o],
+            [o, i/t, o, o],
+            [o, o, p.far/d, -p.near*d],
+            [o, o, i, o]
         ]
     )
 }
 
 ///Tries to return a matrix for a camera with a transformation matrix v and perspective info p - will
 ///fail if the transformation matrix cannot be succesfully inverted.
-pub fn camera_matrix(v: Matrix<4, 4>, p: PerspectiveInfo) -> Option<Matrix<4, 4>> {
+pub fn camera_matrix<T: Float>(v: Matrix<T, 4, 4>, p: PerspectiveInfo<T>) -> Option<Matrix<T, 4, 4>> {
     match v.inverse() {
         Some(vi) => Some(to_camera_space() * perspective(p) * vi),
         None => None,
     }
-}
\ No newline at end of file
+}
@@ -4,304 +4,226 @@ use std::fmt::Display;
 use std::collections::{HashMap, HashSet};
 use std::f32::consts::PI;
 use bimap::BiHashMap;
-use crate::num::surd::surd32;
-use crate::num::{Identity, Sqroot, rational::*, Magnitude};
+use crate::num::{Identity, Sqroot, Scalar, rational::*, Magnitude, quadratic::QuadraticSurd, nested::NestedSurd};
 use super::matrix::*;
 
 
-///Vector Type - length of L, components are f32. Indexable by usize index of component.
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Vector<const L: usize> {
-    ///Array of f32 components.
-    pub data: [f32; L],
-}
-impl<const L: usize> Vector<L> {
-    ///Returns a new Vector of length L from an array of f32 with the same length.
-    pub fn new(data: [f32; L]) -> Self { Self { data } }
+///Vector Type - length of L, components are any Scalar type T (f32, f64, r32, r64...). Indexable by usize index of component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Vector<T, const L: usize> {
+    ///Array of T components.
+    pub data: [T; L],
+}
+///Vector type using rational components - allows for higher precision + implementation of
+///Eq and Hash. Length of L, components are r32, indexable by usize index.
+pub type VectorPrecise<const L: usize> = Vector<r32, L>;
+///Vector type using arbitrary precision rational components - exact under any sequence of arithmetic operations,
+///at the cost of heap allocation. Length of L, components are rbig, indexable by usize index.
+pub type VectorBig<const L: usize> = Vector<rbig, L>;
+///Vector type with quadratic surd components a + b*sqrt(n) - exact results of rotating a VectorPrecise by the exact
+///rotation matrices. Length of L, components are QuadraticSurd<r64>, indexable by usize index.
+pub type VectorQuadratic<const L: usize> = Vector<QuadraticSurd<r64>, L>;
+///Vector type with nested surd components a + b*sqrt(r) - exact results of rotating a VectorPrecise by multiples of
+///pi/5. Length of L, components are NestedSurd<r64>, indexable by usize index.
+pub type VectorNested<const L: usize> = Vector<NestedSurd<r64>, L>;
+
+impl<T: Scalar, const L: usize> Vector<T, L> {
+    ///Returns a new Vector of length L from an array of T with the same length.
+    pub fn new(data: [T; L]) -> Self { Self { data } }
     ///Dot product with another Vector of same length.
-    pub fn dot(&self, other: Vector<L>) -> f32 {
-        (0..L).map(|i| self.data[i]*other.data[i]).sum::<f32>()
+    pub fn dot(&self, other: Vector<T, L>) -> T {
+        (0..L).map(|i| self.data[i].clone()*other.data[i].clone()).fold(T::default(), |a, e| a + e)
     }
     ///Returns the Vector as a Matrix with 1 row and L columns - aka the Vector as a
     ///single row matrix.
-    pub fn as_row(&self) -> Matrix<1, L> {
-        Matrix::<1, L>::new([self.data])
+    pub fn as_row(&self) -> Matrix<T, 1, L> {
+        Matrix::<T, 1, L>::new([self.data.clone()])
     }
     ///Returns the Vector as a Matrix with L rows and 1 column - aka the Vector as a
     ///single column matrix.
-    pub fn as_col(&self) -> Matrix<L, 1> {
-        let mut columns = [[0.0]; L];
-        for i in 0..L {
-            columns[i][0] = self.data[i];
-        }
-        Matrix::<L, 1>::new(columns)
+    pub fn as_col(&self) -> Matrix<T, L, 1> {
+        let columns = std::array::from_fn(|i| [self.data[i].clone()]);
+        Matrix::<T, L, 1>::new(columns)
     }
     ///Utility function for adding vectors - used to implement std::ops.
     pub fn vec_add(&self, other: Self) -> Self {
-        let mut data = [0.0; L];
-        for i in 0..L {
-            data[i] = self.data[i] + other.data[i];
-        }
+        let data = std::array::from_fn(|i| self.data[i].clone() + other.data[i].clone());
+        Self { data }
+    }
+    ///Utility function for subtracting vectors - used to implement std::ops.
+    pub fn vec_sub(&self, other: Self) -> Self {
+        let data = std::array::from_fn(|i| self.data[i].clone() - other.data[i].clone());
         Self { data }
     }
     ///Utility function for multiplying vectors - used to implement std::ops.
     pub fn vec_mul(&self, other: Self) -> Self {
-        let mut data = [0.0; L];
-        for i in 0..L {
-            data[i] = self.data[i] * other.data[i];
-        }
+        let data = std::array::from_fn(|i| self.data[i].clone() * other.data[i].clone());
         Self { data }
     }
-    ///Utility function for multiplying a vector by a float - used to implement std::ops.
-    pub fn float_mul(&self, other: f32) -> Self {
-        let mut data = [0.0; L];
-        for i in 0..L {
-            data[i] = self.data[i] * other;
-        }
+    ///Utility function for multiplying a vector by a scalar - used to implement std::ops.
+    pub fn scalar_mul(&self, other: T) -> Self {
+        let data = std::array::from_fn(|i| self.data[i].clone() * other.clone());
         Self { data }
     }
     ///Utility function for getting the reciprocal of the vector, component wise - used to implement std::ops.
     pub fn recip(&self) -> Self {
-        let mut data = [0.0; L];
-        for i in 0..L {
-            data[i] = 1.0/self.data[i];
-        }
+        let data = std::array::from_fn(|i| T::identity()/self.data[i].clone());
         Self { data }
     }
     ///Returns the sum of the components of the vector.
-    pub fn sum(&self) -> f32 {
-        let mut sum = 0.0;
-        for i in 0..L { sum += self[i] }
+    pub fn sum(&self) -> T {
+        let mut sum = T::default();
+        for i in 0..L { sum += self[i].clone() }
         sum
     }
     ///Returns the sum of the squared components of the vector, i.e. the squared magnitude of the vector.
-    pub fn sq_sum(&self) -> f32 {
-        let mut sum = 0.0;
-        for i in 0..L { sum += self[i]*self[i] }
+    pub fn sq_sum(&self) -> T {
+        let mut sum = T::default();
+        for i in 0..L { sum += self[i].clone()*self[i].clone() }
         sum
     }
     ///Returns a normalised copy of the vector.
-    pub fn normalised(&self) -> Self { *self/self.mag() }
-}
-impl<const L: usize> Magnitude for Vector<L> {
-    type Output = f32;
-    fn mag(&self) -> f32 {
-        self.sq_sum().sqrt()
+    pub fn normalised(&self) -> Self { self.clone()/self.sq_sum().scalar_sqrt() }
+    ///Returns a copy of the vector with every component converted to another scalar type.
+    pub fn convert<U: Scalar + From<T>>(&self) -> Vector<U, L> {
+        let data = std::array::from_fn(|i| U::from(self.data[i].clone()));
+        Vector::<U, L>::new(data)
     }
 }
-
-impl<const L: usize> Display for Vector<L> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.data)
-    }
-}
-impl<const L: usize> Default for Vector<L> {
-    fn default() -> Vector<L> { Vector::<L>::new([0.0; L]) }
-}
-impl<const L: usize> Identity for Vector<L> {
-    fn identity() -> Vector<L> { Vector::<L>::new([1.0; L]) }
-}
-impl<const L: usize> Index<usize> for Vector<L> {
-    type Output = f32;
-    fn index(&self, index: usize) -> &f32 { &self.data[index] }
+impl<const L: usize> Vector<f32, L> {
+    ///Utility function for multiplying a vector by a float - used to implement std::ops.
+    pub fn float_mul(&self, other: f32) -> Self { self.scalar_mul(other) }
 }
-impl<const L: usize> IndexMut<usize> for Vector<L> {
-    fn index_mut(&mut self, index: usize) -> &mut f32 { &mut self.data[index] }
+impl<const L: usize> Vector<r32, L> {
+    ///Utility function for multiplying a vector by a rational - used to implement std::ops.
+    pub fn rational_mul(&self, other: r32) -> Self { self.scalar_mul(other) }
 }
-impl<const L: usize> Add<Vector<L>> for Vector<L> {
-    type Output = Vector<L>;
-    fn add(self, other: Vector<L>) -> Self { self.vec_add(other) }
+impl<const L: usize> Vector<r64, L> {
+    ///Returns the exactly normalised vector - every component is a multiple of the same surd 1/sqrt(sum of squares).
+    ///Panics if the vector is zero.
+    pub fn normalised_exact(&self) -> Vector<QuadraticSurd<r64>, L> {
+        let inv_mag = QuadraticSurd::<r64>::sqrt(self.sq_sum()).reciprocal().expect("Tried to normalise a zero vector.");
+        Vector { data: std::array::from_fn(|i| inv_mag * self.data[i]) }
+    }
 }
-
-impl<const L: usize> Mul<Vector<L>> for Vector<L> {
-    type Output = Vector<L>;
-    fn mul(self, other: Vector<L>) -> Self { self.vec_mul(other) }
+impl<T: Scalar, const L: usize> Magnitude for Vector<T, L> {
+    type Output = <T as Sqroot>::Output;
+    fn mag(&self) -> Self::Output {
+        self.sq_sum().sqroot()
+    }
 }
-
-impl<const L: usize> Mul<f32> for Vector<L> {
-    type Output = Vector<L>;
-    fn mul(self, other: f32) -> Self { self.float_mul(other) }
+impl<const L: usize> From<Vector<f32, L>> for Vector<r32, L> {
+    fn from(v: Vector<f32, L>) -> Self { v.convert() }
 }
-
-impl<const L: usize> Mul<Vector<L>> for f32 {
-    type Output = Vector<L>;
-    fn mul(self, other: Vector<L>) -> Vector<L> { other.float_mul(self) }
+impl<const L: usize> From<Vector<r32, L>> for Vector<f32, L> {
+    fn from(v: Vector<r32, L>) -> Self { v.convert() }
 }
-
-impl<const L: usize> Div<f32> for Vector<L> {
-    type Output = Vector<L>;
-    fn div(self, other: f32) -> Self { self * (1.0/other) }
+impl<const L: usize> From<Vector<f32, L>> for Vector<f64, L> {
+    fn from(v: Vector<f32, L>) -> Self { v.convert() }
 }
-
-impl<const L: usize> Sub<Vector<L>> for Vector<L> {
-    type Output = Vector<L>;
-    fn sub(self, other: Vector<L>) -> Self { self + (other*-1.0) }
+impl<const L: usize> From<Vector<r32, L>> for Vector<rbig, L> {
+    fn from(v: Vector<r32, L>) -> Self { v.convert() }
 }
-
-impl<const L: usize> Div<Vector<L>> for Vector<L> {
-    type Output = Vector<L>;
-    fn div(self, other: Vector<L>) -> Self { self * other.recip() }
+impl<const L: usize> From<Vector<f64, L>> for Vector<rbig, L> {
+    fn from(v: Vector<f64, L>) -> Self { v.convert() }
 }
-
-
-///Vector type using rational components - allows for higher precision + implementation of
-///Eq and Hash. Length of L, components are r32, indexable by usize index.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct VectorPrecise<const L: usize> {
-    ///Array of r32 components.
-    pub data: [r32; L],
+impl<const L: usize> From<Vector<rbig, L>> for Vector<f64, L> {
+    fn from(v: Vector<rbig, L>) -> Self { v.convert() }
 }
-impl<const L: usize> VectorPrecise<L> {
-    ///Returns a new Vector of length L from an array of f32 with the same length.
-    pub fn new(data: [r32; L]) -> Self { Self { data } }
-    ///Dot product with another Vector of same length.
-    pub fn dot(&self, other: VectorPrecise<L>) -> r32 {
-        (0..L).map(|i| self.data[i]*other.data[i]).fold(r32::default(), |a, e| a + e)
-    }
-    ///Returns the Vector as a Matrix with 1 row and L columns - aka the Vector as a
-    ///single row matrix.
-    pub fn as_row(&self) -> MatrixPrecise<1, L> {
-        MatrixPrecise::<1, L>::new([self.data])
-    }
-    ///Returns the Vector as a Matrix with L rows and 1 column - aka the Vector as a
-    ///single column matrix.
-    pub fn as_col(&self) -> MatrixPrecise<L, 1> {
-        let mut columns = [[r32::default()]; L];
-        for i in 0..L {
-            columns[i][0] = self.data[i];
-        }
-        MatrixPrecise::<L, 1>::new(columns)
-    }
-    ///Utility function for adding vectors - used to implement std::ops.
-    pub fn vec_add(&self, other: Self) -> Self {
-        let mut data = [r32::default(); L];
-        for i in 0..L {
-            data[i] = self.data[i] + other.data[i];
-        }
-        Self { data }
-    }
-    ///Utility function for multiplying vectors - used to implement std::ops.
-    pub fn vec_mul(&self, other: Self) -> Self {
-        let mut data = [r32::default(); L];
-        for i in 0..L {
-            data[i] = self.data[i] * other.data[i];
-        }
-        Self { data }
-    }
-    ///Utility function for multiplying a vector by a rational - used to implement std::ops.
-    pub fn rational_mul(&self, other: r32) -> Self {
-        let mut data = [r32::default(); L];
-        for i in 0..L {
-            data[i] = self.data[i] * other;
-        }
-        Self { data }
-    }
-    ///Utility function for getting the reciprocal of the vector, component wise - used to implement std::ops.
-    pub fn recip(&self) -> Self {
-        let mut data = [r32::default(); L];
-        for i in 0..L {
-            data[i] = r32::identity()/self.data[i];
-        }
-        Self { data }
-    }
-    ///Returns the sum of the components of the vector.
-    pub fn sum(&self) -> r32 {
-        let mut sum = r32::default();
-        for i in 0..L { sum += self[i] }
-        sum
-    }
-    ///Returns the sum of the squared components of the vector, i.e. the squared magnitude of the vector.
-    pub fn sq_sum(&self) -> r32 {
-        let mut sum = r32::default();
-        for i in 0..L { sum += self[i]*self[i] }
-        sum
-    }
-    ///Returns a normalised copy of the vector.
-    pub fn normalised(&self) -> Self { *self/r32::from(self.mag()) }
+impl<const L: usize> From<Vector<r32, L>> for Vector<QuadraticSurd<r64>, L> {
+    fn from(v: Vector<r32, L>) -> Self { v.convert() }
 }
-impl<const L: usize> Magnitude for VectorPrecise<L> {
-    type Output = surd32;
-    fn mag(&self) -> surd32 {
-        self.sq_sum().sqroot()
-    }
+impl<const L: usize> From<Vector<r32, L>> for Vector<NestedSurd<r64>, L> {
+    fn from(v: Vector<r32, L>) -> Self { v.convert() }
 }
-impl<const L: usize> From<Vector<L>> for VectorPrecise<L> {
-    fn from(v: Vector<L>) -> Self {
-        let mut data = [r32::default(); L];
-        for i in 0..L {
-            data[i] = r32::from(v.data[i]);
-        }
-        Self::new(data)
-    }
+impl<const L: usize> From<Vector<QuadraticSurd<r64>, L>> for Vector<NestedSurd<r64>, L> {
+    fn from(v: Vector<QuadraticSurd<r64>, L>) -> Self { v.convert() }
 }
-impl<const L: usize> From<VectorPrecise<L>> for Vector<L> {
-    fn from(v: VectorPrecise<L>) -> Self {
+impl<const L: usize> Vector<f64, L> {
+    ///Lossy conversion to a single precision Vector.
+    pub fn to_f32(&self) -> Vector<f32, L> {
         let mut data = [0.0; L];
         for i in 0..L {
-            data[i] = f32::from(v.data[i]);
+            data[i] = self.data[i] as f32;
         }
-        Self::new(data)
+        Vector::<f32, L>::new(data)
     }
 }
-impl<const L: usize> Display for VectorPrecise<L> {
+
+impl<T: Scalar, const L: usize> Display for Vector<T, L> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self.data)
     }
 }
-impl<const L: usize> Default for VectorPrecise<L> {
-    fn default() -> VectorPrecise<L> { VectorPrecise::<L>::new([r32::default(); L]) }
+impl<T: Scalar, const L: usize> Default for Vector<T, L> {
+    fn default() -> Vector<T, L> { Vector::<T, L>::new(std::array::from_fn(|_| T::default())) }
 }
-impl<const L: usize> Identity for VectorPrecise<L> {
-    fn identity() -> VectorPrecise<L> { VectorPrecise::<L>::new([r32::identity(); L]) }
+impl<T: Scalar, const L: usize> Identity for Vector<T, L> {
+    fn identity() -> Vector<T, L> { Vector::<T, L>::new(std::array::from_fn(|_| T::identity())) }
 }
-impl<const L: usize> Index<usize> for VectorPrecise<L> {
-    type Output = r32;
-    fn index(&self, index: usize) -> &r32 { &self.data[index] }
+impl<T, const L: usize> Index<usize> for Vector<T, L> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T { &self.data[index] }
 }
-impl<const L: usize> IndexMut<usize> for VectorPrecise<L> {
-    fn index_mut(&mut self, index: usize) -> &mut r32 { &mut self.data[index] }
+impl<T, const L: usize> IndexMut<usize> for Vector<T, L> {
+    fn index_mut(&mut self, index: usize) -> &mut T { &mut self.data[index] }
 }
-impl<const L: usize> Add<VectorPrecise<L>> for VectorPrecise<L> {
-    type Output = VectorPrecise<L>;
-    fn add(self, other: VectorPrecise<L>) -> Self { self.vec_add(other) }
+impl<T: Scalar, const L: usize> Add<Vector<T, L>> for Vector<T, L> {
+    type Output = Vector<T, L>;
+    fn add(self, other: Vector<T, L>) -> Self { self.vec_add(other) }
+}
+
+impl<T: Scalar, const L: usize> Mul<Vector<T, L>> for Vector<T, L> {
+    type Output = Vector<T, L>;
+    fn mul(self, other: Vector<T, L>) -> Self { self.vec_mul(other) }
+}
+
+impl<T: Scalar, const L: usize> Mul<T> for Vector<T, L> {
+    type Output = Vector<T, L>;
+    fn mul(self, other: T) -> Self { self.scalar_mul(other) }
+}
+
+impl<const L: usize> Mul<Vector<f32, L>> for f32 {
+    type Output = Vector<f32, L>;
+    fn mul(self, other: Vector<f32, L>) -> Vector<f32, L> { other.scalar_mul(self) }
 }
 
-impl<const L: usize> Mul<VectorPrecise<L>> for VectorPrecise<L> {
-    type Output = VectorPrecise<L>;
-    fn mul(self, other: VectorPrecise<L>) -> Self { self.vec_mul(other) }
+impl<const L: usize> Mul<Vector<f64, L>> for f64 {
+    type Output = Vector<f64, L>;
+    fn mul(self, other: Vector<f64, L>) -> Vector<f64, L> { other.scalar_mul(self) }
 }
 
-impl<const L: usize> Mul<r32> for VectorPrecise<L> {
-    type Output = VectorPrecise<L>;
-    fn mul(self, other: r32) -> Self { self.rational_mul(other) }
+impl<const L: usize> Mul<Vector<r32, L>> for r32 {
+    type Output = Vector<r32, L>;
+    fn mul(self, other: Vector<r32, L>) -> Vector<r32, L> { other.scalar_mul(self) }
 }
 
-impl<const L: usize> Mul<VectorPrecise<L>> for r32 {
-    type Output = VectorPrecise<L>;
-    fn mul(self, other: VectorPrecise<L>) -> VectorPrecise<L> { other.rational_mul(self) }
+impl<const L: usize> Mul<Vector<r64, L>> for r64 {
+    type Output = Vector<r64, L>;
+    fn mul(self, other: Vector<r64, L>) -> Vector<r64, L> { other.scalar_mul(self) }
 }
 
-impl<const L: usize> Div<r32> for VectorPrecise<L> {
-    type Output = VectorPrecise<L>;
-    fn div(self, other: r32) -> Self { self * (r32::identity()/other) }
+impl<T: Scalar, const L: usize> Div<T> for Vector<T, L> {
+    type Output = Vector<T, L>;
+    fn div(self, other: T) -> Self { self * (T::identity()/other) }
 }
 
-impl<const L: usize> Sub<VectorPrecise<L>> for VectorPrecise<L> {
-    type Output = VectorPrecise<L>;
-    fn sub(self, other: VectorPrecise<L>) -> Self { self + (other*-r32::identity()) }
+impl<T: Scalar, const L: usize> Sub<Vector<T, L>> for Vector<T, L> {
+    type Output = Vector<T, L>;
+    fn sub(self, other: Vector<T, L>) -> Self { self.vec_sub(other) }
 }
 
-impl<const L: usize> Div<VectorPrecise<L>> for VectorPrecise<L> {
-    type Output = VectorPrecise<L>;
-    fn div(self, other: VectorPrecise<L>) -> Self { self * other.recip() }
+impl<T: Scalar, const L: usize> Div<Vector<T, L>> for Vector<T, L> {
+    type Output = Vector<T, L>;
+    fn div(self, other: Vector<T, L>) -> Self { self * other.recip() }
 }
 
-impl Vector<2> {
+impl Vector<f32, 2> {
     pub fn heading(&self) -> f32 {
         self.data[1].atan2(self.data[0])
     }
 }
-impl VectorPrecise<2> {
+impl Vector<r32, 2> {
     pub fn heading(&self) -> r32 {
         r32::from(f32::from(self.data[1]).atan2(f32::from(self.data[0])))
     }
@@ -338,13 +260,13 @@ impl<const N: usize> VectorGraph<N> {
         }
     }
     ///Inserts a vector point into the graph.
-    pub fn insert(&mut self, point: Vector<N>) {
+    pub fn insert(&mut self, point: Vector<f32, N>) {
         let id = self.get_id();
         self.points.insert(id, VectorPrecise::from(point));
         self.connections.insert(id, Vec::new());
     }
     ///Removes a vector point from the graph.
-    pub fn remove(&mut self, point: Vector<N>) -> bool {
+    pub fn remove(&mut self, point: Vector<f32, N>) -> bool {
         if let Some((id, _hv)) = self.points.remove_by_right(&VectorPrecise::from(point)) {
             self.free.push(id); return true;
         } else { return false; }
@@ -352,7 +274,7 @@ impl<const N: usize> VectorGraph<N> {
     ///Creates a connection between two points in the graph. If bidir is true, this connection is
     ///bidirectional - otherwise, point A gets a one-way connection to point B. Returns true if the
     ///points were present in the graph to be connected, false otherwise.
-    pub fn connect(&mut self, point_a: Vector<N>, point_b: Vector<N>, bidir: bool) -> bool {
+    pub fn connect(&mut self, point_a: Vector<f32, N>, point_b: Vector<f32, N>, bidir: bool) -> bool {
         match (self.points.get_by_right(&VectorPrecise::from(point_a)), self.points.get_by_right(&VectorPrecise::from(point_b))) {
             (Some(a), Some(b)) => {
                 match self.connections.get_mut(a) {
@@ -377,7 +299,7 @@ impl<const N: usize> VectorGraph<N> {
     ///Disconnects two points in the graph. If bidir is true, this will remove any connections between
     ///points A and B, but otherwise it will only remove connections going from point A to point B. Returns
     ///true if the points were in the graph and a connection was removed.
-    pub fn disconnect(&mut self, point_a: Vector<N>, point_b: Vector<N>, bidir: bool) -> bool {
+    pub fn disconnect(&mut self, point_a: Vector<f32, N>, point_b: Vector<f32, N>, bidir: bool) -> bool {
         match (self.points.get_by_right(&VectorPrecise::from(point_a)), self.points.get_by_right(&VectorPrecise::from(point_b))) {
             (Some(a), Some(b)) => {
                 let mut found = false;
@@ -406,14 +328,14 @@ impl<const N: usize> VectorGraph<N> {
             _ => false,
         }
     }
-    fn get_point_index(&self, v: Vector<N>) -> Option<usize> {
+    fn get_point_index(&self, v: Vector<f32, N>) -> Option<usize> {
         match self.points.get_by_right(&VectorPrecise::from(v)) {
             Some(id) => Some(*id),
             None => None,
         }
     }
     ///Checks if two points are connected in either direction.
-    pub fn is_connected(&self, a: Vector<N>, b: Vector<N>) -> bool {
+    pub fn is_connected(&self, a: Vector<f32, N>, b: Vector<f32, N>) -> bool {
         match (self.get_point_index(a), self.get_point_index(b)) {
             (Some(a), Some(b)) => {
                 if let Some(v) = self.connections.get(&a) {
@@ -430,7 +352,7 @@ impl<const N: usize> VectorGraph<N> {
     ///Returns an option containing a vector of all points connected to the point. This will return
     ///None if the point is not found in the graph, but will still return an option containing an empty vector
     ///if the point is found and simply has no connections.
-    pub fn neighbours(&self, point: Vector<N>) -> Option<Vec<Vector<N>>> {
+    pub fn neighbours(&self, point: Vector<f32, N>) -> Option<Vec<Vector<f32, N>>> {
         match self.points.get_by_right(&VectorPrecise::from(point)) {
             Some(id) => {
                 match self.connections.get(id) {
@@ -452,7 +374,7 @@ impl<const N: usize> VectorGraph<N> {
         }
     }
     ///Returns a vector containing all points in the graph.
-    pub fn points(&self) -> Vec<Vector<N>> {
+    pub fn points(&self) -> Vec<Vector<f32, N>> {
         self.points.right_values().map(|hv| Vector::from(*hv)).collect::<Vec<_>>()
     }
     
@@ -461,7 +383,7 @@ impl<const N: usize> VectorGraph<N> {
 impl VectorGraph<2> {
     ///Delaunay Triangulation - takes an array of 2 dimensional vectors, returns a connected graph
     ///generated using the Delaunay Triangulation.
-    pub fn triangulate(points: &[Vector<2>]) -> Self {
+    pub fn triangulate(points: &[Vector<f32, 2>]) -> Self {
         let mut vg = Self::new();
 
         let mut p = crate::Vector2::default();
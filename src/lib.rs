@@ -5,15 +5,15 @@ pub mod bit;
 #[cfg(test)]
 pub mod tests;
 
-pub type Matrix2 = geom::matrix::Matrix<2, 2>;
-pub type Matrix3 = geom::matrix::Matrix<3, 3>;
-pub type Matrix4 = geom::matrix::Matrix<4, 4>;
-pub type Vector2 = geom::vector::Vector<2>;
-pub type Vector3 = geom::vector::Vector<3>;
-pub type Vector4 = geom::vector::Vector<4>;
+pub type Matrix2 = geom::matrix::Matrix<f32, 2, 2>;
+pub type Matrix3 = geom::matrix::Matrix<f32, 3, 3>;
+pub type Matrix4 = geom::matrix::Matrix<f32, 4, 4>;
+pub type Vector2 = geom::vector::Vector<f32, 2>;
+pub type Vector3 = geom::vector::Vector<f32, 3>;
+pub type Vector4 = geom::vector::Vector<f32, 4>;
 pub type VectorGraph<const T: usize> = geom::vector::VectorGraph<T>;
-pub type Matrix<const T: usize, const Q: usize> = geom::matrix::Matrix<T, Q>;
-pub type Vector<const T: usize> = geom::vector::Vector<T>;
+pub type Matrix<const T: usize, const Q: usize> = geom::matrix::Matrix<f32, T, Q>;
+pub type Vector<const T: usize> = geom::vector::Vector<f32, T>;
 pub type MatrixPrecise2 = geom::matrix::MatrixPrecise<2, 2>;
 pub type MatrixPrecise3 = geom::matrix::MatrixPrecise<3, 3>;
 pub type MatrixPrecise4 = geom::matrix::MatrixPrecise<4, 4>;
@@ -22,7 +22,46 @@ pub type VectorPrecise3 = geom::vector::VectorPrecise<3>;
 pub type VectorPrecise4 = geom::vector::VectorPrecise<4>;
 pub type MatrixPrecise<const T: usize, const Q: usize> = geom::matrix::MatrixPrecise<T, Q>;
 pub type VectorPrecise<const T: usize> = geom::vector::VectorPrecise<T>;
-pub type PerspectiveInfo = geom::transforms::PerspectiveInfo;
+pub type MatrixBig2 = geom::matrix::MatrixBig<2, 2>;
+pub type MatrixBig3 = geom::matrix::MatrixBig<3, 3>;
+pub type MatrixBig4 = geom::matrix::MatrixBig<4, 4>;
+pub type VectorBig2 = geom::vector::VectorBig<2>;
+pub type VectorBig3 = geom::vector::VectorBig<3>;
+pub type VectorBig4 = geom::vector::VectorBig<4>;
+pub type MatrixBig<const T: usize, const Q: usize> = geom::matrix::MatrixBig<T, Q>;
+pub type VectorBig<const T: usize> = geom::vector::VectorBig<T>;
+pub type MatrixQuadratic2 = geom::matrix::MatrixQuadratic<2, 2>;
+pub type MatrixQuadratic3 = geom::matrix::MatrixQuadratic<3, 3>;
+pub type VectorQuadratic2 = geom::vector::VectorQuadratic<2>;
+pub type VectorQuadratic3 = geom::vector::VectorQuadratic<3>;
+pub type MatrixQuadratic<const T: usize, const Q: usize> = geom::matrix::MatrixQuadratic<T, Q>;
+pub type VectorQuadratic<const T: usize> = geom::vector::VectorQuadratic<T>;
+pub type MatrixNested2 = geom::matrix::MatrixNested<2, 2>;
+pub type MatrixNested3 = geom::matrix::MatrixNested<3, 3>;
+pub type VectorNested2 = geom::vector::VectorNested<2>;
+pub type VectorNested3 = geom::vector::VectorNested<3>;
+pub type MatrixNested<const T: usize, const Q: usize> = geom::matrix::MatrixNested<T, Q>;
+pub type VectorNested<const T: usize> = geom::vector::VectorNested<T>;
+pub type Matrix2d = geom::matrix::Matrix<f64, 2, 2>;
+pub type Matrix3d = geom::matrix::Matrix<f64, 3, 3>;
+pub type Matrix4d = geom::matrix::Matrix<f64, 4, 4>;
+pub type Vector2d = geom::vector::Vector<f64, 2>;
+pub type Vector3d = geom::vector::Vector<f64, 3>;
+pub type Vector4d = geom::vector::Vector<f64, 4>;
+pub type MatrixD<const T: usize, const Q: usize> = geom::matrix::Matrix<f64, T, Q>;
+pub type VectorD<const T: usize> = geom::vector::Vector<f64, T>;
+pub type DMatrix = geom::dmatrix::DMatrix<f32>;
+pub type DVector = geom::dvector::DVector<f32>;
+pub type DMatrixD = geom::dmatrix::DMatrix<f64>;
+pub type DVectorD = geom::dvector::DVector<f64>;
+pub type Quaternion = geom::quaternion::Quaternion<f32>;
+pub type QuaternionD = geom::quaternion::Quaternion<f64>;
+pub type DualQuaternion = geom::quaternion::DualQuaternion<f32>;
+pub type DualQuaternionD = geom::quaternion::DualQuaternion<f64>;
+pub type Spline<const N: usize> = geom::spline::Spline<f32, N>;
+pub type SplineD<const N: usize> = geom::spline::Spline<f64, N>;
+pub type PerspectiveInfo = geom::transforms::PerspectiveInfo<f32>;
+pub type PerspectiveInfoD = geom::transforms::PerspectiveInfo<f64>;
 pub type RotationOrder = geom::transforms::RotationOrder;
 pub type EulerAxis = geom::transforms::EulerAxis;
 pub type Float2 = cg::Float2;
@@ -30,5 +69,7 @@ pub type Float3 = cg::Float3;
 pub type Float4 = cg::Float4;
 #[allow(non_camel_case_types)]
 pub type r32 = num::rational::r32;
+#[allow(non_camel_case_types)]
+pub type rbig = num::rational::rbig;
 //#[allow(non_camel_case_types)]
 //pub type r64 = num::rational::r64;
\ No newline at end of file
@@ -1,6 +1,14 @@
 pub mod rational;
 pub mod factors;
 pub mod surd;
+pub mod bigint;
+pub mod continued_fraction;
+pub mod quadratic;
+pub mod nested;
+pub mod trig;
+pub mod arithmetic;
+pub mod modular;
+pub mod roots;
 
 ///Square Root trait for convenience.
 pub trait Sqroot {
@@ -10,16 +18,17 @@ pub trait Sqroot {
 
 impl Sqroot for f64 { type Output = f64; fn sqroot(&self) -> Self { self.sqrt() } }
 impl Sqroot for f32 { type Output = f32; fn sqroot(&self) -> Self { self.sqrt() } }
-impl Sqroot for u128 { type Output = u128; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as u128 }}
-impl Sqroot for u64 { type Output = u64; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as u64 }}
-impl Sqroot for u32 { type Output = u32; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as u32 }}
-impl Sqroot for u16 { type Output = u16; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as u16 }}
-impl Sqroot for u8 { type Output = u8; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as u8 }}
-impl Sqroot for i128 { type Output = i128; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as i128 }}
-impl Sqroot for i64 { type Output = i64; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as i64 }}
-impl Sqroot for i32 { type Output = i32; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as i32 }}
-impl Sqroot for i16 { type Output = i16; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as i16 }}
-impl Sqroot for i8 { type Output = i8; fn sqroot(&self) -> Self { (*self as f64).sqrt().floor() as i8 }}
+//Integer roots are exact floors - negative signed values give zero.
+impl Sqroot for u128 { type Output = u128; fn sqroot(&self) -> Self { roots::isqrt(*self) }}
+impl Sqroot for u64 { type Output = u64; fn sqroot(&self) -> Self { roots::isqrt(*self) }}
+impl Sqroot for u32 { type Output = u32; fn sqroot(&self) -> Self { roots::isqrt(*self) }}
+impl Sqroot for u16 { type Output = u16; fn sqroot(&self) -> Self { roots::isqrt(*self) }}
+impl Sqroot for u8 { type Output = u8; fn sqroot(&self) -> Self { roots::isqrt(*self) }}
+impl Sqroot for i128 { type Output = i128; fn sqroot(&self) -> Self { roots::isqrt((*self).max(0)) }}
+impl Sqroot for i64 { type Output = i64; fn sqroot(&self) -> Self { roots::isqrt((*self).max(0)) }}
+impl Sqroot for i32 { type Output = i32; fn sqroot(&self) -> Self { roots::isqrt((*self).max(0)) }}
+impl Sqroot for i16 { type Output = i16; fn sqroot(&self) -> Self { roots::isqrt((*self).max(0)) }}
+impl Sqroot for i8 { type Output = i8; fn sqroot(&self) -> Self { roots::isqrt((*self).max(0)) }}
 
 ///Square Root trait for convenience.
 pub trait Magnitude {
@@ -45,6 +54,117 @@ impl Identity for i32 { fn identity() -> Self { 1 }}
 impl Identity for i16 { fn identity() -> Self { 1 }}
 impl Identity for i8 { fn identity() -> Self { 1 }}
 
+///Integer trait - collects what the generic gcd, lcm and factorisation functions in factors need. Implemented for u8
+///through u128, i8 through i128, usize and isize.
+pub trait Integer:
+    Copy + Eq + Ord + Default + std::fmt::Debug + std::fmt::Display
+    + std::ops::Add<Output = Self> + std::ops::Sub<Output = Self> + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self> + std::ops::Rem<Output = Self>
+    + std::ops::Shl<u32, Output = Self> + std::ops::Shr<u32, Output = Self>
+{
+    ///Unsigned integer of the same width.
+    type Unsigned: Integer<Unsigned = Self::Unsigned, Signed = Self::Signed>;
+    ///Signed integer of the same width.
+    type Signed: Integer<Unsigned = Self::Unsigned, Signed = Self::Signed>;
+    const ZERO: Self;
+    const ONE: Self;
+    const BITS: u32;
+    ///Returns the absolute value as the unsigned type, which can't overflow.
+    fn unsigned_abs(self) -> Self::Unsigned;
+    fn is_negative(self) -> bool;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn trailing_zeros(self) -> u32;
+    ///Converts to a u128 - negative values wrap.
+    fn to_u128(self) -> u128;
+    ///Converts from a u128, truncating to the width of the type.
+    fn from_u128(n: u128) -> Self;
+}
+
+impl Integer for u8 {
+    type Unsigned = u8; type Signed = i8;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = u8::BITS;
+    fn unsigned_abs(self) -> u8 { self } fn is_negative(self) -> bool { false }
+    fn checked_mul(self, other: Self) -> Option<Self> { u8::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { u8::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as u8 }
+}
+impl Integer for u16 {
+    type Unsigned = u16; type Signed = i16;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = u16::BITS;
+    fn unsigned_abs(self) -> u16 { self } fn is_negative(self) -> bool { false }
+    fn checked_mul(self, other: Self) -> Option<Self> { u16::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { u16::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as u16 }
+}
+impl Integer for u32 {
+    type Unsigned = u32; type Signed = i32;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = u32::BITS;
+    fn unsigned_abs(self) -> u32 { self } fn is_negative(self) -> bool { false }
+    fn checked_mul(self, other: Self) -> Option<Self> { u32::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { u32::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as u32 }
+}
+impl Integer for u64 {
+    type Unsigned = u64; type Signed = i64;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = u64::BITS;
+    fn unsigned_abs(self) -> u64 { self } fn is_negative(self) -> bool { false }
+    fn checked_mul(self, other: Self) -> Option<Self> { u64::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { u64::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as u64 }
+}
+impl Integer for u128 {
+    type Unsigned = u128; type Signed = i128;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = u128::BITS;
+    fn unsigned_abs(self) -> u128 { self } fn is_negative(self) -> bool { false }
+    fn checked_mul(self, other: Self) -> Option<Self> { u128::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { u128::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self } fn from_u128(n: u128) -> Self { n }
+}
+impl Integer for usize {
+    type Unsigned = usize; type Signed = isize;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = usize::BITS;
+    fn unsigned_abs(self) -> usize { self } fn is_negative(self) -> bool { false }
+    fn checked_mul(self, other: Self) -> Option<Self> { usize::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { usize::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as usize }
+}
+impl Integer for i8 {
+    type Unsigned = u8; type Signed = i8;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = i8::BITS;
+    fn unsigned_abs(self) -> u8 { self.unsigned_abs() } fn is_negative(self) -> bool { self < 0 }
+    fn checked_mul(self, other: Self) -> Option<Self> { i8::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { i8::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as i8 }
+}
+impl Integer for i16 {
+    type Unsigned = u16; type Signed = i16;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = i16::BITS;
+    fn unsigned_abs(self) -> u16 { self.unsigned_abs() } fn is_negative(self) -> bool { self < 0 }
+    fn checked_mul(self, other: Self) -> Option<Self> { i16::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { i16::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as i16 }
+}
+impl Integer for i32 {
+    type Unsigned = u32; type Signed = i32;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = i32::BITS;
+    fn unsigned_abs(self) -> u32 { self.unsigned_abs() } fn is_negative(self) -> bool { self < 0 }
+    fn checked_mul(self, other: Self) -> Option<Self> { i32::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { i32::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as i32 }
+}
+impl Integer for i64 {
+    type Unsigned = u64; type Signed = i64;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = i64::BITS;
+    fn unsigned_abs(self) -> u64 { self.unsigned_abs() } fn is_negative(self) -> bool { self < 0 }
+    fn checked_mul(self, other: Self) -> Option<Self> { i64::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { i64::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as i64 }
+}
+impl Integer for i128 {
+    type Unsigned = u128; type Signed = i128;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = i128::BITS;
+    fn unsigned_abs(self) -> u128 { self.unsigned_abs() } fn is_negative(self) -> bool { self < 0 }
+    fn checked_mul(self, other: Self) -> Option<Self> { i128::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { i128::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as i128 }
+}
+impl Integer for isize {
+    type Unsigned = usize; type Signed = isize;
+    const ZERO: Self = 0; const ONE: Self = 1; const BITS: u32 = isize::BITS;
+    fn unsigned_abs(self) -> usize { self.unsigned_abs() } fn is_negative(self) -> bool { self < 0 }
+    fn checked_mul(self, other: Self) -> Option<Self> { isize::checked_mul(self, other) } fn trailing_zeros(self) -> u32 { isize::trailing_zeros(self) }
+    fn to_u128(self) -> u128 { self as u128 } fn from_u128(n: u128) -> Self { n as isize }
+}
+
 ///Trait for mod_add operation - this adds two items and then modulates them to the range provided by the boundaries argument (inclusive at both ends).
 ///Implemented by default for anything that implements Copy, PartialOrd and Add and Sub for itself.
 pub trait ModuloAdd<T, Output> {
@@ -92,6 +212,53 @@ where T: Absolute<Output=Q> {
     fn mag(&self) -> Q { self.absolute() }
 }
 
+///Scalar trait - collects the number traits needed by the generic Vector and Matrix types, so that
+///f32, f64, r32, r64, rbig and any future number types share the same implementation.
+pub trait Scalar:
+    Clone + Default + PartialEq + PartialOrd + std::fmt::Debug
+    + Identity + Absolute<Output = Self> + Sqroot
+    + std::ops::Add<Output = Self> + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self> + std::ops::Div<Output = Self>
+    + std::ops::Neg<Output = Self>
+    + std::ops::AddAssign + std::ops::SubAssign + std::ops::MulAssign + std::ops::DivAssign
+{
+    ///Returns the tolerance below which a value is treated as zero - exact types return zero.
+    fn epsilon() -> Self;
+    ///Returns the square root as the scalar type itself - this is lossy for types like r32, whose exact root is a surd.
+    fn scalar_sqrt(&self) -> Self;
+}
+
+impl Scalar for f64 { fn epsilon() -> Self { 0.000000000001 } fn scalar_sqrt(&self) -> Self { self.sqrt() } }
+impl Scalar for f32 { fn epsilon() -> Self { 0.00001 } fn scalar_sqrt(&self) -> Self { self.sqrt() } }
+impl Scalar for rational::r64 { fn epsilon() -> Self { Self::default() } fn scalar_sqrt(&self) -> Self { Self::from(self.surd_sqrt()) } }
+impl Scalar for rational::r32 { fn epsilon() -> Self { Self::default() } fn scalar_sqrt(&self) -> Self { Self::from(self.surd_sqrt()) } }
+impl Scalar for rational::rbig {
+    fn epsilon() -> Self { Self::default() }
+    fn scalar_sqrt(&self) -> Self { match self.signum() { 0 => Self::default(), _ => Self::from(self.surd_sqrt()) } }
+}
+
+///Float trait - extends Scalar with the trigonometric functions needed by the floating point geometry types
+///(Quaternion, DualQuaternion, Spline and the transforms). Implemented for f32 and f64.
+pub trait Float: Scalar + Copy + Sqroot<Output = Self> + Round<Output = Self> + From<f32> + Into<f64> {
+    fn pi() -> Self;
+    fn tsin(&self) -> Self;
+    fn tcos(&self) -> Self;
+    fn ttan(&self) -> Self;
+    fn tacos(&self) -> Self;
+    fn tatan2(&self, other: Self) -> Self;
+}
+
+impl Float for f64 {
+    fn pi() -> Self { std::f64::consts::PI }
+    fn tsin(&self) -> Self { self.sin() } fn tcos(&self) -> Self { self.cos() } fn ttan(&self) -> Self { self.tan() }
+    fn tacos(&self) -> Self { self.acos() } fn tatan2(&self, other: Self) -> Self { self.atan2(other) }
+}
+impl Float for f32 {
+    fn pi() -> Self { std::f32::consts::PI }
+    fn tsin(&self) -> Self { self.sin() } fn tcos(&self) -> Self { self.cos() } fn ttan(&self) -> Self { self.tan() }
+    fn tacos(&self) -> Self { self.acos() } fn tatan2(&self, other: Self) -> Self { self.atan2(other) }
+}
+
 pub trait Round {
     type Output;
     fn tround(&self) -> Self::Output;
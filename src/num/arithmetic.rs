@@ -0,0 +1,112 @@
+use super::Identity;
+use super::factors::{gcd128, prime_powers32, prime_powers64, prime_powers128};
+
+///Returns Euler's totient of a u32 - the count of 1 <= k <= n coprime to n. Panics on zero.
+pub fn totient32(n: u32) -> u32 { totient(&widen(prime_powers32(n))) as u32 }
+///Returns Euler's totient of a u64 - the count of 1 <= k <= n coprime to n. Panics on zero.
+pub fn totient64(n: u64) -> u64 { totient(&widen(prime_powers64(n))) as u64 }
+///Returns Euler's totient of a u128 - the count of 1 <= k <= n coprime to n. Panics on zero.
+pub fn totient128(n: u128) -> u128 { totient(&prime_powers128(n)) }
+
+///Returns the number of divisors of a u32. Panics on zero.
+pub fn divisor_count32(n: u32) -> u64 { divisor_count(&widen(prime_powers32(n))) }
+///Returns the number of divisors of a u64. Panics on zero.
+pub fn divisor_count64(n: u64) -> u64 { divisor_count(&widen(prime_powers64(n))) }
+///Returns the number of divisors of a u128. Panics on zero.
+pub fn divisor_count128(n: u128) -> u64 { divisor_count(&prime_powers128(n)) }
+
+///Returns the sum of the divisors of a u32, including n itself. Panics on zero.
+pub fn divisor_sum32(n: u32) -> u64 { divisor_sum(&widen(prime_powers32(n))).unwrap() as u64 }
+///Returns the sum of the divisors of a u64, including n itself. Panics on zero.
+pub fn divisor_sum64(n: u64) -> u128 { divisor_sum(&widen(prime_powers64(n))).unwrap() }
+///Returns the sum of the divisors of a u128, including n itself - None if it overflows a u128. Panics on zero.
+pub fn divisor_sum128(n: u128) -> Option<u128> { divisor_sum(&prime_powers128(n)) }
+
+///Returns the Mobius function of a u32 - 0 if n has a square factor, otherwise -1 or 1 for an odd or even number of
+///prime factors. Panics on zero.
+pub fn mobius32(n: u32) -> i32 { mobius(&widen(prime_powers32(n))) }
+///Returns the Mobius function of a u64 - 0 if n has a square factor, otherwise -1 or 1 for an odd or even number of
+///prime factors. Panics on zero.
+pub fn mobius64(n: u64) -> i32 { mobius(&widen(prime_powers64(n))) }
+///Returns the Mobius function of a u128 - 0 if n has a square factor, otherwise -1 or 1 for an odd or even number of
+///prime factors. Panics on zero.
+pub fn mobius128(n: u128) -> i32 { mobius(&prime_powers128(n)) }
+
+///Returns Carmichael's lambda of a u32 - the smallest m with a^m = 1 mod n for every a coprime to n. Panics on zero.
+pub fn carmichael32(n: u32) -> u32 { carmichael(&widen(prime_powers32(n))) as u32 }
+///Returns Carmichael's lambda of a u64 - the smallest m with a^m = 1 mod n for every a coprime to n. Panics on zero.
+pub fn carmichael64(n: u64) -> u64 { carmichael(&widen(prime_powers64(n))) as u64 }
+///Returns Carmichael's lambda of a u128 - the smallest m with a^m = 1 mod n for every a coprime to n. Panics on zero.
+pub fn carmichael128(n: u128) -> u128 { carmichael(&prime_powers128(n)) }
+
+///Returns an iterator over every divisor of a u32, in no particular order. Panics on zero.
+pub fn divisors32(n: u32) -> Divisors<u32> { Divisors::new(prime_powers32(n)) }
+///Returns an iterator over every divisor of a u64, in no particular order. Panics on zero.
+pub fn divisors64(n: u64) -> Divisors<u64> { Divisors::new(prime_powers64(n)) }
+///Returns an iterator over every divisor of a u128, in no particular order. Panics on zero.
+pub fn divisors128(n: u128) -> Divisors<u128> { Divisors::new(prime_powers128(n)) }
+
+fn widen<T: Into<u128>>(f: Vec<(T, u32)>) -> Vec<(u128, u32)> { f.into_iter().map(|(p, k)| (p.into(), k)).collect() }
+fn totient(f: &[(u128, u32)]) -> u128 { f.iter().map(|(p, k)| p.pow(k - 1) * (p - 1)).product() }
+fn divisor_count(f: &[(u128, u32)]) -> u64 { f.iter().map(|(_p, k)| *k as u64 + 1).product() }
+fn divisor_sum(f: &[(u128, u32)]) -> Option<u128> {
+    //sigma(p^k) = 1 + p + ... + p^k, summed directly as (p^(k+1) - 1)/(p - 1) can overflow when sigma doesn't.
+    f.iter().try_fold(1_u128, |acc, (p, k)| {
+        let (mut term, mut pk) = (1_u128, 1_u128);
+        for _ in 0..*k {
+            pk *= p;
+            term = term.checked_add(pk)?;
+        }
+        acc.checked_mul(term)
+    })
+}
+fn mobius(f: &[(u128, u32)]) -> i32 {
+    match f.iter().any(|(_p, k)| *k > 1) {
+        true => 0,
+        false => match f.len() % 2 { 0 => 1, _ => -1 },
+    }
+}
+fn carmichael(f: &[(u128, u32)]) -> u128 {
+    f.iter().fold(1, |acc, (p, k)| {
+        let lambda = match (*p, *k) {
+            (2, 1) => 1,
+            (2, 2) => 2,
+            (2, k) => 1 << (k - 2),
+            (p, k) => p.pow(k - 1) * (p - 1),
+        };
+        acc / gcd128(acc, lambda) * lambda
+    })
+}
+
+///Iterator over the divisors of a number, built from its prime factorisation - counts through every combination of
+///exponents.
+#[derive(Clone, Debug)]
+pub struct Divisors<T> {
+    powers: Vec<(T, u32)>,
+    exponents: Vec<u32>,
+    done: bool,
+}
+impl<T> Divisors<T> {
+    ///Returns an iterator over the divisors of the product of p^k for each (p, k).
+    pub fn new(powers: Vec<(T, u32)>) -> Self {
+        let exponents = vec![0; powers.len()];
+        Self { powers, exponents, done: false }
+    }
+}
+impl<T: Copy + Identity + std::ops::Mul<Output = T>> Iterator for Divisors<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.done { return None; }
+        let mut d = T::identity();
+        for ((p, _k), e) in self.powers.iter().zip(&self.exponents) {
+            for _ in 0..*e { d = d * *p; }
+        }
+        //Step the exponents like the digits of a mixed radix counter.
+        self.done = true;
+        for ((_p, k), e) in self.powers.iter().zip(self.exponents.iter_mut()) {
+            if *e < *k { *e += 1; self.done = false; break; }
+            *e = 0;
+        }
+        Some(d)
+    }
+}
@@ -0,0 +1,497 @@
+use std::cmp::Ordering;
+use std::ops::*;
+use std::str::FromStr;
+use super::{Identity, Absolute, Sqroot};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseBigError {
+    Empty,
+    InvalidDigit,
+}
+
+const LIMB_BITS: usize = 32;
+///Largest power of ten that fits in a limb - used for decimal conversion.
+const DEC_BASE: u32 = 1_000_000_000;
+const DEC_DIGITS: usize = 9;
+
+///Unsigned arbitrary precision integer - stored as little endian u32 limbs, with no trailing zero limbs
+///(so zero is the empty vector). Grows as needed, so never overflows.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct ubig {
+    limbs: Vec<u32>,
+}
+impl ubig {
+    ///Returns zero.
+    pub fn zero() -> Self { Self { limbs: Vec::new() } }
+    ///Returns a new ubig from little endian u32 limbs.
+    pub fn from_limbs(limbs: Vec<u32>) -> Self { let mut out = Self { limbs }; out.trim(); out }
+    ///Returns the little endian u32 limbs.
+    pub fn limbs(&self) -> &[u32] { &self.limbs }
+    ///Checks if zero.
+    pub fn is_zero(&self) -> bool { self.limbs.is_empty() }
+    ///Checks if one.
+    pub fn is_one(&self) -> bool { self.limbs.len() == 1 && self.limbs[0] == 1 }
+    ///Checks if even.
+    pub fn is_even(&self) -> bool { self.limbs.first().is_none_or(|l| l & 1 == 0) }
+    ///Returns the number of significant bits.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * LIMB_BITS - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+    ///Returns the number of trailing zero bits - zero for zero.
+    pub fn trailing_zeros(&self) -> usize {
+        for (i, l) in self.limbs.iter().enumerate() {
+            if *l != 0 { return i * LIMB_BITS + l.trailing_zeros() as usize; }
+        }
+        0
+    }
+    ///Tries to convert to a u64, returning None if too big.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u64),
+            2 => Some(self.limbs[0] as u64 | (self.limbs[1] as u64) << 32),
+            _ => None,
+        }
+    }
+    ///Tries to convert to a u128, returning None if too big.
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 { return None; }
+        Some(self.limbs.iter().rev().fold(0u128, |acc, l| (acc << 32) | *l as u128))
+    }
+    ///Converts to the nearest f64 - returns infinity if too big.
+    pub fn to_f64(&self) -> f64 {
+        self.limbs.iter().rev().fold(0.0, |acc, l| acc * 4294967296.0 + *l as f64)
+    }
+    ///Raises to the power of exp by repeated squaring.
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut out = Self::identity();
+        while exp > 0 {
+            if exp & 1 == 1 { out = &out * &base; }
+            exp >>= 1;
+            if exp > 0 { base = &base * &base; }
+        }
+        out
+    }
+    ///Returns the quotient and remainder - panics if dividing by zero.
+    pub fn div_rem(&self, other: &ubig) -> (ubig, ubig) {
+        if other.is_zero() { panic!("Tried to divide a ubig by zero!"); }
+        if self < other { return (Self::zero(), self.clone()); }
+        if other.limbs.len() == 1 {
+            let (q, r) = self.div_rem_small(other.limbs[0]);
+            return (q, Self::from(r));
+        }
+        self.div_rem_knuth(other)
+    }
+    ///Returns the quotient and remainder of division by a single limb - panics if dividing by zero.
+    pub fn div_rem_small(&self, other: u32) -> (ubig, u32) {
+        if other == 0 { panic!("Tried to divide a ubig by zero!"); }
+        let mut q = vec![0u32; self.limbs.len()];
+        let mut r = 0u64;
+        for i in (0..self.limbs.len()).rev() {
+            let cur = (r << 32) | self.limbs[i] as u64;
+            q[i] = (cur / other as u64) as u32;
+            r = cur % other as u64;
+        }
+        (Self::from_limbs(q), r as u32)
+    }
+    ///Knuth's algorithm D - long division for divisors of at least two limbs.
+    fn div_rem_knuth(&self, other: &ubig) -> (ubig, ubig) {
+        let shift = other.limbs.last().unwrap().leading_zeros() as usize;
+        let v = (other << shift).limbs;
+        let mut u = (self << shift).limbs;
+        if u.len() == self.limbs.len() { u.push(0); }
+        let n = v.len();
+        let m = u.len() - n - 1;
+        let mut q = vec![0u32; m + 1];
+        let base = 1u64 << 32;
+        for j in (0..=m).rev() {
+            let num = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+            let mut qhat = num / v[n - 1] as u64;
+            let mut rhat = num % v[n - 1] as u64;
+            while qhat >= base || qhat * v[n - 2] as u64 > ((rhat << 32) | u[j + n - 2] as u64) {
+                qhat -= 1;
+                rhat += v[n - 1] as u64;
+                if rhat >= base { break; }
+            }
+            let (mut borrow, mut carry) = (0i64, 0u64);
+            for i in 0..n {
+                let p = qhat * v[i] as u64 + carry;
+                carry = p >> 32;
+                let t = u[i + j] as i64 - borrow - (p & 0xFFFF_FFFF) as i64;
+                u[i + j] = t as u32;
+                borrow = (t < 0) as i64;
+            }
+            let t = u[j + n] as i64 - borrow - carry as i64;
+            u[j + n] = t as u32;
+            if t < 0 {
+                qhat -= 1;
+                let mut c = 0u64;
+                for i in 0..n {
+                    let s = u[i + j] as u64 + v[i] as u64 + c;
+                    u[i + j] = s as u32;
+                    c = s >> 32;
+                }
+                u[j + n] = u[j + n].wrapping_add(c as u32);
+            }
+            q[j] = qhat as u32;
+        }
+        u.truncate(n);
+        (Self::from_limbs(q), &Self::from_limbs(u) >> shift)
+    }
+    ///Greatest common divisor using the Euclidean algorithm.
+    pub fn gcd(&self, other: &ubig) -> ubig {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a
+    }
+    ///Lowest common multiple.
+    pub fn lcm(&self, other: &ubig) -> ubig {
+        if self.is_zero() || other.is_zero() { return Self::zero(); }
+        &(self / &self.gcd(other)) * other
+    }
+    ///Integer square root (floor) using Newton's method.
+    pub fn isqrt(&self) -> ubig {
+        if self.is_zero() { return Self::zero(); }
+        let mut x = &Self::identity() << self.bits().div_ceil(2);
+        loop {
+            let y = &(&x + &(self / &x)) >> 1;
+            if y >= x { return x; }
+            x = y;
+        }
+    }
+    ///Checks if this is a perfect square.
+    pub fn is_square(&self) -> bool {
+        let r = self.isqrt();
+        &(&r * &r) == self
+    }
+    fn trim(&mut self) {
+        while let Some(0) = self.limbs.last() { self.limbs.pop(); }
+    }
+}
+
+impl From<u32> for ubig {
+    fn from(other: u32) -> Self { Self::from_limbs(vec![other]) }
+}
+impl From<u64> for ubig {
+    fn from(other: u64) -> Self { Self::from_limbs(vec![other as u32, (other >> 32) as u32]) }
+}
+impl From<u128> for ubig {
+    fn from(other: u128) -> Self { Self::from_limbs((0..4).map(|i| (other >> (32 * i)) as u32).collect()) }
+}
+impl From<u16> for ubig {
+    fn from(other: u16) -> Self { Self::from(other as u32) }
+}
+
+impl FromStr for ubig {
+    type Err = ParseBigError;
+    fn from_str(s: &str) -> Result<Self, ParseBigError> {
+        if s.is_empty() { return Err(ParseBigError::Empty); }
+        if !s.bytes().all(|b| b.is_ascii_digit()) { return Err(ParseBigError::InvalidDigit); }
+        let mut out = Self::zero();
+        let head = s.len() % DEC_DIGITS;
+        let mut chunks = vec![&s[..head]];
+        chunks.extend(s.as_bytes()[head..].chunks(DEC_DIGITS).map(|c| std::str::from_utf8(c).unwrap()));
+        for chunk in chunks.into_iter().filter(|c| !c.is_empty()) {
+            out = &(&out * 10u32.pow(chunk.len() as u32)) + &Self::from(chunk.parse::<u32>().unwrap());
+        }
+        Ok(out)
+    }
+}
+
+impl std::fmt::Display for ubig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_zero() { return write!(f, "0"); }
+        let mut chunks = Vec::new();
+        let mut n = self.clone();
+        while !n.is_zero() {
+            let (q, r) = n.div_rem_small(DEC_BASE);
+            chunks.push(r);
+            n = q;
+        }
+        let mut s = chunks.pop().unwrap().to_string();
+        for c in chunks.iter().rev() { s += &format!("{:09}", c); }
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl Identity for ubig {
+    fn identity() -> Self { Self::from(1u32) }
+}
+
+impl Absolute for ubig {
+    type Output = ubig;
+    fn absolute(&self) -> Self { self.clone() }
+}
+
+impl Sqroot for ubig {
+    type Output = ubig;
+    fn sqroot(&self) -> Self { self.isqrt() }
+}
+
+impl PartialOrd for ubig {
+    fn partial_cmp(&self, other: &ubig) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for ubig {
+    fn cmp(&self, other: &ubig) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len()).then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl Add<&ubig> for &ubig { type Output = ubig; fn add(self, other: &ubig) -> ubig {
+    let (long, short) = match self.limbs.len() >= other.limbs.len() { true => (self, other), false => (other, self) };
+    let mut out = Vec::with_capacity(long.limbs.len() + 1);
+    let mut carry = 0u64;
+    for i in 0..long.limbs.len() {
+        let s = long.limbs[i] as u64 + *short.limbs.get(i).unwrap_or(&0) as u64 + carry;
+        out.push(s as u32);
+        carry = s >> 32;
+    }
+    if carry > 0 { out.push(carry as u32); }
+    ubig::from_limbs(out)
+} }
+impl Sub<&ubig> for &ubig { type Output = ubig; fn sub(self, other: &ubig) -> ubig {
+    if self < other { panic!("Tried to subtract a larger ubig from a smaller one, underflowed."); }
+    let mut out = Vec::with_capacity(self.limbs.len());
+    let mut borrow = 0i64;
+    for i in 0..self.limbs.len() {
+        let t = self.limbs[i] as i64 - *other.limbs.get(i).unwrap_or(&0) as i64 - borrow;
+        out.push(t as u32);
+        borrow = (t < 0) as i64;
+    }
+    ubig::from_limbs(out)
+} }
+impl Mul<&ubig> for &ubig { type Output = ubig; fn mul(self, other: &ubig) -> ubig {
+    if self.is_zero() || other.is_zero() { return ubig::zero(); }
+    let mut out = vec![0u32; self.limbs.len() + other.limbs.len()];
+    for (i, a) in self.limbs.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, b) in other.limbs.iter().enumerate() {
+            let p = *a as u64 * *b as u64 + out[i + j] as u64 + carry;
+            out[i + j] = p as u32;
+            carry = p >> 32;
+        }
+        out[i + other.limbs.len()] = carry as u32;
+    }
+    ubig::from_limbs(out)
+} }
+impl Mul<u32> for &ubig { type Output = ubig; fn mul(self, other: u32) -> ubig { self * &ubig::from(other) } }
+impl Div<&ubig> for &ubig { type Output = ubig; fn div(self, other: &ubig) -> ubig { self.div_rem(other).0 } }
+impl Rem<&ubig> for &ubig { type Output = ubig; fn rem(self, other: &ubig) -> ubig { self.div_rem(other).1 } }
+impl Shl<usize> for &ubig { type Output = ubig; fn shl(self, other: usize) -> ubig {
+    if self.is_zero() { return ubig::zero(); }
+    let (limbs, bits) = (other / LIMB_BITS, other % LIMB_BITS);
+    let mut out = vec![0u32; limbs];
+    let mut carry = 0u32;
+    for l in self.limbs.iter() {
+        match bits {
+            0 => out.push(*l),
+            _ => { out.push((l << bits) | carry); carry = l >> (LIMB_BITS - bits); },
+        }
+    }
+    out.push(carry);
+    ubig::from_limbs(out)
+} }
+impl Shr<usize> for &ubig { type Output = ubig; fn shr(self, other: usize) -> ubig {
+    let (limbs, bits) = (other / LIMB_BITS, other % LIMB_BITS);
+    if limbs >= self.limbs.len() { return ubig::zero(); }
+    let src = &self.limbs[limbs..];
+    let out = (0..src.len()).map(|i| match bits {
+        0 => src[i],
+        _ => (src[i] >> bits) | (src.get(i + 1).unwrap_or(&0) << (LIMB_BITS - bits)),
+    }).collect();
+    ubig::from_limbs(out)
+} }
+impl Add<ubig> for ubig { type Output = ubig; fn add(self, other: ubig) -> ubig { &self + &other } }
+impl Add<&ubig> for ubig { type Output = ubig; fn add(self, other: &ubig) -> ubig { &self + other } }
+impl Add<ubig> for &ubig { type Output = ubig; fn add(self, other: ubig) -> ubig { self + &other } }
+impl AddAssign<ubig> for ubig { fn add_assign(&mut self, other: ubig) { *self = &*self + &other; } }
+impl AddAssign<&ubig> for ubig { fn add_assign(&mut self, other: &ubig) { *self = &*self + other; } }
+impl Sub<ubig> for ubig { type Output = ubig; fn sub(self, other: ubig) -> ubig { &self - &other } }
+impl Sub<&ubig> for ubig { type Output = ubig; fn sub(self, other: &ubig) -> ubig { &self - other } }
+impl Sub<ubig> for &ubig { type Output = ubig; fn sub(self, other: ubig) -> ubig { self - &other } }
+impl SubAssign<ubig> for ubig { fn sub_assign(&mut self, other: ubig) { *self = &*self - &other; } }
+impl SubAssign<&ubig> for ubig { fn sub_assign(&mut self, other: &ubig) { *self = &*self - other; } }
+impl Mul<ubig> for ubig { type Output = ubig; fn mul(self, other: ubig) -> ubig { &self * &other } }
+impl Mul<&ubig> for ubig { type Output = ubig; fn mul(self, other: &ubig) -> ubig { &self * other } }
+impl Mul<ubig> for &ubig { type Output = ubig; fn mul(self, other: ubig) -> ubig { self * &other } }
+impl MulAssign<ubig> for ubig { fn mul_assign(&mut self, other: ubig) { *self = &*self * &other; } }
+impl MulAssign<&ubig> for ubig { fn mul_assign(&mut self, other: &ubig) { *self = &*self * other; } }
+impl Div<ubig> for ubig { type Output = ubig; fn div(self, other: ubig) -> ubig { &self / &other } }
+impl Div<&ubig> for ubig { type Output = ubig; fn div(self, other: &ubig) -> ubig { &self / other } }
+impl Div<ubig> for &ubig { type Output = ubig; fn div(self, other: ubig) -> ubig { self / &other } }
+impl DivAssign<ubig> for ubig { fn div_assign(&mut self, other: ubig) { *self = &*self / &other; } }
+impl DivAssign<&ubig> for ubig { fn div_assign(&mut self, other: &ubig) { *self = &*self / other; } }
+impl Rem<ubig> for ubig { type Output = ubig; fn rem(self, other: ubig) -> ubig { &self % &other } }
+impl Rem<&ubig> for ubig { type Output = ubig; fn rem(self, other: &ubig) -> ubig { &self % other } }
+impl Rem<ubig> for &ubig { type Output = ubig; fn rem(self, other: ubig) -> ubig { self % &other } }
+impl RemAssign<ubig> for ubig { fn rem_assign(&mut self, other: ubig) { *self = &*self % &other; } }
+impl RemAssign<&ubig> for ubig { fn rem_assign(&mut self, other: &ubig) { *self = &*self % other; } }
+impl Mul<u32> for ubig { type Output = ubig; fn mul(self, other: u32) -> ubig { &self * other } }
+impl Shl<usize> for ubig { type Output = ubig; fn shl(self, other: usize) -> ubig { &self << other } }
+impl Shr<usize> for ubig { type Output = ubig; fn shr(self, other: usize) -> ubig { &self >> other } }
+impl ShlAssign<usize> for ubig { fn shl_assign(&mut self, other: usize) { *self = &*self << other; } }
+impl ShrAssign<usize> for ubig { fn shr_assign(&mut self, other: usize) { *self = &*self >> other; } }
+
+///Signed arbitrary precision integer - a sign and a ubig magnitude. Zero is never negative.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct ibig {
+    negative: bool,
+    mag: ubig,
+}
+impl ibig {
+    ///Returns zero.
+    pub fn zero() -> Self { Self { negative: false, mag: ubig::zero() } }
+    ///Returns a new ibig from a sign (true if negative) and magnitude.
+    pub fn from_parts(negative: bool, mag: ubig) -> Self {
+        let negative = negative && !mag.is_zero();
+        Self { negative, mag }
+    }
+    ///Returns the magnitude.
+    pub fn magnitude(&self) -> &ubig { &self.mag }
+    ///Returns the magnitude, consuming the ibig.
+    pub fn into_magnitude(self) -> ubig { self.mag }
+    ///Checks if zero.
+    pub fn is_zero(&self) -> bool { self.mag.is_zero() }
+    ///Checks if negative.
+    pub fn is_negative(&self) -> bool { self.negative }
+    ///Checks if positive.
+    pub fn is_positive(&self) -> bool { !self.negative && !self.mag.is_zero() }
+    ///Returns the signum.
+    pub fn signum(&self) -> i32 {
+        match (self.negative, self.mag.is_zero()) {
+            (_, true) => 0,
+            (true, false) => -1,
+            (false, false) => 1,
+        }
+    }
+    ///Tries to convert to an i64, returning None if out of range.
+    pub fn to_i64(&self) -> Option<i64> {
+        let m = self.mag.to_u64()?;
+        match self.negative {
+            true if m <= i64::MAX as u64 + 1 => Some((m as i64).wrapping_neg()),
+            false if m <= i64::MAX as u64 => Some(m as i64),
+            _ => None,
+        }
+    }
+    ///Converts to the nearest f64 - returns +/- infinity if too big.
+    pub fn to_f64(&self) -> f64 {
+        match self.negative { true => -self.mag.to_f64(), false => self.mag.to_f64() }
+    }
+    ///Raises to the power of exp.
+    pub fn pow(&self, exp: u32) -> Self { Self::from_parts(self.negative && exp % 2 == 1, self.mag.pow(exp)) }
+    ///Returns the truncated quotient and the remainder, which has the sign of self - panics if dividing by zero.
+    pub fn div_rem(&self, other: &ibig) -> (ibig, ibig) {
+        let (q, r) = self.mag.div_rem(&other.mag);
+        (Self::from_parts(self.negative != other.negative, q), Self::from_parts(self.negative, r))
+    }
+}
+
+impl From<ubig> for ibig {
+    fn from(other: ubig) -> Self { Self::from_parts(false, other) }
+}
+impl From<u32> for ibig {
+    fn from(other: u32) -> Self { Self::from(ubig::from(other)) }
+}
+impl From<u64> for ibig {
+    fn from(other: u64) -> Self { Self::from(ubig::from(other)) }
+}
+impl From<i16> for ibig {
+    fn from(other: i16) -> Self { Self::from(other as i64) }
+}
+impl From<i32> for ibig {
+    fn from(other: i32) -> Self { Self::from(other as i64) }
+}
+impl From<i64> for ibig {
+    fn from(other: i64) -> Self { Self::from_parts(other < 0, ubig::from(other.unsigned_abs())) }
+}
+impl From<i128> for ibig {
+    fn from(other: i128) -> Self { Self::from_parts(other < 0, ubig::from(other.unsigned_abs())) }
+}
+
+impl FromStr for ibig {
+    type Err = ParseBigError;
+    fn from_str(s: &str) -> Result<Self, ParseBigError> {
+        match s.strip_prefix('-') {
+            Some(rest) => Ok(Self::from_parts(true, rest.parse()?)),
+            None => Ok(Self::from(s.strip_prefix('+').unwrap_or(s).parse::<ubig>()?)),
+        }
+    }
+}
+
+impl std::fmt::Display for ibig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.pad_integral(!self.negative, "", &self.mag.to_string())
+    }
+}
+
+impl Identity for ibig {
+    fn identity() -> Self { Self::from(ubig::identity()) }
+}
+
+impl Absolute for ibig {
+    type Output = ibig;
+    fn absolute(&self) -> Self { Self::from(self.mag.clone()) }
+}
+
+impl PartialOrd for ibig {
+    fn partial_cmp(&self, other: &ibig) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for ibig {
+    fn cmp(&self, other: &ibig) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.mag.cmp(&other.mag),
+            (true, true) => other.mag.cmp(&self.mag),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Add<&ibig> for &ibig { type Output = ibig; fn add(self, other: &ibig) -> ibig {
+    if self.negative == other.negative { return ibig::from_parts(self.negative, &self.mag + &other.mag); }
+    match self.mag.cmp(&other.mag) {
+        Ordering::Less => ibig::from_parts(other.negative, &other.mag - &self.mag),
+        _ => ibig::from_parts(self.negative, &self.mag - &other.mag),
+    }
+} }
+impl Neg for &ibig { type Output = ibig; fn neg(self) -> ibig { ibig::from_parts(!self.negative, self.mag.clone()) } }
+impl Neg for ibig { type Output = ibig; fn neg(self) -> ibig { ibig::from_parts(!self.negative, self.mag) } }
+impl Sub<&ibig> for &ibig { type Output = ibig; fn sub(self, other: &ibig) -> ibig { self + &-other } }
+impl Mul<&ibig> for &ibig { type Output = ibig; fn mul(self, other: &ibig) -> ibig { ibig::from_parts(self.negative != other.negative, &self.mag * &other.mag) } }
+impl Div<&ibig> for &ibig { type Output = ibig; fn div(self, other: &ibig) -> ibig { self.div_rem(other).0 } }
+impl Rem<&ibig> for &ibig { type Output = ibig; fn rem(self, other: &ibig) -> ibig { self.div_rem(other).1 } }
+impl Add<ibig> for ibig { type Output = ibig; fn add(self, other: ibig) -> ibig { &self + &other } }
+impl Add<&ibig> for ibig { type Output = ibig; fn add(self, other: &ibig) -> ibig { &self + other } }
+impl Add<ibig> for &ibig { type Output = ibig; fn add(self, other: ibig) -> ibig { self + &other } }
+impl AddAssign<ibig> for ibig { fn add_assign(&mut self, other: ibig) { *self = &*self + &other; } }
+impl AddAssign<&ibig> for ibig { fn add_assign(&mut self, other: &ibig) { *self = &*self + other; } }
+impl Sub<ibig> for ibig { type Output = ibig; fn sub(self, other: ibig) -> ibig { &self - &other } }
+impl Sub<&ibig> for ibig { type Output = ibig; fn sub(self, other: &ibig) -> ibig { &self - other } }
+impl Sub<ibig> for &ibig { type Output = ibig; fn sub(self, other: ibig) -> ibig { self - &other } }
+impl SubAssign<ibig> for ibig { fn sub_assign(&mut self, other: ibig) { *self = &*self - &other; } }
+impl SubAssign<&ibig> for ibig { fn sub_assign(&mut self, other: &ibig) { *self = &*self - other; } }
+impl Mul<ibig> for ibig { type Output = ibig; fn mul(self, other: ibig) -> ibig { &self * &other } }
+impl Mul<&ibig> for ibig { type Output = ibig; fn mul(self, other: &ibig) -> ibig { &self * other } }
+impl Mul<ibig> for &ibig { type Output = ibig; fn mul(self, other: ibig) -> ibig { self * &other } }
+impl MulAssign<ibig> for ibig { fn mul_assign(&mut self, other: ibig) { *self = &*self * &other; } }
+impl MulAssign<&ibig> for ibig { fn mul_assign(&mut self, other: &ibig) { *self = &*self * other; } }
+impl Div<ibig> for ibig { type Output = ibig; fn div(self, other: ibig) -> ibig { &self / &other } }
+impl Div<&ibig> for ibig { type Output = ibig; fn div(self, other: &ibig) -> ibig { &self / other } }
+impl Div<ibig> for &ibig { type Output = ibig; fn div(self, other: ibig) -> ibig { self / &other } }
+impl DivAssign<ibig> for ibig { fn div_assign(&mut self, other: ibig) { *self = &*self / &other; } }
+impl DivAssign<&ibig> for ibig { fn div_assign(&mut self, other: &ibig) { *self = &*self / other; } }
+impl Rem<ibig> for ibig { type Output = ibig; fn rem(self, other: ibig) -> ibig { &self % &other } }
+impl Rem<&ibig> for ibig { type Output = ibig; fn rem(self, other: &ibig) -> ibig { &self % other } }
+impl Rem<ibig> for &ibig { type Output = ibig; fn rem(self, other: ibig) -> ibig { self % &other } }
+impl RemAssign<ibig> for ibig { fn rem_assign(&mut self, other: ibig) { *self = &*self % &other; } }
+impl RemAssign<&ibig> for ibig { fn rem_assign(&mut self, other: &ibig) { *self = &*self % other; } }
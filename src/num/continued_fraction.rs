@@ -0,0 +1,150 @@
+use super::rational::{r32, r64};
+use super::bigint::ibig;
+
+///Maximum number of terms taken when expanding an f64 - the expansion is also cut off once a convergent reproduces
+///the f64 exactly or would overflow an i64.
+const MAX_F64_TERMS: usize = 64;
+
+///Simple continued fraction [a0; a1, a2, ...] = a0 + 1/(a1 + 1/(a2 + ...)). a0 is the floor of the value and can be
+///zero or negative, every later term is positive. Rationals always have a finite expansion.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ContinuedFraction {
+    terms: Vec<i64>,
+}
+impl ContinuedFraction {
+    ///Returns a new continued fraction from its terms.
+    pub fn new(terms: Vec<i64>) -> Self { Self { terms } }
+    ///Returns the terms of the continued fraction.
+    pub fn terms(&self) -> &[i64] { &self.terms }
+    ///Expands the fraction numerator/denominator - panics if the denominator is zero.
+    pub fn from_ratio(numerator: i128, denominator: i128) -> Self {
+        if denominator == 0 { panic!("Denominator of a continued fraction can never be zero!"); }
+        let (mut n, mut d) = (numerator * denominator.signum(), denominator.abs());
+        let mut terms = Vec::new();
+        while d != 0 {
+            terms.push(n.div_euclid(d) as i64);
+            (n, d) = (d, n.rem_euclid(d));
+        }
+        Self { terms }
+    }
+    ///Returns the value as a (numerator, denominator) pair - the last convergent.
+    pub fn value(&self) -> (i128, i128) { self.convergents().last().unwrap_or((0, 1)) }
+    ///Returns an iterator over the convergents h/k as (numerator, denominator) pairs - stops early if they overflow.
+    pub fn convergents(&self) -> Convergents<'_> {
+        Convergents { terms: &self.terms, index: 0, prev: (0, 1), cur: (1, 0) }
+    }
+    ///Returns an iterator over the semiconvergents (intermediate fractions) as (numerator, denominator) pairs - for each
+    ///term a_i this yields (h_(i-2) + t*h_(i-1))/(k_(i-2) + t*k_(i-1)) for t in 1..=a_i, so every convergent is included
+    ///and is the last fraction of its run. Stops early if they overflow.
+    pub fn semiconvergents(&self) -> Semiconvergents<'_> {
+        Semiconvergents { terms: &self.terms, index: 0, t: 1, prev: (0, 1), cur: (1, 0) }
+    }
+    ///Returns the closest fraction to the value with a denominator no larger than max_denominator, as a
+    ///(numerator, denominator) pair. Ties go to the smaller denominator. Panics if max_denominator is zero.
+    pub fn best_approximation(&self, max_denominator: u128) -> (i128, i128) {
+        if max_denominator == 0 { panic!("Maximum denominator for an approximation can never be zero!"); }
+        let max = max_denominator.min(i128::MAX as u128) as i128;
+        let convergents = self.convergents().collect::<Vec<_>>();
+        let Some(k) = convergents.iter().rposition(|(_, q)| *q <= max) else { return (0, 1); };
+        if k + 1 == convergents.len() { return convergents[k]; }
+        let (p, q) = convergents[k];
+        let (pp, qq) = match k { 0 => (1, 0), _ => convergents[k - 1] };
+        let t = (max - qq) / q;
+        if t == 0 { return (p, q); }
+        let semi = (pp + t * p, qq + t * q);
+        //Compare |x - p1/q1| against |x - p2/q2| exactly, with x = h/k the full value.
+        let (h, k) = self.value();
+        let (h, k) = (ibig::from(h), ibig::from(k));
+        let dist = |(p, q): (i128, i128)| -> ibig { use super::Absolute; (&h * &ibig::from(q) - &ibig::from(p) * &k).absolute() };
+        match dist(semi) * ibig::from(q) < dist((p, q)) * ibig::from(semi.1) {
+            true => semi,
+            false => (p, q),
+        }
+    }
+}
+
+impl From<r32> for ContinuedFraction {
+    fn from(other: r32) -> Self { Self::from_ratio(other.numerator() as i128, other.denominator() as i128) }
+}
+impl From<r64> for ContinuedFraction {
+    fn from(other: r64) -> Self { Self::from_ratio(other.numerator() as i128, other.denominator() as i128) }
+}
+///Expands an f64 until a convergent reproduces it exactly, a convergent would overflow an i64, or MAX_F64_TERMS
+///terms have been taken. Panics on NaN or infinity.
+impl From<f64> for ContinuedFraction {
+    fn from(other: f64) -> Self {
+        if !other.is_finite() { panic!("Tried to expand a non-finite f64 as a continued fraction."); }
+        let mut terms = Vec::new();
+        let (mut prev, mut cur): ((i64, i64), (i64, i64)) = ((0, 1), (1, 0));
+        let mut x = other;
+        while terms.len() < MAX_F64_TERMS {
+            let a = x.floor();
+            if a.abs() >= i64::MAX as f64 { break; }
+            let a = a as i64;
+            let next = match (a.checked_mul(cur.0).and_then(|h| h.checked_add(prev.0)), a.checked_mul(cur.1).and_then(|k| k.checked_add(prev.1))) {
+                (Some(h), Some(k)) => (h, k),
+                _ => break,
+            };
+            terms.push(a);
+            (prev, cur) = (cur, next);
+            let frac = x - x.floor();
+            if frac == 0.0 || cur.0 as f64 / cur.1 as f64 == other { break; }
+            x = 1.0 / frac;
+        }
+        Self { terms }
+    }
+}
+
+impl std::fmt::Display for ContinuedFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let rest = self.terms.iter().skip(1).map(|t| t.to_string()).collect::<Vec<_>>().join(", ");
+        match self.terms.first() {
+            Some(a0) if rest.is_empty() => write!(f, "[{}]", a0),
+            Some(a0) => write!(f, "[{}; {}]", a0, rest),
+            None => write!(f, "[]"),
+        }
+    }
+}
+
+///Iterator over the convergents of a ContinuedFraction.
+pub struct Convergents<'a> {
+    terms: &'a [i64],
+    index: usize,
+    prev: (i128, i128),
+    cur: (i128, i128),
+}
+impl Iterator for Convergents<'_> {
+    type Item = (i128, i128);
+    fn next(&mut self) -> Option<(i128, i128)> {
+        let a = *self.terms.get(self.index)? as i128;
+        let h = a.checked_mul(self.cur.0)?.checked_add(self.prev.0)?;
+        let k = a.checked_mul(self.cur.1)?.checked_add(self.prev.1)?;
+        (self.prev, self.cur) = (self.cur, (h, k));
+        self.index += 1;
+        Some(self.cur)
+    }
+}
+
+///Iterator over the semiconvergents of a ContinuedFraction.
+pub struct Semiconvergents<'a> {
+    terms: &'a [i64],
+    index: usize,
+    t: i64,
+    prev: (i128, i128),
+    cur: (i128, i128),
+}
+impl Iterator for Semiconvergents<'_> {
+    type Item = (i128, i128);
+    fn next(&mut self) -> Option<(i128, i128)> {
+        let a = *self.terms.get(self.index)?;
+        //The first term isn't a count, so only the convergent a0/1 is yielded for it.
+        let t = match self.index { 0 => a, _ => self.t } as i128;
+        let h = t.checked_mul(self.cur.0)?.checked_add(self.prev.0)?;
+        let k = t.checked_mul(self.cur.1)?.checked_add(self.prev.1)?;
+        match self.index == 0 || self.t >= a {
+            true => { (self.prev, self.cur) = (self.cur, (h, k)); self.index += 1; self.t = 1; },
+            false => { self.t += 1; },
+        }
+        Some((h, k))
+    }
+}
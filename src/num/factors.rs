@@ -1,4 +1,13 @@
-const FAC_ITER: usize = 100;
+use super::Integer;
+
+///Number of steps of Brent's rho between gcds - the differences are multiplied together so one gcd covers the batch.
+const RHO_BATCH: usize = 128;
+///Number of primes below 1000 - the trial division table.
+const SMALL_PRIME_COUNT: usize = 168;
+///Length of each segment of PrimeSieve.
+const SIEVE_SEGMENT: usize = 1 << 15;
+///Primes below 1000, used for trial division in fac32 and fac64 before falling back to Pollard rho.
+pub const SMALL_PRIMES: [u32; SMALL_PRIME_COUNT] = small_primes();
 
 pub fn rat_approx_32(f: f32, max: f32) -> (f32, f32) {
     let frac = f.fract();
@@ -136,132 +145,179 @@ pub fn rat_approx_64(f: f64, max: f64) -> (f64, f64) {
     (n as f64, d as f64)
 }
 
-///Returns the greatest common denominator of two u16s.
-pub fn gcd16(a: u16, b: u16) -> u16 {
-    let (mut a0, mut b0) = (a, b);
-    while a0 != 0 {
-        let a1 = b0 % a0;
-        b0 = a0;
-        a0 = a1;    
-    }
-    return b0;
-}
-///Returns the greatest common denominator of two u32s.
-pub fn gcd32(a: u32, b: u32) -> u32 {
-    let (mut a0, mut b0) = (a, b);
-    while a0 != 0 {
-        let a1 = b0 % a0;
-        b0 = a0;
-        a0 = a1;    
-    }
-    return b0;
-}
-///Returns the greatest common denominator of two u64s.
-pub fn gcd64(a: u64, b: u64) -> u64 {
-    let (mut a0, mut b0) = (a, b);
-    while a0 != 0 {
-        let a1 = b0 % a0;
-        b0 = a0;
-        a0 = a1;    
-    }
-    return b0;
-}
-///Returns the lowest common multiple of two u32s.
-pub fn lcm16(a: u16, b: u16) -> u16 { (a * b) / gcd16(a, b) }
-///Returns the lowest common multiple of two u32s.
-pub fn lcm32(a: u32, b: u32) -> u32 { (a * b) / gcd32(a, b) }
-///Returns the lowest common multiple of two u64s.
-pub fn lcm64(a: u64, b: u64) -> u64 { (a * b) / gcd64(a, b) }
-///Returns a list of prime factors for a u32.
-pub fn fac32(n: u32) -> Vec<u32> {
-    let mut v = vec!(n);
-    let mut running = true;
-    while running {
-        running = false;
-        let mut v0 = Vec::new();
-        for n0 in &v {
-            match prho32(*n0) {
-                (0, 0) => { v0.push(*n0); },
-                (_, 1) => { v0.push(*n0); },
-                (1, _) => { v0.push(*n0); },
-                (a, b) => { v0.push(a); v0.push(b); running = true; }
-            }
-        }
-        v = v0;
+///Returns the greatest common divisor of two integers, using the binary (Stein) algorithm - always non-negative, so
+///it's returned as the unsigned type of the same width.
+pub fn gcd<T: Integer>(a: T, b: T) -> T::Unsigned { stein(a.unsigned_abs(), b.unsigned_abs()) }
+///Binary gcd of two unsigned values.
+fn stein<U: Integer>(a: U, b: U) -> U {
+    if a == U::ZERO { return b; }
+    if b == U::ZERO { return a; }
+    let shift = a.trailing_zeros().min(b.trailing_zeros());
+    let (mut a, mut b) = (a >> a.trailing_zeros(), b);
+    loop {
+        b = b >> b.trailing_zeros();
+        if a > b { (a, b) = (b, a); }
+        b = b - a;
+        if b == U::ZERO { return a << shift; }
     }
-    v
 }
-///Pollard Rho algorithm for a u32 - returns a non-trivial factor of a u32 and the original number
-///with the factor removed.
-pub fn prho32(n: u32) -> (u32, u32) {
-    let (mut x, mut y, mut d);
-    let (mut count, mut attempt) = (0, 0);
-    while count < FAC_ITER {
-        count += 1;
-        if n % 2 == 0 { return (2, n/2); }
-        else if n % 3 == 0 { return (3, n/3); }
-        else if n % 5 == 0 { return (5, n/5); }
-        else {
-            x = 2_i32;
-            y = 2_i32;
-            d = 1_i32;
-            while d == 1 {
-                x = (x*x + attempt) % n as i32;
-                y = (y*y + attempt) % n as i32;
-                y = (y*y + attempt) % n as i32;
-                d = gcd32((x - y).abs() as u32, n) as i32;
-            }
-            if d != n as i32 { return (d as u32, n/d as u32); }
-            else { attempt += 1; }
-        }
+///Returns the lowest common multiple of two integers - dividing by the gcd first, so this only overflows if the lcm
+///itself doesn't fit, in which case it's None. The lcm with zero is zero.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T::Unsigned> {
+    let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+    if a == T::Unsigned::ZERO || b == T::Unsigned::ZERO { return Some(T::Unsigned::ZERO); }
+    (a / stein(a, b)).checked_mul(b)
+}
+///Extended Euclidean algorithm - returns (g, x, y) with g = gcd(a, b) and a*x + b*y = g. The Bezout coefficients are
+///the minimal pair, which always fit the signed type of the same width.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> (T::Unsigned, T::Signed, T::Signed) {
+    let (mut r0, mut r1) = (a.unsigned_abs().to_u128(), b.unsigned_abs().to_u128());
+    let (mut s0, mut s1, mut t0, mut t1) = (1_i128, 0_i128, 0_i128, 1_i128);
+    while r1 != 0 {
+        //Only the last (discarded) coefficients can overflow, so wrapping keeps the results exact.
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0.wrapping_sub((q as i128).wrapping_mul(s1)));
+        (t0, t1) = (t1, t0.wrapping_sub((q as i128).wrapping_mul(t1)));
     }
-    (0, 0)
+    if a.is_negative() { s0 = s0.wrapping_neg(); }
+    if b.is_negative() { t0 = t0.wrapping_neg(); }
+    (T::Unsigned::from_u128(r0), T::Signed::from_u128(s0 as u128), T::Signed::from_u128(t0 as u128))
 }
-///Returns a list of prime factors for a u64.
-pub fn fac64(n: u64) -> Vec<u64> {
-    let mut v = vec!(n);
-    let mut running = true;
-    while running {
-        running = false;
-        let mut v0 = Vec::new();
-        for n0 in &v {
-            match prho64(*n0) {
-                (0, 0) => { v0.push(*n0); },
-                (_, 1) => { v0.push(*n0); },
-                (1, _) => { v0.push(*n0); },
-                (a, b) => { v0.push(a); v0.push(b); running = true; }
+///Returns the greatest common divisor of two u16s.
+pub fn gcd16(a: u16, b: u16) -> u16 { gcd(a, b) }
+///Returns the greatest common divisor of two u32s.
+pub fn gcd32(a: u32, b: u32) -> u32 { gcd(a, b) }
+///Returns the greatest common divisor of two u64s.
+pub fn gcd64(a: u64, b: u64) -> u64 { gcd(a, b) }
+///Returns the greatest common divisor of two u128s.
+pub fn gcd128(a: u128, b: u128) -> u128 { gcd(a, b) }
+///Returns the lowest common multiple of two u16s - panics if it doesn't fit in a u16.
+pub fn lcm16(a: u16, b: u16) -> u16 { lcm(a, b).expect("Lowest common multiple overflowed a u16.") }
+///Returns the lowest common multiple of two u32s - panics if it doesn't fit in a u32.
+pub fn lcm32(a: u32, b: u32) -> u32 { lcm(a, b).expect("Lowest common multiple overflowed a u32.") }
+///Returns the lowest common multiple of two u64s - panics if it doesn't fit in a u64.
+pub fn lcm64(a: u64, b: u64) -> u64 { lcm(a, b).expect("Lowest common multiple overflowed a u64.") }
+///Returns a list of prime factors for the absolute value of an integer, smallest first - every factor is prime.
+///Zero and one are returned as themselves.
+pub fn fac<T: Integer>(n: T) -> Vec<T::Unsigned> {
+    let n = n.unsigned_abs().to_u128();
+    if n < 2 { return vec!(T::Unsigned::from_u128(n)); }
+    let (mut n, mut f) = (n, Vec::new());
+    for p in SMALL_PRIMES {
+        let p = p as u128;
+        if p * p > n { break; }
+        while n % p == 0 { f.push(p); n /= p; }
+    }
+    let mut stack = vec!(n);
+    while let Some(n0) = stack.pop() {
+        if n0 == 1 { continue; }
+        if is_prime128(n0) { f.push(n0); continue; }
+        let (a, b) = prho(n0);
+        stack.push(a);
+        stack.push(b);
+    }
+    f.sort();
+    f.into_iter().map(T::Unsigned::from_u128).collect()
+}
+///Pollard Rho algorithm with Brent's cycle detection - returns a non-trivial factor of the absolute value of an
+///integer and the value with the factor removed, or (0, 0) if it's zero, one or prime. A failed run is retried with a
+///new polynomial x^2 + c, so composites always split.
+pub fn prho<T: Integer>(n: T) -> (T::Unsigned, T::Unsigned) {
+    let n = n.unsigned_abs().to_u128();
+    if n <= 1 || is_prime128(n) { return (T::Unsigned::ZERO, T::Unsigned::ZERO); }
+    let d = match n % 2 {
+        0 => 2,
+        _ => (1..).find_map(|c| match n <= u64::MAX as u128 {
+            true => brent(n, c, |a, b| a * b % n),
+            false => {
+                //Run in Montgomery form - x -> x^2 + c is still a pseudo-random map, and the factor of R in the
+                //batched product doesn't change its gcd with n.
+                let mont = Montgomery128::new(n);
+                brent(n, c, |a, b| mont.mul(a, b))
+            }
+        }).unwrap(),
+    };
+    (T::Unsigned::from_u128(d), T::Unsigned::from_u128(n / d))
+}
+///One run of Brent's rho on odd composite n with x -> x^2 + c, using mul for products mod n - None if the cycle
+///closed without finding a factor.
+fn brent(n: u128, c: u128, mul: impl Fn(u128, u128) -> u128) -> Option<u128> {
+    let f = |x: u128| addmod128(mul(x, x), c, n);
+    let (mut x, mut y, mut ys) = (2, 2, 2);
+    let (mut r, mut q, mut g) = (1_usize, 1_u128, 1_u128);
+    while g == 1 {
+        x = y;
+        for _ in 0..r { y = f(y); }
+        let mut k = 0;
+        while k < r && g == 1 {
+            ys = y;
+            for _ in 0..RHO_BATCH.min(r - k) {
+                y = f(y);
+                q = mul(q, x.abs_diff(y));
             }
+            g = gcd(q, n);
+            k += RHO_BATCH;
         }
-        v = v0;
+        r *= 2;
+    }
+    //The batch overshot - step back through it one gcd at a time.
+    if g == n {
+        loop {
+            ys = f(ys);
+            g = gcd(x.abs_diff(ys), n);
+            if g > 1 { break; }
+        }
+    }
+    match g == n {
+        true => None,
+        false => Some(g),
     }
-    v
 }
-///Pollard Rho algorithm for a u64 - returns a non-trivial factor of a u64 and the original number
-///with the factor removed.
-pub fn prho64(n: u64) -> (u64, u64) {
-    let (mut x, mut y, mut d);
-    let (mut count, mut attempt) = (0, 0);
-    while count < FAC_ITER {
-        count += 1;
-        if n % 2 == 0 { return (2, n/2); }
-        else if n % 3 == 0 { return (3, n/3); }
-        else if n % 5 == 0 { return (5, n/5); }
-        else {
-            x = 2_i64;
-            y = 2_i64;
-            d = 1_i64;
-            while d == 1 {
-                x = (x*x + attempt) % n as i64;
-                y = (y*y + attempt) % n as i64;
-                y = (y*y + attempt) % n as i64;
-                d = gcd64((x - y).abs() as u64, n) as i64;
-            }
-            if d != n as i64 { return (d as u64, n/d as u64); }
-            else { attempt += 1; }
+///Montgomery multiplication mod an odd u128 n with R = 2^128 - mul(aR, bR) = abR mod n, without a 256 bit division.
+struct Montgomery128 {
+    n: u128,
+    ///n^-1 mod R.
+    n_inv: u128,
+}
+impl Montgomery128 {
+    fn new(n: u128) -> Self {
+        let mut n_inv = n;
+        for _ in 0..6 { n_inv = n_inv.wrapping_mul(2_u128.wrapping_sub(n.wrapping_mul(n_inv))); }
+        Self { n, n_inv }
+    }
+    ///Returns ab/R mod n.
+    fn mul(&self, a: u128, b: u128) -> u128 {
+        let (hi, lo) = mul_wide(a, b);
+        let (mn_hi, _mn_lo) = mul_wide(lo.wrapping_mul(self.n_inv), self.n);
+        match hi >= mn_hi {
+            true => hi - mn_hi,
+            false => hi.wrapping_sub(mn_hi).wrapping_add(self.n),
         }
     }
-    (0, 0)
 }
+///Returns the full 256 bit product of two u128s as (high, low) halves.
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0, b1, b0) = (a >> 64, a as u64 as u128, b >> 64, b as u64 as u128);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 as u64 as u128) + (p10 as u64 as u128);
+    ((p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64)), (p00 as u64 as u128) | (mid << 64))
+}
+///Returns a list of prime factors for a u32.
+pub fn fac32(n: u32) -> Vec<u32> { fac(n) }
+///Pollard Rho algorithm for a u32 - returns a non-trivial factor of a u32 and the original number
+///with the factor removed.
+pub fn prho32(n: u32) -> (u32, u32) { prho(n) }
+///Returns a list of prime factors for a u64.
+pub fn fac64(n: u64) -> Vec<u64> { fac(n) }
+///Pollard Rho algorithm for a u64 - returns a non-trivial factor of a u64 and the original number
+///with the factor removed.
+pub fn prho64(n: u64) -> (u64, u64) { prho(n) }
+///Returns a list of prime factors for a u128.
+pub fn fac128(n: u128) -> Vec<u128> { fac(n) }
+///Pollard Rho algorithm for a u128 - returns a non-trivial factor of a u128 and the original number
+///with the factor removed.
+pub fn prho128(n: u128) -> (u128, u128) { prho(n) }
 
 //Returns a vector of square factors for a u32 - each item is a tuple of the unsquared factor and the squared factor.
 pub fn sqfac32(n: u32) -> Vec<(u32, u32)> {
@@ -274,7 +330,7 @@ pub fn sqfac32(n: u32) -> Vec<(u32, u32)> {
         }
         if !found { v.push((fac, 1)); }
     }
-    v.iter().filter(|(_f, n)| n % 2 == 0).map(|(f, n)| (f.pow(*n/2), f.pow(*n))).collect::<Vec<_>>()
+    v.iter().filter(|(_f, n)| *n >= 2).map(|(f, n)| (f.pow(*n/2), f.pow(*n/2*2))).collect::<Vec<_>>()
 }
 
 //Returns a vector of square factors for a u64 - each item is a tuple of the unsquared factor and the squared factor.
@@ -288,5 +344,329 @@ pub fn sqfac64(n: u64) -> Vec<(u64, u64)> {
         }
         if !found { v.push((fac, 1)); }
     }
-    v.iter().filter(|(_f, n)| n % 2 == 0).map(|(f, n)| (f.pow(*n/2), f.pow(*n))).collect::<Vec<_>>()
-}
\ No newline at end of file
+    v.iter().filter(|(_f, n)| *n >= 2).map(|(f, n)| (f.pow(*n/2), f.pow(*n/2*2))).collect::<Vec<_>>()
+}
+///Returns the prime factorisation of a u32 as (prime, exponent) pairs in increasing order of prime - empty for 1.
+///Panics on zero.
+pub fn prime_powers32(n: u32) -> Vec<(u32, u32)> {
+    if n == 0 { panic!("Zero has no prime factorisation."); }
+    group_factors(fac32(n).into_iter().filter(|p| *p > 1).collect())
+}
+///Returns the prime factorisation of a u64 as (prime, exponent) pairs in increasing order of prime - empty for 1.
+///Panics on zero.
+pub fn prime_powers64(n: u64) -> Vec<(u64, u32)> {
+    if n == 0 { panic!("Zero has no prime factorisation."); }
+    group_factors(fac64(n).into_iter().filter(|p| *p > 1).collect())
+}
+///Returns the prime factorisation of a u128 as (prime, exponent) pairs in increasing order of prime - empty for 1.
+///Panics on zero.
+pub fn prime_powers128(n: u128) -> Vec<(u128, u32)> {
+    if n == 0 { panic!("Zero has no prime factorisation."); }
+    group_factors(fac(n).into_iter().filter(|p| *p > 1).collect())
+}
+///Sorts a list of prime factors and counts repeats.
+fn group_factors<T: Ord + Copy>(mut f: Vec<T>) -> Vec<(T, u32)> {
+    f.sort();
+    let mut v: Vec<(T, u32)> = Vec::new();
+    for p in f {
+        match v.last_mut() {
+            Some((q, k)) if *q == p => *k += 1,
+            _ => v.push((p, 1)),
+        }
+    }
+    v
+}
+
+///Bases that make Miller-Rabin deterministic for every u32.
+const MR_BASES_32: [u32; 3] = [2, 7, 61];
+///Bases that make Miller-Rabin deterministic for every u64 - the first 12 primes. The first 13 primes (up to 41) are
+///deterministic below 3.3e24, which is used for u128.
+const MR_BASES_64: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+///Upper bound below which MR_BASES_64 is a deterministic test.
+const MR_BOUND_128: u128 = 3_317_044_064_679_887_385_961_981;
+
+///Returns a*b mod n for u32s without overflowing.
+pub fn mulmod32(a: u32, b: u32, n: u32) -> u32 { ((a as u64 * b as u64) % n as u64) as u32 }
+///Returns a*b mod n for u64s without overflowing.
+pub fn mulmod64(a: u64, b: u64, n: u64) -> u64 { ((a as u128 * b as u128) % n as u128) as u64 }
+///Returns a*b mod n for u128s without overflowing - uses double and add, as there's no wider type to multiply in.
+pub fn mulmod128(a: u128, b: u128, n: u128) -> u128 {
+    if n <= u64::MAX as u128 { return mulmod64((a % n) as u64, (b % n) as u64, n as u64) as u128; }
+    let (mut a, mut b, mut r) = (a % n, b % n, 0);
+    while b != 0 {
+        if b & 1 == 1 { r = addmod128(r, a, n); }
+        a = addmod128(a, a, n);
+        b >>= 1;
+    }
+    r
+}
+///Returns a+b mod n for reduced u128s without overflowing.
+fn addmod128(a: u128, b: u128, n: u128) -> u128 {
+    match a >= n - b {
+        true => a - (n - b),
+        false => a + b,
+    }
+}
+///Returns b^e mod n for u32s.
+pub fn powmod32(b: u32, e: u32, n: u32) -> u32 { powmod64(b as u64, e as u64, n as u64) as u32 }
+///Returns b^e mod n for u64s.
+pub fn powmod64(b: u64, e: u64, n: u64) -> u64 {
+    let (mut b, mut e, mut r) = (b % n, e, 1 % n);
+    while e != 0 {
+        if e & 1 == 1 { r = mulmod64(r, b, n); }
+        b = mulmod64(b, b, n);
+        e >>= 1;
+    }
+    r
+}
+///Returns b^e mod n for u128s.
+pub fn powmod128(b: u128, e: u128, n: u128) -> u128 {
+    if n > u64::MAX as u128 && n % 2 == 1 {
+        //Montgomery form - only converting in needs the slow mulmod128.
+        let mont = Montgomery128::new(n);
+        let one = (u128::MAX % n + 1) % n;
+        let (mut b, mut e, mut r) = (mont.mul(b % n, mulmod128(one, one, n)), e, one);
+        while e != 0 {
+            if e & 1 == 1 { r = mont.mul(r, b); }
+            b = mont.mul(b, b);
+            e >>= 1;
+        }
+        return mont.mul(r, 1);
+    }
+    let (mut b, mut e, mut r) = (b % n, e, 1 % n);
+    while e != 0 {
+        if e & 1 == 1 { r = mulmod128(r, b, n); }
+        b = mulmod128(b, b, n);
+        e >>= 1;
+    }
+    r
+}
+
+///Checks if a u32 is prime - deterministic Miller-Rabin.
+pub fn is_prime32(n: u32) -> bool {
+    if n < 64 { return (1_u64 << n) & 0x28208a20a08a28ac != 0; }
+    if n.is_multiple_of(2) || n.is_multiple_of(3) || n.is_multiple_of(5) { return false; }
+    MR_BASES_32.iter().all(|a| miller_rabin64(n as u64, *a as u64))
+}
+///Checks if a u64 is prime - deterministic Miller-Rabin.
+pub fn is_prime64(n: u64) -> bool {
+    if n <= u32::MAX as u64 { return is_prime32(n as u32); }
+    if n.is_multiple_of(2) || n.is_multiple_of(3) || n.is_multiple_of(5) { return false; }
+    MR_BASES_64[..12].iter().all(|a| miller_rabin64(n, *a))
+}
+///Checks if a u128 is prime. Miller-Rabin is deterministic below 3.3e24 - above that a strong Lucas test is added,
+///making this the Baillie-PSW test, which has no known counterexamples.
+pub fn is_prime128(n: u128) -> bool {
+    if n <= u64::MAX as u128 { return is_prime64(n as u64); }
+    if MR_BASES_64.iter().any(|p| n.is_multiple_of(*p as u128)) { return false; }
+    if !MR_BASES_64.iter().all(|a| miller_rabin128(n, *a as u128)) { return false; }
+    n < MR_BOUND_128 || strong_lucas128(n)
+}
+///Miller-Rabin strong probable prime test of an odd n > a to the base a.
+fn miller_rabin64(n: u64, a: u64) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let mut x = powmod64(a, (n - 1) >> s, n);
+    if x == 1 || x == n - 1 { return true; }
+    for _ in 1..s {
+        x = mulmod64(x, x, n);
+        if x == n - 1 { return true; }
+    }
+    false
+}
+fn miller_rabin128(n: u128, a: u128) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let mut x = powmod128(a, (n - 1) >> s, n);
+    if x == 1 || x == n - 1 { return true; }
+    for _ in 1..s {
+        x = mulmod128(x, x, n);
+        if x == n - 1 { return true; }
+    }
+    false
+}
+///Strong Lucas probable prime test of an odd n with no small factors, with Selfridge's parameters - the first D in
+///5, -7, 9, -11... with Jacobi symbol (D/n) = -1, P = 1 and Q = (1 - D)/4.
+fn strong_lucas128(n: u128) -> bool {
+    if super::roots::is_perfect_square(n) { return false; }
+    let residue = |x: i128| -> u128 { match x < 0 { true => n - (x.unsigned_abs() % n), false => x as u128 % n } };
+    let mut d: i128 = 5;
+    while jacobi128(residue(d), n) != -1 {
+        d = match d > 0 { true => -d - 2, false => -d + 2 };
+    }
+    let (dm, q) = (residue(d), residue((1 - d) / 4));
+    let half = |x: u128| -> u128 { match x & 1 { 0 => x >> 1, _ => (x >> 1) + (n >> 1) + 1 } };
+    let sub = |a: u128, b: u128| -> u128 { match a >= b { true => a - b, false => n - (b - a) } };
+    //n + 1 = k*2^s, walk the bits of k to get U_k, V_k and Q^k.
+    let s = (n + 1).trailing_zeros();
+    let k = (n + 1) >> s;
+    let (mut u, mut v, mut qk) = (1_u128, 1_u128, q);
+    for bit in (0..(127 - k.leading_zeros())).rev() {
+        u = mulmod128(u, v, n);
+        v = sub(mulmod128(v, v, n), addmod128(qk, qk, n));
+        qk = mulmod128(qk, qk, n);
+        if (k >> bit) & 1 == 1 {
+            (u, v) = (half(addmod128(u, v, n)), half(addmod128(mulmod128(dm, u, n), v, n)));
+            qk = mulmod128(qk, q, n);
+        }
+    }
+    if u == 0 || v == 0 { return true; }
+    for _ in 1..s {
+        v = sub(mulmod128(v, v, n), addmod128(qk, qk, n));
+        if v == 0 { return true; }
+        qk = mulmod128(qk, qk, n);
+    }
+    false
+}
+///Jacobi symbol (a/n) for odd n.
+fn jacobi128(a: u128, n: u128) -> i32 {
+    let (mut a, mut n, mut r) = (a % n, n, 1);
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 { r = -r; }
+        }
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 { r = -r; }
+        a %= n;
+    }
+    match n == 1 { true => r, false => 0 }
+}
+
+///Returns the smallest prime greater than n - None if it doesn't fit in a u32.
+pub fn next_prime32(n: u32) -> Option<u32> { next_prime64(n as u64).and_then(|p| u32::try_from(p).ok()) }
+///Returns the smallest prime greater than n - None if it doesn't fit in a u64.
+pub fn next_prime64(n: u64) -> Option<u64> {
+    if n < 2 { return Some(2); }
+    let mut p = n.checked_add(1 + (n & 1))?;
+    while !is_prime64(p) { p = p.checked_add(2)?; }
+    Some(p)
+}
+///Returns the smallest prime greater than n - None if it doesn't fit in a u128.
+pub fn next_prime128(n: u128) -> Option<u128> {
+    if n < 2 { return Some(2); }
+    let mut p = n.checked_add(1 + (n & 1))?;
+    while !is_prime128(p) { p = p.checked_add(2)?; }
+    Some(p)
+}
+///Returns the largest prime less than n - None if n <= 2.
+pub fn prev_prime32(n: u32) -> Option<u32> { prev_prime64(n as u64).map(|p| p as u32) }
+///Returns the largest prime less than n - None if n <= 2.
+pub fn prev_prime64(n: u64) -> Option<u64> {
+    match n {
+        0..=2 => None,
+        3 => Some(2),
+        _ => {
+            let mut p = n - 1 - (n & 1);
+            while !is_prime64(p) { p -= 2; }
+            Some(p)
+        }
+    }
+}
+///Returns the largest prime less than n - None if n <= 2.
+pub fn prev_prime128(n: u128) -> Option<u128> {
+    match n {
+        0..=2 => None,
+        3 => Some(2),
+        _ => {
+            let mut p = n - 1 - (n & 1);
+            while !is_prime128(p) { p -= 2; }
+            Some(p)
+        }
+    }
+}
+///Returns the nth prime, counting from nth_prime(1) = 2 - panics if n is zero.
+pub fn nth_prime(n: usize) -> u64 {
+    if n == 0 { panic!("There is no zeroth prime - primes are counted from nth_prime(1) = 2."); }
+    let mut p = 2;
+    for _ in 1..n { p = next_prime64(p).expect("Tried to find a prime larger than u64::MAX."); }
+    p
+}
+
+const fn small_primes() -> [u32; SMALL_PRIME_COUNT] {
+    let (mut table, mut composite) = ([0; SMALL_PRIME_COUNT], [false; 1000]);
+    let (mut i, mut k) = (2, 0);
+    while i < 1000 {
+        if !composite[i] {
+            table[k] = i as u32;
+            k += 1;
+            let mut m = i * i;
+            while m < 1000 { composite[m] = true; m += i; }
+        }
+        i += 1;
+    }
+    table
+}
+
+///Segmented sieve of Eratosthenes - iterates over the primes in [lo, hi) in increasing order. Memory use is one
+///segment plus the primes up to sqrt(hi), however large the range.
+#[derive(Clone, Debug)]
+pub struct PrimeSieve {
+    hi: u64,
+    ///Primes p with p*p < hi.
+    base: Vec<u32>,
+    ///Start of the current segment.
+    seg_lo: u64,
+    ///Primality of seg_lo, seg_lo + 1, ...
+    segment: Vec<bool>,
+    pos: usize,
+}
+impl PrimeSieve {
+    ///Returns a sieve over the primes in [lo, hi).
+    pub fn new(lo: u64, hi: u64) -> Self {
+        let bound = hi.saturating_sub(1).isqrt();
+        let base = match bound < SIEVE_SEGMENT as u64 {
+            true => Self::simple(bound as usize),
+            false => PrimeSieve::new(2, bound + 1).map(|p| p as u32).collect(),
+        };
+        let mut sieve = Self { hi, base, seg_lo: lo.max(2), segment: Vec::new(), pos: 0 };
+        sieve.fill();
+        sieve
+    }
+    ///Plain sieve of the primes up to and including n.
+    fn simple(n: usize) -> Vec<u32> {
+        let mut composite = vec![false; n + 1];
+        let mut primes = Vec::new();
+        for i in 2..=n {
+            if composite[i] { continue; }
+            primes.push(i as u32);
+            for m in (i * i..=n).step_by(i) { composite[m] = true; }
+        }
+        primes
+    }
+    ///Sieves the segment starting at seg_lo.
+    fn fill(&mut self) {
+        let len = self.hi.saturating_sub(self.seg_lo).min(SIEVE_SEGMENT as u64);
+        let seg_hi = self.seg_lo + len;
+        self.segment.clear();
+        self.segment.resize(len as usize, true);
+        self.pos = 0;
+        for p in &self.base {
+            let p = *p as u64;
+            if p * p >= seg_hi { break; }
+            let Some(mut m) = self.seg_lo.div_ceil(p).checked_mul(p) else { continue; };
+            m = m.max(p * p);
+            while m < seg_hi {
+                self.segment[(m - self.seg_lo) as usize] = false;
+                m = match m.checked_add(p) { Some(m) => m, None => break };
+            }
+        }
+    }
+}
+impl Iterator for PrimeSieve {
+    type Item = u64;
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            while self.pos < self.segment.len() {
+                self.pos += 1;
+                if self.segment[self.pos - 1] { return Some(self.seg_lo + self.pos as u64 - 1); }
+            }
+            if self.segment.is_empty() { return None; }
+            self.seg_lo += self.segment.len() as u64;
+            self.fill();
+        }
+    }
+}
+///Returns the number of primes less than or equal to n.
+pub fn prime_pi(n: u64) -> u64 {
+    let count = PrimeSieve::new(2, n.saturating_add(1)).count() as u64;
+    match n == u64::MAX { true => count + is_prime64(n) as u64, false => count }
+}
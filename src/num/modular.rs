@@ -0,0 +1,225 @@
+use std::fmt::{Display, Formatter};
+use std::ops::*;
+use super::Identity;
+use super::factors::{extended_gcd, gcd128, mulmod64, mulmod128};
+
+///Modular integer with a compile-time modulus M - values are kept reduced to [0, M). M must be non-zero, and
+///division only works for values coprime to M.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct ModInt<const M: u64> {
+    v: u64,
+}
+impl<const M: u64> ModInt<M> {
+    ///Returns v mod M.
+    pub fn new(v: u64) -> Self { Self { v: v % M } }
+    ///Returns v mod M for a signed v - always in [0, M).
+    pub fn from_i64(v: i64) -> Self { Self { v: (v as i128).rem_euclid(M as i128) as u64 } }
+    ///Returns the reduced value in [0, M).
+    pub fn value(&self) -> u64 { self.v }
+    ///Returns the modulus M.
+    pub fn modulus() -> u64 { M }
+    ///Returns self^e by square and multiply - using Montgomery multiplication when M is odd.
+    pub fn pow(&self, e: u64) -> Self { Self { v: pow_mod(self.v, e, M) } }
+    ///Returns the multiplicative inverse - None if the value isn't coprime to M.
+    pub fn inverse(&self) -> Option<Self> { mod_inverse(self.v, M).map(|v| Self { v }) }
+}
+
+///Modular integer with a modulus chosen at runtime - values are kept reduced to [0, modulus). Combining values with
+///different moduli panics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    v: u64,
+    m: u64,
+}
+impl DynModInt {
+    ///Returns v mod m - panics if m is zero.
+    pub fn new(v: u64, m: u64) -> Self {
+        if m == 0 { panic!("Modulus of a modular integer can never be zero!"); }
+        Self { v: v % m, m }
+    }
+    ///Returns v mod m for a signed v - always in [0, m). Panics if m is zero.
+    pub fn from_i64(v: i64, m: u64) -> Self {
+        if m == 0 { panic!("Modulus of a modular integer can never be zero!"); }
+        Self { v: (v as i128).rem_euclid(m as i128) as u64, m }
+    }
+    ///Returns the reduced value in [0, modulus).
+    pub fn value(&self) -> u64 { self.v }
+    ///Returns the modulus.
+    pub fn modulus(&self) -> u64 { self.m }
+    ///Returns self^e by square and multiply - using Montgomery multiplication when the modulus is odd.
+    pub fn pow(&self, e: u64) -> Self { Self { v: pow_mod(self.v, e, self.m), m: self.m } }
+    ///Returns the multiplicative inverse - None if the value isn't coprime to the modulus.
+    pub fn inverse(&self) -> Option<Self> { mod_inverse(self.v, self.m).map(|v| Self { v, m: self.m }) }
+    fn check(&self, other: &Self) -> u64 {
+        match self.m == other.m {
+            true => self.m,
+            false => panic!("Tried to combine modular integers with different moduli - {} and {}.", self.m, other.m),
+        }
+    }
+}
+
+///Montgomery form arithmetic for an odd modulus n - values are stored as aR mod n with R = 2^64, so products reduce
+///with shifts and multiplies instead of a 128 bit division.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Montgomery {
+    n: u64,
+    ///n^-1 mod R.
+    n_inv: u64,
+    ///R^2 mod n.
+    r2: u64,
+}
+impl Montgomery {
+    ///Returns the Montgomery context for n - None if n is even.
+    pub fn new(n: u64) -> Option<Self> {
+        if n.is_multiple_of(2) { return None; }
+        //Newton's iteration doubles the correct low bits each step, and n is its own inverse mod 8.
+        let mut n_inv = n;
+        for _ in 0..5 { n_inv = n_inv.wrapping_mul(2_u64.wrapping_sub(n.wrapping_mul(n_inv))); }
+        let r = ((1_u128 << 64) % n as u128) as u64;
+        Some(Self { n, n_inv, r2: mulmod64(r, r, n) })
+    }
+    ///Returns the modulus.
+    pub fn modulus(&self) -> u64 { self.n }
+    ///Converts a into Montgomery form aR mod n.
+    pub fn to_mont(&self, a: u64) -> u64 { self.redc((a % self.n) as u128 * self.r2 as u128) }
+    ///Converts aR mod n back to a.
+    pub fn from_mont(&self, a: u64) -> u64 { self.redc(a as u128) }
+    ///Multiplies two values in Montgomery form.
+    pub fn mul(&self, a: u64, b: u64) -> u64 { self.redc(a as u128 * b as u128) }
+    ///Returns a^e mod n for a and the result in normal form.
+    pub fn pow(&self, a: u64, e: u64) -> u64 {
+        let (mut b, mut e, mut r) = (self.to_mont(a), e, self.to_mont(1));
+        while e != 0 {
+            if e & 1 == 1 { r = self.mul(r, b); }
+            b = self.mul(b, b);
+            e >>= 1;
+        }
+        self.from_mont(r)
+    }
+    ///Montgomery reduction - returns t/R mod n for t < nR. Subtracting mn rather than adding it keeps every step
+    ///inside 128 bits even for n close to 2^64.
+    fn redc(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.n_inv);
+        let mn = m as u128 * self.n as u128;
+        let (hi, mn_hi) = ((t >> 64) as u64, (mn >> 64) as u64);
+        match hi >= mn_hi {
+            true => hi - mn_hi,
+            false => hi.wrapping_sub(mn_hi).wrapping_add(self.n),
+        }
+    }
+}
+
+///Returns a^e mod n, using Montgomery multiplication for odd n.
+fn pow_mod(a: u64, e: u64, n: u64) -> u64 {
+    match Montgomery::new(n) {
+        Some(mont) if n > 1 => mont.pow(a, e),
+        _ => super::factors::powmod64(a, e, n),
+    }
+}
+
+///Returns the inverse of a mod m via the extended Euclidean algorithm - None if a and m aren't coprime, or m is zero.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 { return None; }
+    let (g, x, _y) = extended_gcd(a % m, m);
+    match g == 1 {
+        true => Some((x as i128).rem_euclid(m as i128) as u64),
+        false => None,
+    }
+}
+
+///Chinese Remainder Theorem - returns the x with x = r (mod m) for every (r, m) pair, as (x, lcm of the moduli) with
+///x in [0, lcm). The moduli don't need to be coprime. None if the congruences are inconsistent, a modulus is zero, or
+///the lcm overflows a u128.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u128, u128)> {
+    let (mut x, mut l) = (0_u128, 1_u128);
+    for (r, m) in congruences {
+        if *m == 0 { return None; }
+        let m = *m as u128;
+        let g = gcd128(l, m);
+        //x + l*k = r (mod m) needs l*k = r - x (mod m), solvable when g divides r - x.
+        let diff = (*r as u128 % m + m - x % m) % m;
+        if !diff.is_multiple_of(g) { return None; }
+        let mg = m / g;
+        let inv = mod_inverse(((l / g) % mg) as u64, mg as u64)? as u128;
+        let k = mulmod128(diff / g, inv, mg);
+        let lcm = l.checked_mul(mg)?;
+        (x, l) = (x + l * k, lcm);
+    }
+    Some((x % l, l))
+}
+
+impl<const M: u64> Identity for ModInt<M> {
+    fn identity() -> Self { Self::new(1) }
+}
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(other: u64) -> Self { Self::new(other) }
+}
+impl<const M: u64> From<ModInt<M>> for u64 {
+    fn from(other: ModInt<M>) -> u64 { other.v }
+}
+impl<const M: u64> From<ModInt<M>> for DynModInt {
+    fn from(other: ModInt<M>) -> Self { Self { v: other.v, m: M } }
+}
+impl<const M: u64> Display for ModInt<M> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result { write!(f, "{} (mod {})", self.v, M) }
+}
+impl Display for DynModInt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result { write!(f, "{} (mod {})", self.v, self.m) }
+}
+
+impl<const M: u64> Add<ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn add(self, other: ModInt<M>) -> ModInt<M> { ModInt { v: ((self.v as u128 + other.v as u128) % M as u128) as u64 } } }
+impl<const M: u64> Add<&ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn add(self, other: &ModInt<M>) -> ModInt<M> { self + *other } }
+impl<const M: u64> Add<ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn add(self, other: ModInt<M>) -> ModInt<M> { *self + other } }
+impl<const M: u64> Add<&ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn add(self, other: &ModInt<M>) -> ModInt<M> { *self + *other } }
+impl<const M: u64> AddAssign<ModInt<M>> for ModInt<M> { fn add_assign(&mut self, other: ModInt<M>) { *self = *self + other; } }
+impl<const M: u64> AddAssign<&ModInt<M>> for ModInt<M> { fn add_assign(&mut self, other: &ModInt<M>) { *self = *self + *other; } }
+impl<const M: u64> Sub<ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn sub(self, other: ModInt<M>) -> ModInt<M> { ModInt { v: ((self.v as u128 + M as u128 - other.v as u128) % M as u128) as u64 } } }
+impl<const M: u64> Sub<&ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn sub(self, other: &ModInt<M>) -> ModInt<M> { self - *other } }
+impl<const M: u64> Sub<ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn sub(self, other: ModInt<M>) -> ModInt<M> { *self - other } }
+impl<const M: u64> Sub<&ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn sub(self, other: &ModInt<M>) -> ModInt<M> { *self - *other } }
+impl<const M: u64> SubAssign<ModInt<M>> for ModInt<M> { fn sub_assign(&mut self, other: ModInt<M>) { *self = *self - other; } }
+impl<const M: u64> SubAssign<&ModInt<M>> for ModInt<M> { fn sub_assign(&mut self, other: &ModInt<M>) { *self = *self - *other; } }
+impl<const M: u64> Mul<ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn mul(self, other: ModInt<M>) -> ModInt<M> { ModInt { v: mulmod64(self.v, other.v, M) } } }
+impl<const M: u64> Mul<&ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn mul(self, other: &ModInt<M>) -> ModInt<M> { self * *other } }
+impl<const M: u64> Mul<ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn mul(self, other: ModInt<M>) -> ModInt<M> { *self * other } }
+impl<const M: u64> Mul<&ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn mul(self, other: &ModInt<M>) -> ModInt<M> { *self * *other } }
+impl<const M: u64> MulAssign<ModInt<M>> for ModInt<M> { fn mul_assign(&mut self, other: ModInt<M>) { *self = *self * other; } }
+impl<const M: u64> MulAssign<&ModInt<M>> for ModInt<M> { fn mul_assign(&mut self, other: &ModInt<M>) { *self = *self * *other; } }
+//Division is multiplication by the modular inverse.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const M: u64> Div<ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn div(self, other: ModInt<M>) -> ModInt<M> { self * other.inverse().expect("Tried to divide by a modular integer with no inverse!") } }
+impl<const M: u64> Div<&ModInt<M>> for ModInt<M> { type Output = ModInt<M>; fn div(self, other: &ModInt<M>) -> ModInt<M> { self / *other } }
+impl<const M: u64> Div<ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn div(self, other: ModInt<M>) -> ModInt<M> { *self / other } }
+impl<const M: u64> Div<&ModInt<M>> for &ModInt<M> { type Output = ModInt<M>; fn div(self, other: &ModInt<M>) -> ModInt<M> { *self / *other } }
+impl<const M: u64> DivAssign<ModInt<M>> for ModInt<M> { fn div_assign(&mut self, other: ModInt<M>) { *self = *self / other; } }
+impl<const M: u64> DivAssign<&ModInt<M>> for ModInt<M> { fn div_assign(&mut self, other: &ModInt<M>) { *self = *self / *other; } }
+impl<const M: u64> Neg for ModInt<M> { type Output = ModInt<M>; fn neg(self) -> ModInt<M> { ModInt { v: (M - self.v) % M } } }
+impl<const M: u64> Neg for &ModInt<M> { type Output = ModInt<M>; fn neg(self) -> ModInt<M> { -*self } }
+impl Add<DynModInt> for DynModInt { type Output = DynModInt; fn add(self, other: DynModInt) -> DynModInt { { let m = self.check(&other); DynModInt { v: ((self.v as u128 + other.v as u128) % m as u128) as u64, m } } } }
+impl Add<&DynModInt> for DynModInt { type Output = DynModInt; fn add(self, other: &DynModInt) -> DynModInt { self + *other } }
+impl Add<DynModInt> for &DynModInt { type Output = DynModInt; fn add(self, other: DynModInt) -> DynModInt { *self + other } }
+impl Add<&DynModInt> for &DynModInt { type Output = DynModInt; fn add(self, other: &DynModInt) -> DynModInt { *self + *other } }
+impl AddAssign<DynModInt> for DynModInt { fn add_assign(&mut self, other: DynModInt) { *self = *self + other; } }
+impl AddAssign<&DynModInt> for DynModInt { fn add_assign(&mut self, other: &DynModInt) { *self = *self + *other; } }
+impl Sub<DynModInt> for DynModInt { type Output = DynModInt; fn sub(self, other: DynModInt) -> DynModInt { { let m = self.check(&other); DynModInt { v: ((self.v as u128 + m as u128 - other.v as u128) % m as u128) as u64, m } } } }
+impl Sub<&DynModInt> for DynModInt { type Output = DynModInt; fn sub(self, other: &DynModInt) -> DynModInt { self - *other } }
+impl Sub<DynModInt> for &DynModInt { type Output = DynModInt; fn sub(self, other: DynModInt) -> DynModInt { *self - other } }
+impl Sub<&DynModInt> for &DynModInt { type Output = DynModInt; fn sub(self, other: &DynModInt) -> DynModInt { *self - *other } }
+impl SubAssign<DynModInt> for DynModInt { fn sub_assign(&mut self, other: DynModInt) { *self = *self - other; } }
+impl SubAssign<&DynModInt> for DynModInt { fn sub_assign(&mut self, other: &DynModInt) { *self = *self - *other; } }
+impl Mul<DynModInt> for DynModInt { type Output = DynModInt; fn mul(self, other: DynModInt) -> DynModInt { { let m = self.check(&other); DynModInt { v: mulmod64(self.v, other.v, m), m } } } }
+impl Mul<&DynModInt> for DynModInt { type Output = DynModInt; fn mul(self, other: &DynModInt) -> DynModInt { self * *other } }
+impl Mul<DynModInt> for &DynModInt { type Output = DynModInt; fn mul(self, other: DynModInt) -> DynModInt { *self * other } }
+impl Mul<&DynModInt> for &DynModInt { type Output = DynModInt; fn mul(self, other: &DynModInt) -> DynModInt { *self * *other } }
+impl MulAssign<DynModInt> for DynModInt { fn mul_assign(&mut self, other: DynModInt) { *self = *self * other; } }
+impl MulAssign<&DynModInt> for DynModInt { fn mul_assign(&mut self, other: &DynModInt) { *self = *self * *other; } }
+//Division is multiplication by the modular inverse.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div<DynModInt> for DynModInt { type Output = DynModInt; fn div(self, other: DynModInt) -> DynModInt { self * other.inverse().expect("Tried to divide by a modular integer with no inverse!") } }
+impl Div<&DynModInt> for DynModInt { type Output = DynModInt; fn div(self, other: &DynModInt) -> DynModInt { self / *other } }
+impl Div<DynModInt> for &DynModInt { type Output = DynModInt; fn div(self, other: DynModInt) -> DynModInt { *self / other } }
+impl Div<&DynModInt> for &DynModInt { type Output = DynModInt; fn div(self, other: &DynModInt) -> DynModInt { *self / *other } }
+impl DivAssign<DynModInt> for DynModInt { fn div_assign(&mut self, other: DynModInt) { *self = *self / other; } }
+impl DivAssign<&DynModInt> for DynModInt { fn div_assign(&mut self, other: &DynModInt) { *self = *self / *other; } }
+impl Neg for DynModInt { type Output = DynModInt; fn neg(self) -> DynModInt { DynModInt { v: (self.m - self.v) % self.m, m: self.m } } }
+impl Neg for &DynModInt { type Output = DynModInt; fn neg(self) -> DynModInt { -*self } }
@@ -0,0 +1,183 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::*;
+use super::rational::{r32, r64, rbig};
+use super::quadratic::{QuadraticCoef, QuadraticSurd};
+use super::{Absolute, Conjugate, Identity, Scalar, Sqroot};
+
+///Nested Surd Type - an element a + b*sqrt(r) of a quadratic extension of the quadratic field Q(sqrt(n)), with a, b and
+///the radicand r all in Q(sqrt(n)) and r positive and not a square there. Represents values like sin(pi/5) =
+///sqrt(10 - 2*sqrt(5))/4 that QuadraticSurd can't. Values with b = 0 have r = 1 and combine with any radicand - combining
+///values with different radicands r, or from different fields Q(sqrt(n)), panics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NestedSurd<T> {
+    ///Part in Q(sqrt(n)).
+    pub a: QuadraticSurd<T>,
+    ///Coefficient of the nested surd part.
+    pub b: QuadraticSurd<T>,
+    ///Radicand - positive and not a square in Q(sqrt(n)).
+    pub r: QuadraticSurd<T>,
+}
+impl<T: QuadraticCoef> NestedSurd<T> {
+    ///Returns a new nested surd a + b*sqrt(r) - r must be positive and not a square in the field of a and b, which isn't
+    ///checked. Panics if b is non-zero and r isn't positive.
+    pub fn new(a: QuadraticSurd<T>, b: QuadraticSurd<T>, r: QuadraticSurd<T>) -> Self {
+        if b != QuadraticSurd::default() && r.signum() <= 0 { panic!("Radicand for a NestedSurd should always be positive."); }
+        Self::new_raw(a, b, r)
+    }
+    ///Returns a new nested surd with no nested part.
+    pub fn from_quadratic(a: QuadraticSurd<T>) -> Self { Self { a, b: QuadraticSurd::default(), r: QuadraticSurd::identity() } }
+    ///Tries to convert to a quadratic surd - only possible if the nested part is zero.
+    pub fn quadratic(&self) -> Option<QuadraticSurd<T>> { match self.b == QuadraticSurd::default() {
+        true => Some(self.a.clone()), false => None
+    }}
+    ///Returns the relative norm (a + b*sqrt(r))(a - b*sqrt(r)) = a^2 - r*b^2, which is in Q(sqrt(n)).
+    pub fn norm(&self) -> QuadraticSurd<T> { &(&self.a * &self.a) - &(&(&self.b * &self.b) * &self.r) }
+    ///Returns the exact sign of the value.
+    pub fn signum(&self) -> i32 {
+        let (sa, sb) = (self.a.signum(), self.b.signum());
+        if sb == 0 || sa == sb { return sa; }
+        if sa == 0 { return sb; }
+        //Opposite signs - whichever part has the larger square wins (they can't be equal as r isn't a square).
+        match self.norm().signum() > 0 {
+            true => sa,
+            false => sb,
+        }
+    }
+    ///Returns the reciprocal by rationalising the denominator - None if zero.
+    pub fn reciprocal(&self) -> Option<Self> {
+        let norm = self.norm().reciprocal()?;
+        Some(Self::new_raw(&self.a * &norm, -(&self.b * &norm), self.r.clone()))
+    }
+    ///Checks if both values can be combined - their nested radicands match or either has no nested part, and every
+    ///part is in the same quadratic field.
+    pub fn compatible(&self, other: &Self) -> bool {
+        let nested = self.r == other.r || self.r == QuadraticSurd::identity() || other.r == QuadraticSurd::identity();
+        let (n, m) = (self.field_n(), other.field_n());
+        nested && (n == m || n == 1 || m == 1)
+    }
+    fn field_n(&self) -> u64 { self.a.n.max(self.b.n).max(self.r.n) }
+    fn new_raw(a: QuadraticSurd<T>, b: QuadraticSurd<T>, r: QuadraticSurd<T>) -> Self {
+        match b == QuadraticSurd::default() || r == QuadraticSurd::identity() {
+            true => Self::from_quadratic(a + b),
+            false => Self { a, b, r },
+        }
+    }
+    fn product(&self, other: &Self) -> Self {
+        //(a + b*sqrt(r))(c + d*sqrt(r)) = (ac + r*bd) + (ad + bc)*sqrt(r)
+        let r = self.radicand(other);
+        let a = &(&self.a * &other.a) + &(&(&self.b * &other.b) * &r);
+        let b = &(&self.a * &other.b) + &(&self.b * &other.a);
+        Self::new_raw(a, b, r)
+    }
+    fn radicand(&self, other: &Self) -> QuadraticSurd<T> {
+        match self.compatible(other) {
+            true if self.r == QuadraticSurd::identity() => other.r.clone(),
+            true => self.r.clone(),
+            false => panic!("Tried to combine nested surds with different radicands or fields - sqrt({:?}) and sqrt({:?}).", self.r, other.r),
+        }
+    }
+}
+
+///Exact for values without a nested part whose square root is a rational surd, otherwise lossy like QuadraticSurd.
+impl Scalar for NestedSurd<r64> {
+    fn epsilon() -> Self { Self::default() }
+    fn scalar_sqrt(&self) -> Self { Self::from_quadratic(match self.quadratic() {
+        Some(q) => q.scalar_sqrt(),
+        None => QuadraticSurd::from_rational(r64::from(f64::from(*self).sqrt())),
+    })}
+}
+///Lossy unless the value is rational, as for rbig itself.
+impl Scalar for NestedSurd<rbig> {
+    fn epsilon() -> Self { Self::default() }
+    fn scalar_sqrt(&self) -> Self { Self::from_quadratic(match self.quadratic() {
+        Some(q) => q.scalar_sqrt(),
+        None => QuadraticSurd::from_rational(rbig::from(f64::from(self.clone()).sqrt())),
+    })}
+}
+impl Sqroot for NestedSurd<r64> {
+    type Output = NestedSurd<r64>;
+    fn sqroot(&self) -> Self { self.scalar_sqrt() }
+}
+impl Sqroot for NestedSurd<rbig> {
+    type Output = NestedSurd<rbig>;
+    fn sqroot(&self) -> Self { self.scalar_sqrt() }
+}
+impl<T: QuadraticCoef> Absolute for NestedSurd<T> {
+    type Output = NestedSurd<T>;
+    fn absolute(&self) -> Self { match self.signum() < 0 { true => -self, false => self.clone() } }
+}
+impl<T: QuadraticCoef> Conjugate for NestedSurd<T> {
+    fn conjugate(&self) -> Self { Self { a: self.a.clone(), b: -self.b.clone(), r: self.r.clone() } }
+}
+impl<T: QuadraticCoef> Default for NestedSurd<T> {
+    fn default() -> Self { Self::from_quadratic(QuadraticSurd::default()) }
+}
+impl<T: QuadraticCoef> Identity for NestedSurd<T> {
+    fn identity() -> Self { Self::from_quadratic(QuadraticSurd::identity()) }
+}
+impl<T: QuadraticCoef> PartialOrd for NestedSurd<T> {
+    ///Exact comparison - None if the values can't be combined.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.compatible(other) {
+            true => Some((self - other).signum().cmp(&0)),
+            false => None,
+        }
+    }
+}
+impl<T: QuadraticCoef + Display> Display for NestedSurd<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "({}) + ({})*sqrt({})", self.a, self.b, self.r)
+    }
+}
+
+impl<T: QuadraticCoef> From<QuadraticSurd<T>> for NestedSurd<T> {
+    fn from(other: QuadraticSurd<T>) -> Self { Self::from_quadratic(other) }
+}
+impl From<r64> for NestedSurd<r64> {
+    fn from(other: r64) -> Self { Self::from_quadratic(QuadraticSurd::from_rational(other)) }
+}
+impl From<r32> for NestedSurd<r64> {
+    fn from(other: r32) -> Self { Self::from_quadratic(QuadraticSurd::from(other)) }
+}
+impl From<rbig> for NestedSurd<rbig> {
+    fn from(other: rbig) -> Self { Self::from_quadratic(QuadraticSurd::from_rational(other)) }
+}
+impl<T: QuadraticCoef> From<NestedSurd<T>> for f64 where f64: From<QuadraticSurd<T>> {
+    fn from(other: NestedSurd<T>) -> f64 { f64::from(other.a) + f64::from(other.b) * f64::from(other.r).sqrt() }
+}
+
+impl<T: QuadraticCoef> Add<&NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn add(self, other: &NestedSurd<T>) -> NestedSurd<T> {
+    let r = self.radicand(other);
+    NestedSurd::new_raw(&self.a + &other.a, &self.b + &other.b, r)
+} }
+impl<T: QuadraticCoef> Sub<&NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn sub(self, other: &NestedSurd<T>) -> NestedSurd<T> {
+    let r = self.radicand(other);
+    NestedSurd::new_raw(&self.a - &other.a, &self.b - &other.b, r)
+} }
+impl<T: QuadraticCoef> Mul<&NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn mul(self, other: &NestedSurd<T>) -> NestedSurd<T> { self.product(other) } }
+impl<T: QuadraticCoef> Div<&NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn div(self, other: &NestedSurd<T>) -> NestedSurd<T> {
+    self.product(&other.reciprocal().expect("Tried to divide a NestedSurd by zero!"))
+} }
+impl<T: QuadraticCoef> Neg for &NestedSurd<T> { type Output = NestedSurd<T>; fn neg(self) -> NestedSurd<T> { NestedSurd { a: -&self.a, b: -&self.b, r: self.r.clone() } } }
+impl<T: QuadraticCoef> Neg for NestedSurd<T> { type Output = NestedSurd<T>; fn neg(self) -> NestedSurd<T> { -&self } }
+impl<T: QuadraticCoef> Add<NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn add(self, other: NestedSurd<T>) -> NestedSurd<T> { &self + &other } }
+impl<T: QuadraticCoef> Add<&NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn add(self, other: &NestedSurd<T>) -> NestedSurd<T> { &self + other } }
+impl<T: QuadraticCoef> Add<NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn add(self, other: NestedSurd<T>) -> NestedSurd<T> { self + &other } }
+impl<T: QuadraticCoef> AddAssign<NestedSurd<T>> for NestedSurd<T> { fn add_assign(&mut self, other: NestedSurd<T>) { *self = &*self + &other; } }
+impl<T: QuadraticCoef> AddAssign<&NestedSurd<T>> for NestedSurd<T> { fn add_assign(&mut self, other: &NestedSurd<T>) { *self = &*self + other; } }
+impl<T: QuadraticCoef> Sub<NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn sub(self, other: NestedSurd<T>) -> NestedSurd<T> { &self - &other } }
+impl<T: QuadraticCoef> Sub<&NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn sub(self, other: &NestedSurd<T>) -> NestedSurd<T> { &self - other } }
+impl<T: QuadraticCoef> Sub<NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn sub(self, other: NestedSurd<T>) -> NestedSurd<T> { self - &other } }
+impl<T: QuadraticCoef> SubAssign<NestedSurd<T>> for NestedSurd<T> { fn sub_assign(&mut self, other: NestedSurd<T>) { *self = &*self - &other; } }
+impl<T: QuadraticCoef> SubAssign<&NestedSurd<T>> for NestedSurd<T> { fn sub_assign(&mut self, other: &NestedSurd<T>) { *self = &*self - other; } }
+impl<T: QuadraticCoef> Mul<NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn mul(self, other: NestedSurd<T>) -> NestedSurd<T> { &self * &other } }
+impl<T: QuadraticCoef> Mul<&NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn mul(self, other: &NestedSurd<T>) -> NestedSurd<T> { &self * other } }
+impl<T: QuadraticCoef> Mul<NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn mul(self, other: NestedSurd<T>) -> NestedSurd<T> { self * &other } }
+impl<T: QuadraticCoef> MulAssign<NestedSurd<T>> for NestedSurd<T> { fn mul_assign(&mut self, other: NestedSurd<T>) { *self = &*self * &other; } }
+impl<T: QuadraticCoef> MulAssign<&NestedSurd<T>> for NestedSurd<T> { fn mul_assign(&mut self, other: &NestedSurd<T>) { *self = &*self * other; } }
+impl<T: QuadraticCoef> Div<NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn div(self, other: NestedSurd<T>) -> NestedSurd<T> { &self / &other } }
+impl<T: QuadraticCoef> Div<&NestedSurd<T>> for NestedSurd<T> { type Output = NestedSurd<T>; fn div(self, other: &NestedSurd<T>) -> NestedSurd<T> { &self / other } }
+impl<T: QuadraticCoef> Div<NestedSurd<T>> for &NestedSurd<T> { type Output = NestedSurd<T>; fn div(self, other: NestedSurd<T>) -> NestedSurd<T> { self / &other } }
+impl<T: QuadraticCoef> DivAssign<NestedSurd<T>> for NestedSurd<T> { fn div_assign(&mut self, other: NestedSurd<T>) { *self = &*self / &other; } }
+impl<T: QuadraticCoef> DivAssign<&NestedSurd<T>> for NestedSurd<T> { fn div_assign(&mut self, other: &NestedSurd<T>) { *self = &*self / other; } }
@@ -0,0 +1,255 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::*;
+use super::rational::{r32, r64, rbig};
+use super::surd::{surd32, surd64};
+use super::{Absolute, Conjugate, Scalar, Sqroot};
+
+///Coefficient trait for QuadraticSurd - a Scalar that can be multiplied by the u64 radicand. Implemented for r64 and rbig.
+pub trait QuadraticCoef: Scalar + Mul<u64, Output = Self> {}
+impl<T: Scalar + Mul<u64, Output = T>> QuadraticCoef for T {}
+
+///Quadratic Surd Type - an element a + b*sqrt(n) of the quadratic field Q(sqrt(n)), with rational a and b and square-free n.
+///Unlike surd32/surd64 these can be Added and Subtracted as well as Multiplied and Divided. Rational values always have
+///b = 0 and n = 1, so they combine with any field - combining irrational values from different fields panics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QuadraticSurd<T> {
+    ///Rational part.
+    pub a: T,
+    ///Coefficient of the surd part.
+    pub b: T,
+    ///Square-free radicand.
+    pub n: u64,
+}
+impl<T: QuadraticCoef> QuadraticSurd<T> {
+    ///Returns a new quadratic surd a + b*sqrt(n) - square factors are taken out of n.
+    pub fn new(a: T, b: T, n: u64) -> Self {
+        if n == 0 || b == T::default() { return Self::from_rational(a); }
+        let sq = super::factors::sqfac64(n).iter().map(|(s, _sq)| s).product::<u64>();
+        Self::new_raw(a, b * sq, n / (sq * sq))
+    }
+    ///Returns a new quadratic surd with no surd part.
+    pub fn from_rational(a: T) -> Self { Self { a, b: T::default(), n: 1 } }
+    ///Tries to convert to a rational number - only possible if the surd part is zero.
+    pub fn rational(&self) -> Option<T> { match self.b == T::default() {
+        true => Some(self.a.clone()), false => None
+    }}
+    ///Returns the field norm (a + b*sqrt(n))(a - b*sqrt(n)) = a^2 - n*b^2, which is always rational.
+    pub fn norm(&self) -> T { self.a.clone() * self.a.clone() - self.b.clone() * self.b.clone() * self.n }
+    ///Returns the exact sign of the value.
+    pub fn signum(&self) -> i32 {
+        let (sa, sb) = (Self::sign(&self.a), Self::sign(&self.b));
+        if sb == 0 || sa == sb { return sa; }
+        if sa == 0 { return sb; }
+        //Opposite signs - whichever part has the larger square wins (they can't be equal as n is square-free).
+        match self.a.clone() * self.a.clone() > self.b.clone() * self.b.clone() * self.n {
+            true => sa,
+            false => sb,
+        }
+    }
+    ///Returns the reciprocal by rationalising the denominator - None if zero.
+    pub fn reciprocal(&self) -> Option<Self> {
+        let norm = self.norm();
+        match norm == T::default() {
+            true => None,
+            false => Some(Self::new_raw(self.a.clone() / norm.clone(), -self.b.clone() / norm, self.n)),
+        }
+    }
+    ///Checks if both values are in the same field - rationals are in every field.
+    pub fn compatible(&self, other: &Self) -> bool { self.n == other.n || self.n == 1 || other.n == 1 }
+    fn sign(x: &T) -> i32 {
+        match x.partial_cmp(&T::default()) {
+            Some(Ordering::Greater) => 1,
+            Some(Ordering::Less) => -1,
+            _ => 0,
+        }
+    }
+    fn new_raw(a: T, b: T, n: u64) -> Self {
+        match b == T::default() || n == 1 {
+            true => Self::from_rational(a + b),
+            false => Self { a, b, n },
+        }
+    }
+    fn field(&self, other: &Self) -> u64 {
+        match self.compatible(other) {
+            true => self.n.max(other.n),
+            false => panic!("Tried to combine surds from different quadratic fields - sqrt({}) and sqrt({}).", self.n, other.n),
+        }
+    }
+}
+impl QuadraticSurd<r64> {
+    ///Returns the exact square root of a non-negative r64 - panics if negative.
+    pub fn sqrt(r: r64) -> Self {
+        if r.signum() < 0 { panic!("Tried to take the square root of a negative number."); }
+        let (n, d) = (r.numerator() as u64, r.denominator() as u64);
+        Self::new(r64::default(), r64::new_raw(1, d as u32), n * d)
+    }
+}
+impl QuadraticSurd<rbig> {
+    ///Returns the exact square root of a non-negative r64 - panics if negative.
+    pub fn sqrt(r: r64) -> Self {
+        if r.signum() < 0 { panic!("Tried to take the square root of a negative number."); }
+        let (n, d) = (r.numerator() as u64, r.denominator() as u64);
+        Self::new(rbig::default(), rbig::from(r64::new_raw(1, d as u32)), n * d)
+    }
+}
+
+///Exact for non-negative rationals, otherwise lossy like r64 - the root of a + b*sqrt(n) is generally outside the field.
+impl Scalar for QuadraticSurd<r64> {
+    fn epsilon() -> Self { Self::default() }
+    fn scalar_sqrt(&self) -> Self { match self.rational() {
+        Some(r) if r.signum() >= 0 => Self::sqrt(r),
+        _ => Self::from_rational(r64::from(f64::from(*self).sqrt())),
+    }}
+}
+///Lossy unless the value is rational, as for rbig itself.
+impl Scalar for QuadraticSurd<rbig> {
+    fn epsilon() -> Self { Self::default() }
+    fn scalar_sqrt(&self) -> Self { match self.rational() {
+        Some(r) => Self::from_rational(r.scalar_sqrt()),
+        None => Self::from_rational(rbig::from(f64::from(self.clone()).sqrt())),
+    }}
+}
+impl Sqroot for QuadraticSurd<r64> {
+    type Output = QuadraticSurd<r64>;
+    fn sqroot(&self) -> Self { self.scalar_sqrt() }
+}
+impl Sqroot for QuadraticSurd<rbig> {
+    type Output = QuadraticSurd<rbig>;
+    fn sqroot(&self) -> Self { self.scalar_sqrt() }
+}
+impl<T: QuadraticCoef> Absolute for QuadraticSurd<T> {
+    type Output = QuadraticSurd<T>;
+    fn absolute(&self) -> Self { match self.signum() < 0 { true => -self, false => self.clone() } }
+}
+impl<T: QuadraticCoef> Conjugate for QuadraticSurd<T> {
+    fn conjugate(&self) -> Self { Self { a: self.a.clone(), b: -self.b.clone(), n: self.n } }
+}
+impl<T: QuadraticCoef> Default for QuadraticSurd<T> {
+    fn default() -> Self { Self::from_rational(T::default()) }
+}
+impl<T: QuadraticCoef> super::Identity for QuadraticSurd<T> {
+    fn identity() -> Self { Self::from_rational(T::identity()) }
+}
+impl<T: QuadraticCoef> PartialOrd for QuadraticSurd<T> {
+    ///Exact comparison - None if the values are irrational surds from different fields.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.compatible(other) {
+            true => Some((self - other).signum().cmp(&0)),
+            false => None,
+        }
+    }
+}
+impl<T: QuadraticCoef + Display> Display for QuadraticSurd<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "({}) + ({})*sqrt({})", self.a, self.b, self.n)
+    }
+}
+
+impl From<r64> for QuadraticSurd<r64> {
+    fn from(other: r64) -> Self { Self::from_rational(other) }
+}
+impl From<r32> for QuadraticSurd<r64> {
+    fn from(other: r32) -> Self { Self::from_rational(r64::from(other)) }
+}
+impl From<surd32> for QuadraticSurd<r64> {
+    fn from(other: surd32) -> Self { Self::new(r64::default(), r64::from(other.coef), other.radicand as u64) }
+}
+impl From<surd64> for QuadraticSurd<r64> {
+    fn from(other: surd64) -> Self { Self::new(r64::default(), other.coef, other.radicand) }
+}
+impl From<rbig> for QuadraticSurd<rbig> {
+    fn from(other: rbig) -> Self { Self::from_rational(other) }
+}
+impl From<surd32> for QuadraticSurd<rbig> {
+    fn from(other: surd32) -> Self { Self::new(rbig::default(), rbig::from(other.coef), other.radicand as u64) }
+}
+impl From<surd64> for QuadraticSurd<rbig> {
+    fn from(other: surd64) -> Self { Self::new(rbig::default(), rbig::from(other.coef), other.radicand) }
+}
+impl<T: QuadraticCoef> From<QuadraticSurd<T>> for f64 where f64: From<T> {
+    fn from(other: QuadraticSurd<T>) -> f64 { f64::from(other.a) + f64::from(other.b) * (other.n as f64).sqrt() }
+}
+
+impl<T: QuadraticCoef> Add<&QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> {
+    let n = self.field(other);
+    QuadraticSurd::new_raw(self.a.clone() + other.a.clone(), self.b.clone() + other.b.clone(), n)
+} }
+impl<T: QuadraticCoef> Sub<&QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> {
+    let n = self.field(other);
+    QuadraticSurd::new_raw(self.a.clone() - other.a.clone(), self.b.clone() - other.b.clone(), n)
+} }
+impl<T: QuadraticCoef> Mul<&QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> {
+    //(a + b*sqrt(n))(c + d*sqrt(n)) = (ac + n*bd) + (ad + bc)*sqrt(n)
+    let n = self.field(other);
+    let a = self.a.clone() * other.a.clone() + self.b.clone() * other.b.clone() * n;
+    let b = self.a.clone() * other.b.clone() + self.b.clone() * other.a.clone();
+    QuadraticSurd::new_raw(a, b, n)
+} }
+//Division is multiplication by the reciprocal, which rationalises the denominator.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T: QuadraticCoef> Div<&QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> {
+    self * &other.reciprocal().expect("Tried to divide a QuadraticSurd by zero!")
+} }
+impl<T: QuadraticCoef> Neg for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn neg(self) -> QuadraticSurd<T> { QuadraticSurd { a: -self.a.clone(), b: -self.b.clone(), n: self.n } } }
+impl<T: QuadraticCoef> Neg for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn neg(self) -> QuadraticSurd<T> { -&self } }
+impl<T: QuadraticCoef> Mul<&T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: &T) -> QuadraticSurd<T> {
+    QuadraticSurd::new_raw(self.a.clone() * other.clone(), self.b.clone() * other.clone(), self.n)
+} }
+impl<T: QuadraticCoef> Div<&T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: &T) -> QuadraticSurd<T> {
+    QuadraticSurd::new_raw(self.a.clone() / other.clone(), self.b.clone() / other.clone(), self.n)
+} }
+impl<T: QuadraticCoef> Add<&T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: &T) -> QuadraticSurd<T> {
+    QuadraticSurd::new_raw(self.a.clone() + other.clone(), self.b.clone(), self.n)
+} }
+impl<T: QuadraticCoef> Sub<&T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: &T) -> QuadraticSurd<T> {
+    QuadraticSurd::new_raw(self.a.clone() - other.clone(), self.b.clone(), self.n)
+} }
+impl<T: QuadraticCoef> Add<QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { &self + &other } }
+impl<T: QuadraticCoef> Add<&QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> { &self + other } }
+impl<T: QuadraticCoef> Add<QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { self + &other } }
+impl<T: QuadraticCoef> AddAssign<QuadraticSurd<T>> for QuadraticSurd<T> { fn add_assign(&mut self, other: QuadraticSurd<T>) { *self = &*self + &other; } }
+impl<T: QuadraticCoef> AddAssign<&QuadraticSurd<T>> for QuadraticSurd<T> { fn add_assign(&mut self, other: &QuadraticSurd<T>) { *self = &*self + other; } }
+impl<T: QuadraticCoef> Add<T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: T) -> QuadraticSurd<T> { &self + &other } }
+impl<T: QuadraticCoef> Add<&T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: &T) -> QuadraticSurd<T> { &self + other } }
+impl<T: QuadraticCoef> Add<T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn add(self, other: T) -> QuadraticSurd<T> { self + &other } }
+impl<T: QuadraticCoef> AddAssign<T> for QuadraticSurd<T> { fn add_assign(&mut self, other: T) { *self = &*self + &other; } }
+impl<T: QuadraticCoef> AddAssign<&T> for QuadraticSurd<T> { fn add_assign(&mut self, other: &T) { *self = &*self + other; } }
+impl<T: QuadraticCoef> Sub<QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { &self - &other } }
+impl<T: QuadraticCoef> Sub<&QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> { &self - other } }
+impl<T: QuadraticCoef> Sub<QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { self - &other } }
+impl<T: QuadraticCoef> SubAssign<QuadraticSurd<T>> for QuadraticSurd<T> { fn sub_assign(&mut self, other: QuadraticSurd<T>) { *self = &*self - &other; } }
+impl<T: QuadraticCoef> SubAssign<&QuadraticSurd<T>> for QuadraticSurd<T> { fn sub_assign(&mut self, other: &QuadraticSurd<T>) { *self = &*self - other; } }
+impl<T: QuadraticCoef> Sub<T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: T) -> QuadraticSurd<T> { &self - &other } }
+impl<T: QuadraticCoef> Sub<&T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: &T) -> QuadraticSurd<T> { &self - other } }
+impl<T: QuadraticCoef> Sub<T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn sub(self, other: T) -> QuadraticSurd<T> { self - &other } }
+impl<T: QuadraticCoef> SubAssign<T> for QuadraticSurd<T> { fn sub_assign(&mut self, other: T) { *self = &*self - &other; } }
+impl<T: QuadraticCoef> SubAssign<&T> for QuadraticSurd<T> { fn sub_assign(&mut self, other: &T) { *self = &*self - other; } }
+impl<T: QuadraticCoef> Mul<QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { &self * &other } }
+impl<T: QuadraticCoef> Mul<&QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> { &self * other } }
+impl<T: QuadraticCoef> Mul<QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { self * &other } }
+impl<T: QuadraticCoef> MulAssign<QuadraticSurd<T>> for QuadraticSurd<T> { fn mul_assign(&mut self, other: QuadraticSurd<T>) { *self = &*self * &other; } }
+impl<T: QuadraticCoef> MulAssign<&QuadraticSurd<T>> for QuadraticSurd<T> { fn mul_assign(&mut self, other: &QuadraticSurd<T>) { *self = &*self * other; } }
+impl<T: QuadraticCoef> Mul<T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: T) -> QuadraticSurd<T> { &self * &other } }
+impl<T: QuadraticCoef> Mul<&T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: &T) -> QuadraticSurd<T> { &self * other } }
+impl<T: QuadraticCoef> Mul<T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn mul(self, other: T) -> QuadraticSurd<T> { self * &other } }
+impl<T: QuadraticCoef> MulAssign<T> for QuadraticSurd<T> { fn mul_assign(&mut self, other: T) { *self = &*self * &other; } }
+impl<T: QuadraticCoef> MulAssign<&T> for QuadraticSurd<T> { fn mul_assign(&mut self, other: &T) { *self = &*self * other; } }
+impl<T: QuadraticCoef> Div<QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { &self / &other } }
+impl<T: QuadraticCoef> Div<&QuadraticSurd<T>> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: &QuadraticSurd<T>) -> QuadraticSurd<T> { &self / other } }
+impl<T: QuadraticCoef> Div<QuadraticSurd<T>> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: QuadraticSurd<T>) -> QuadraticSurd<T> { self / &other } }
+impl<T: QuadraticCoef> DivAssign<QuadraticSurd<T>> for QuadraticSurd<T> { fn div_assign(&mut self, other: QuadraticSurd<T>) { *self = &*self / &other; } }
+impl<T: QuadraticCoef> DivAssign<&QuadraticSurd<T>> for QuadraticSurd<T> { fn div_assign(&mut self, other: &QuadraticSurd<T>) { *self = &*self / other; } }
+impl<T: QuadraticCoef> Div<T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: T) -> QuadraticSurd<T> { &self / &other } }
+impl<T: QuadraticCoef> Div<&T> for QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: &T) -> QuadraticSurd<T> { &self / other } }
+impl<T: QuadraticCoef> Div<T> for &QuadraticSurd<T> { type Output = QuadraticSurd<T>; fn div(self, other: T) -> QuadraticSurd<T> { self / &other } }
+impl<T: QuadraticCoef> DivAssign<T> for QuadraticSurd<T> { fn div_assign(&mut self, other: T) { *self = &*self / &other; } }
+impl<T: QuadraticCoef> DivAssign<&T> for QuadraticSurd<T> { fn div_assign(&mut self, other: &T) { *self = &*self / other; } }
+impl Add<QuadraticSurd<r64>> for r64 { type Output = QuadraticSurd<r64>; fn add(self, other: QuadraticSurd<r64>) -> QuadraticSurd<r64> { QuadraticSurd::<r64>::from(self) + other } }
+impl Sub<QuadraticSurd<r64>> for r64 { type Output = QuadraticSurd<r64>; fn sub(self, other: QuadraticSurd<r64>) -> QuadraticSurd<r64> { QuadraticSurd::<r64>::from(self) - other } }
+impl Mul<QuadraticSurd<r64>> for r64 { type Output = QuadraticSurd<r64>; fn mul(self, other: QuadraticSurd<r64>) -> QuadraticSurd<r64> { QuadraticSurd::<r64>::from(self) * other } }
+impl Div<QuadraticSurd<r64>> for r64 { type Output = QuadraticSurd<r64>; fn div(self, other: QuadraticSurd<r64>) -> QuadraticSurd<r64> { QuadraticSurd::<r64>::from(self) / other } }
+impl Add<QuadraticSurd<rbig>> for rbig { type Output = QuadraticSurd<rbig>; fn add(self, other: QuadraticSurd<rbig>) -> QuadraticSurd<rbig> { QuadraticSurd::<rbig>::from(self) + other } }
+impl Sub<QuadraticSurd<rbig>> for rbig { type Output = QuadraticSurd<rbig>; fn sub(self, other: QuadraticSurd<rbig>) -> QuadraticSurd<rbig> { QuadraticSurd::<rbig>::from(self) - other } }
+impl Mul<QuadraticSurd<rbig>> for rbig { type Output = QuadraticSurd<rbig>; fn mul(self, other: QuadraticSurd<rbig>) -> QuadraticSurd<rbig> { QuadraticSurd::<rbig>::from(self) * other } }
+impl Div<QuadraticSurd<rbig>> for rbig { type Output = QuadraticSurd<rbig>; fn div(self, other: QuadraticSurd<rbig>) -> QuadraticSurd<rbig> { QuadraticSurd::<rbig>::from(self) / other } }
@@ -1,17 +1,138 @@
 use std::{cmp::Ordering};
 use std::num::ParseIntError;
 use std::ops::*;
-use super::surd::surd64;
+use std::str::FromStr;
+use super::surd::{surd64, surdbig};
+use super::bigint::{ibig, ubig, ParseBigError};
 use super::{Identity, Absolute, surd::surd32, Sqroot};
 
 #[derive(Clone, Debug)]
 pub enum Error {
     ParseInt(ParseIntError),
+    ParseBig(ParseBigError),
     DivideByZero,
+    ///The reduced numerator doesn't fit in the numerator type.
+    NumeratorOverflow,
+    ///The reduced denominator doesn't fit in the denominator type.
+    DenominatorOverflow,
+    ///The string to parse was empty (or only whitespace and a sign).
+    Empty,
+    ///A fraction or mixed number was malformed - e.g. "1/", "/2", "1/+2", "1/2/3" or "2 1.5/3".
+    InvalidFraction,
+    ///A decimal or integer was malformed - e.g. "12a", "1.2.3", "1.(", "1.()" or "1.2(3)4".
+    InvalidDecimal,
 }
 impl From<ParseIntError> for Error {
     fn from(other: ParseIntError) -> Self { Self::ParseInt(other) }
 }
+impl From<ParseBigError> for Error {
+    fn from(other: ParseBigError) -> Self { Self::ParseBig(other) }
+}
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ParseInt(e) => write!(f, "invalid integer: {}", e),
+            Self::ParseBig(e) => write!(f, "invalid big integer: {:?}", e),
+            Self::DivideByZero => write!(f, "denominator of rational type can never be zero"),
+            Self::NumeratorOverflow => write!(f, "numerator overflowed"),
+            Self::DenominatorOverflow => write!(f, "denominator overflowed"),
+            Self::Empty => write!(f, "cannot parse rational from empty string"),
+            Self::InvalidFraction => write!(f, "invalid fraction"),
+            Self::InvalidDecimal => write!(f, "invalid decimal"),
+        }
+    }
+}
+impl std::error::Error for Error {}
+
+///Parses a string of ASCII decimal digits - returns the invalid error if it's empty or has any other character (even a
+///sign), and the overflow error if it doesn't fit in a u64.
+fn parse_digits(s: &str, invalid: Error, overflow: Error) -> Result<i128, Error> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) { return Err(invalid); }
+    s.parse::<u64>().map(i128::from).map_err(|_| overflow)
+}
+
+///Parses "3/4", "-7", "1.25", "-0.1(6)" (repeating decimal), "2 1/3" (mixed number) or the Display format "3 / 4"
+///into a reduced, widened (numerator, denominator) pair - shared by the FromStr impls.
+fn parse_wide(s: &str) -> Result<(i128, i128), Error> {
+    let s = s.trim();
+    let (negative, body) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, s.strip_prefix('+').unwrap_or(s).trim_start()),
+    };
+    if body.is_empty() { return Err(Error::Empty); }
+    let (n, d) = match body.split_once('/') {
+        Some((top, bottom)) => {
+            let d = parse_digits(bottom.trim(), Error::InvalidFraction, Error::DenominatorOverflow)?;
+            if d == 0 { return Err(Error::DivideByZero); }
+            let n = match top.trim().split_once(char::is_whitespace) {
+                Some((whole, top)) => {
+                    let whole = parse_digits(whole, Error::InvalidFraction, Error::NumeratorOverflow)?;
+                    let top = parse_digits(top.trim(), Error::InvalidFraction, Error::NumeratorOverflow)?;
+                    whole.checked_mul(d).and_then(|w| w.checked_add(top)).ok_or(Error::NumeratorOverflow)?
+                },
+                None => parse_digits(top.trim(), Error::InvalidFraction, Error::NumeratorOverflow)?,
+            };
+            (n, d)
+        },
+        None => match body.split_once('.') {
+            None => (parse_digits(body, Error::InvalidDecimal, Error::NumeratorOverflow)?, 1),
+            Some((int, dec)) => {
+                let (fixed, repeating) = match dec.split_once('(') {
+                    Some((fixed, rest)) => match rest.strip_suffix(')') {
+                        Some(rep) if !rep.is_empty() => (fixed, rep),
+                        _ => return Err(Error::InvalidDecimal),
+                    },
+                    None => (dec, ""),
+                };
+                if int.is_empty() && fixed.is_empty() && repeating.is_empty() { return Err(Error::InvalidDecimal); }
+                if ![int, fixed, repeating].iter().all(|p| p.bytes().all(|b| b.is_ascii_digit())) { return Err(Error::InvalidDecimal); }
+                //x = int.fixed(repeating) => x = (int fixed repeating - int fixed) / ((10^r - 1) * 10^k)
+                let k = 10_i128.checked_pow(fixed.len() as u32).ok_or(Error::DenominatorOverflow)?;
+                let a = match int.is_empty() && fixed.is_empty() {
+                    true => 0,
+                    false => parse_digits(&(int.to_owned() + fixed), Error::InvalidDecimal, Error::NumeratorOverflow)?,
+                };
+                match repeating.is_empty() {
+                    true => (a, k),
+                    false => {
+                        let b = parse_digits(&(int.to_owned() + fixed + repeating), Error::InvalidDecimal, Error::NumeratorOverflow)?;
+                        let r = 10_i128.checked_pow(repeating.len() as u32).ok_or(Error::DenominatorOverflow)? - 1;
+                        (b - a, r.checked_mul(k).ok_or(Error::DenominatorOverflow)?)
+                    },
+                }
+            },
+        },
+    };
+    let g = super::factors::gcd128(n as u128, d as u128) as i128;
+    Ok((if negative { -n / g } else { n / g }, d / g))
+}
+
+///Closest fraction h/k to p/q with h <= max_n and k <= max_d, for saturating arithmetic - walks the continued fraction
+///expansion, and once the next convergent doesn't fit, takes the largest semiconvergent that does if it's closer than
+///the last convergent. q must not be zero, and p/q must be below max_n.
+fn best_fit(p: u128, q: u128, max_n: u128, max_d: u128) -> (u128, u128) {
+    let (mut x, mut y) = (p, q);
+    let (mut h0, mut h1, mut k0, mut k1) = (0_u128, 1_u128, 1_u128, 0_u128);
+    while y != 0 {
+        let a = x / y;
+        let room = |max: u128, prev: u128, cur: u128| (max - prev).checked_div(cur).unwrap_or(a);
+        let t = a.min(room(max_n, h0, h1)).min(room(max_d, k0, k1));
+        if t < a {
+            if t == 0 { break; }
+            //Compare |p/q - h/k| for the semiconvergent and the last convergent exactly, by cross-multiplying in ubig.
+            let dist = |h: u128, k: u128| {
+                let (a, b) = (&ubig::from(p) * &ubig::from(k), &ubig::from(h) * &ubig::from(q));
+                if a > b { &a - &b } else { &b - &a }
+            };
+            let (h, k) = (t * h1 + h0, t * k1 + k0);
+            if &dist(h, k) * &ubig::from(k1) < &dist(h1, k1) * &ubig::from(k) { return (h, k); }
+            break;
+        }
+        (h0, h1, k0, k1) = (h1, a * h1 + h0, k1, a * k1 + k0);
+        (x, y) = (y, x % y);
+    }
+    (h1, k1)
+}
 
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -58,10 +179,85 @@ impl r32 {
         Ok(Self::new(n, 10_i16.pow(dec.len() as u32))?)
     }
     pub fn reciprocal(&self) -> Result<Self, Error> {
-        if self.n == 0 { return Err(Error::DivideByZero) }
-        else {
-            let sign = self.n.signum();
-            Ok(Self::new_unchecked(self.d as i16 * sign, self.n.abs()))
+        Self::from_wide(self.d as i64, self.n as i64)
+    }
+    ///Builds an r32 from a widened numerator and denominator - reduces via gcd, then checks the result fits.
+    ///The numerator range is kept symmetric (excluding i16::MIN) so negation can't overflow.
+    fn from_wide(n: i64, d: i64) -> Result<Self, Error> {
+        if d == 0 { return Err(Error::DivideByZero); }
+        let g = super::factors::gcd64(n.unsigned_abs(), d.unsigned_abs()) as i64;
+        let (n, d) = (n / g * d.signum(), d.abs() / g);
+        if n.abs() > i16::MAX as i64 { return Err(Error::NumeratorOverflow); }
+        if d > u16::MAX as i64 { return Err(Error::DenominatorOverflow); }
+        Ok(Self::new_raw(n as i16, d as u16))
+    }
+    ///Returns the closest r32 to a widened fraction - clamps to +/-i16::MAX if it's too large, otherwise takes the best
+    ///approximation that fits from the continued fraction expansion, semiconvergents included. Denominator must not be zero.
+    fn saturate_wide(n: i64, d: i64) -> (Self, bool) {
+        if let Ok(r) = Self::from_wide(n, d) { return (r, false); }
+        let sign = n.signum() * d.signum();
+        let (p, q) = (n.unsigned_abs(), d.unsigned_abs());
+        if p / q >= i16::MAX as u64 { return (Self::new_raw(i16::MAX * sign as i16, 1), true); }
+        let (h, k) = best_fit(p as u128, q as u128, i16::MAX as u128, u16::MAX as u128);
+        (Self::new_raw(h as i16 * sign as i16, k as u16), true)
+    }
+    fn wide_add(self, other: r32) -> (i64, i64) {
+        (self.n as i64 * other.d as i64 + other.n as i64 * self.d as i64, self.d as i64 * other.d as i64)
+    }
+    fn wide_sub(self, other: r32) -> (i64, i64) {
+        (self.n as i64 * other.d as i64 - other.n as i64 * self.d as i64, self.d as i64 * other.d as i64)
+    }
+    fn wide_mul(self, other: r32) -> (i64, i64) {
+        (self.n as i64 * other.n as i64, self.d as i64 * other.d as i64)
+    }
+    fn wide_div(self, other: r32) -> (i64, i64) {
+        (self.n as i64 * other.d as i64, self.d as i64 * other.n as i64)
+    }
+    ///Adds two r32s, widening internally - returns an Error if the reduced result can't be represented.
+    pub fn checked_add(self, other: r32) -> Result<Self, Error> { let (n, d) = self.wide_add(other); Self::from_wide(n, d) }
+    ///Subtracts two r32s, widening internally - returns an Error if the reduced result can't be represented.
+    pub fn checked_sub(self, other: r32) -> Result<Self, Error> { let (n, d) = self.wide_sub(other); Self::from_wide(n, d) }
+    ///Multiplies two r32s, widening internally - returns an Error if the reduced result can't be represented.
+    pub fn checked_mul(self, other: r32) -> Result<Self, Error> { let (n, d) = self.wide_mul(other); Self::from_wide(n, d) }
+    ///Divides two r32s, widening internally - returns an Error if dividing by zero or if the reduced result can't be represented.
+    pub fn checked_div(self, other: r32) -> Result<Self, Error> { let (n, d) = self.wide_div(other); Self::from_wide(n, d) }
+    ///Adds two r32s - on overflow returns the closest representable value instead.
+    pub fn saturating_add(self, other: r32) -> Self { self.overflowing_add(other).0 }
+    ///Subtracts two r32s - on overflow returns the closest representable value instead.
+    pub fn saturating_sub(self, other: r32) -> Self { self.overflowing_sub(other).0 }
+    ///Multiplies two r32s - on overflow returns the closest representable value instead.
+    pub fn saturating_mul(self, other: r32) -> Self { self.overflowing_mul(other).0 }
+    ///Divides two r32s - on overflow returns the closest representable value instead. Returns an Error if dividing by zero.
+    pub fn saturating_div(self, other: r32) -> Result<Self, Error> { Ok(self.overflowing_div(other)?.0) }
+    ///Adds two r32s - returns the saturated result and whether it overflowed. Wrapping has no meaningful rational
+    ///analogue, so unlike the integer versions this doesn't wrap.
+    pub fn overflowing_add(self, other: r32) -> (Self, bool) { let (n, d) = self.wide_add(other); Self::saturate_wide(n, d) }
+    ///Subtracts two r32s - returns the saturated result and whether it overflowed.
+    pub fn overflowing_sub(self, other: r32) -> (Self, bool) { let (n, d) = self.wide_sub(other); Self::saturate_wide(n, d) }
+    ///Multiplies two r32s - returns the saturated result and whether it overflowed.
+    pub fn overflowing_mul(self, other: r32) -> (Self, bool) { let (n, d) = self.wide_mul(other); Self::saturate_wide(n, d) }
+    ///Divides two r32s - returns the saturated result and whether it overflowed. Returns an Error if dividing by zero.
+    pub fn overflowing_div(self, other: r32) -> Result<(Self, bool), Error> {
+        if other.n == 0 { return Err(Error::DivideByZero); }
+        let (n, d) = self.wide_div(other);
+        Ok(Self::saturate_wide(n, d))
+    }
+    ///Returns the closest r32 to x with a denominator no larger than max_denominator, via its continued fraction.
+    ///The bound is tightened if needed so the numerator fits. Returns an Error if x is too large or not finite.
+    pub fn best_approximation(x: f64, max_denominator: u16) -> Result<Self, Error> {
+        if !x.is_finite() || x.abs() > i16::MAX as f64 { return Err(Error::NumeratorOverflow); }
+        if max_denominator == 0 { return Err(Error::DivideByZero); }
+        let cf = super::continued_fraction::ContinuedFraction::from(x);
+        let mut max = match x.abs() > 1.0 {
+            true => (max_denominator as f64).min((i16::MAX as f64 / x.abs()).floor()).max(1.0) as u128,
+            false => max_denominator as u128,
+        };
+        loop {
+            let (n, d) = cf.best_approximation(max);
+            match Self::from_wide(n as i64, d as i64) {
+                Err(Error::NumeratorOverflow) if max > 1 => max -= 1,
+                r => return r,
+            }
         }
     }
     pub fn surd_sqrt(&self) -> surd32 {
@@ -69,22 +265,34 @@ impl r32 {
     }
 }
 
+///Parses "3/4", "-7", "1.25", "-0.1(6)" (repeating decimal), "2 1/3" (mixed number), and the Display format "3 / 4".
+impl FromStr for r32 {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (n, d) = parse_wide(s)?;
+        let n = i64::try_from(n).map_err(|_| Error::NumeratorOverflow)?;
+        let d = i64::try_from(d).map_err(|_| Error::DenominatorOverflow)?;
+        Self::from_wide(n, d)
+    }
+}
+
 impl std::fmt::Display for r32 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{} / {}", self.n, self.d)
     }
 }
 
+///Closest r32 to the f32 - see best_approximation. Panics if out of bounds or not finite.
 impl From<f32> for r32 {
     fn from(other: f32) -> Self {
-        let (mut n, mut d) = super::factors::rat_approx_32(other, i16::MAX as f32);
-        while n.abs() > i16::MAX as f32 || d > u16::MAX as f32 {
-            n /= 2.0; n = n.round(); d /= 2.0; d = d.round();
-        }
-        Self::new_raw(n as i16, d as u16)
+        Self::best_approximation(other as f64, u16::MAX).expect("Tried to convert an out of bounds or non-finite f32 to r32.")
     }
 }
 
+impl From<r32> for r64 {
+    fn from(other: r32) -> Self { Self::new_raw(other.n as i32, other.d as u32) }
+}
+
 impl From<r32> for f32 {
     fn from(other: r32) -> Self { other.n as f32 / other.d as f32 }
 }
@@ -108,37 +316,26 @@ impl Sqroot for r32 {
 }
 
 impl PartialOrd for r32 {
-    fn partial_cmp(&self, other: &r32) -> Option<Ordering> {
-        match (self - other).signum() {
-            1 => Some(Ordering::Greater),
-            0 => Some(Ordering::Equal),
-            -1 => Some(Ordering::Less),
-            _ => None,
-        }
-    }
+    fn partial_cmp(&self, other: &r32) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 impl Ord for r32 {
     fn cmp(&self, other: &r32) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        (self.n as i64 * other.d as i64).cmp(&(other.n as i64 * self.d as i64))
     }
 }
 
-impl Add<r32> for r32 { type Output = r32; fn add(self, other: r32) -> Self {
-    let d = super::factors::lcm16(self.d, other.d);
-    //eprintln!("denom: {}", d);
-    Self::new_unchecked((self.n * (d/self.d) as i16) + (other.n * (d/other.d) as i16), d as i16)    
-} }
-impl Sub<r32> for r32 { type Output = r32; fn sub(self, other: r32) -> Self { self + -other } }
-impl Mul<r32> for r32 { type Output = r32; fn mul(self, other: r32) -> Self { Self::new_unchecked(self.n * other.n, (self.d * other.d) as i16) } }
-impl Mul<i32> for r32 { type Output = r32; fn mul(self, other: i32) -> Self { Self::new_unchecked(self.n * other as i16, self.d as i16) } }
-impl Mul<r32> for i32 { type Output = r32; fn mul(self, other: r32) -> r32 { r32::new_unchecked(other.n * self as i16, other.d as i16) } }
-impl Mul<u32> for r32 { type Output = r32; fn mul(self, other: u32) -> Self { Self::new_unchecked(self.n * other as i16, self.d as i16) } }
-impl Mul<r32> for u32 { type Output = r32; fn mul(self, other: r32) -> r32 { r32::new_unchecked(other.n * self as i16, other.d as i16) } }
-impl Div<r32> for r32 { type Output = r32; fn div(self, other: r32) -> Self { self * other.reciprocal().unwrap() } }
-impl Div<i32> for r32 { type Output = r32; fn div(self, other: i32) -> Self { self * Self::new_unchecked(1, other as i16) } }
-impl Div<r32> for i32 { type Output = r32; fn div(self, other: r32) -> r32 { other * r32::new_unchecked(1, self as i16) } }
-impl Div<u32> for r32 { type Output = r32; fn div(self, other: u32) -> Self { self * Self::new_unchecked(1, other as i16) } }
-impl Div<r32> for u32 { type Output = r32; fn div(self, other: r32) -> r32 { other * r32::new_unchecked(1, self as i16) } }
+impl Add<r32> for r32 { type Output = r32; fn add(self, other: r32) -> Self { self.checked_add(other).unwrap() } }
+impl Sub<r32> for r32 { type Output = r32; fn sub(self, other: r32) -> Self { self.checked_sub(other).unwrap() } }
+impl Mul<r32> for r32 { type Output = r32; fn mul(self, other: r32) -> Self { self.checked_mul(other).unwrap() } }
+impl Mul<i32> for r32 { type Output = r32; fn mul(self, other: i32) -> Self { Self::from_wide(self.n as i64 * other as i64, self.d as i64).unwrap() } }
+impl Mul<r32> for i32 { type Output = r32; fn mul(self, other: r32) -> r32 { other * self } }
+impl Mul<u32> for r32 { type Output = r32; fn mul(self, other: u32) -> Self { Self::from_wide(self.n as i64 * other as i64, self.d as i64).unwrap() } }
+impl Mul<r32> for u32 { type Output = r32; fn mul(self, other: r32) -> r32 { other * self } }
+impl Div<r32> for r32 { type Output = r32; fn div(self, other: r32) -> Self { self.checked_div(other).unwrap() } }
+impl Div<i32> for r32 { type Output = r32; fn div(self, other: i32) -> Self { Self::from_wide(self.n as i64, self.d as i64 * other as i64).unwrap() } }
+impl Div<r32> for i32 { type Output = r32; fn div(self, other: r32) -> r32 { r32::from_wide(self as i64 * other.d as i64, other.n as i64).unwrap() } }
+impl Div<u32> for r32 { type Output = r32; fn div(self, other: u32) -> Self { Self::from_wide(self.n as i64, self.d as i64 * other as i64).unwrap() } }
+impl Div<r32> for u32 { type Output = r32; fn div(self, other: r32) -> r32 { r32::from_wide(self as i64 * other.d as i64, other.n as i64).unwrap() } }
 impl Neg for r32 { type Output = r32; fn neg(self) -> Self { Self::new_raw(-self.n, self.d) } }
 impl Add<&r32> for r32 { type Output = r32; fn add(self, other: &r32) -> r32 { self + *other } }
 impl Add<r32> for &r32 { type Output = r32; fn add(self, other: r32) -> r32 { *self + other } }
@@ -226,10 +423,85 @@ impl r64 {
         Ok(Self::new(n, 10_i32.pow(dec.len() as u32))?)
     }
     pub fn reciprocal(&self) -> Result<Self, Error> {
-        if self.n == 0 { return Err(Error::DivideByZero) }
-        else {
-            let sign = self.n.signum();
-            Ok(Self::new_unchecked(self.d as i32 * sign, self.n.abs()))
+        Self::from_wide(self.d as i128, self.n as i128)
+    }
+    ///Builds an r64 from a widened numerator and denominator - reduces via gcd, then checks the result fits.
+    ///The numerator range is kept symmetric (excluding i32::MIN) so negation can't overflow.
+    fn from_wide(n: i128, d: i128) -> Result<Self, Error> {
+        if d == 0 { return Err(Error::DivideByZero); }
+        let g = super::factors::gcd128(n.unsigned_abs(), d.unsigned_abs()) as i128;
+        let (n, d) = (n / g * d.signum(), d.abs() / g);
+        if n.abs() > i32::MAX as i128 { return Err(Error::NumeratorOverflow); }
+        if d > u32::MAX as i128 { return Err(Error::DenominatorOverflow); }
+        Ok(Self::new_raw(n as i32, d as u32))
+    }
+    ///Returns the closest r64 to a widened fraction - clamps to +/-i32::MAX if it's too large, otherwise takes the best
+    ///approximation that fits from the continued fraction expansion, semiconvergents included. Denominator must not be zero.
+    fn saturate_wide(n: i128, d: i128) -> (Self, bool) {
+        if let Ok(r) = Self::from_wide(n, d) { return (r, false); }
+        let sign = n.signum() * d.signum();
+        let (p, q) = (n.unsigned_abs(), d.unsigned_abs());
+        if p / q >= i32::MAX as u128 { return (Self::new_raw(i32::MAX * sign as i32, 1), true); }
+        let (h, k) = best_fit(p, q, i32::MAX as u128, u32::MAX as u128);
+        (Self::new_raw(h as i32 * sign as i32, k as u32), true)
+    }
+    fn wide_add(self, other: r64) -> (i128, i128) {
+        (self.n as i128 * other.d as i128 + other.n as i128 * self.d as i128, self.d as i128 * other.d as i128)
+    }
+    fn wide_sub(self, other: r64) -> (i128, i128) {
+        (self.n as i128 * other.d as i128 - other.n as i128 * self.d as i128, self.d as i128 * other.d as i128)
+    }
+    fn wide_mul(self, other: r64) -> (i128, i128) {
+        (self.n as i128 * other.n as i128, self.d as i128 * other.d as i128)
+    }
+    fn wide_div(self, other: r64) -> (i128, i128) {
+        (self.n as i128 * other.d as i128, self.d as i128 * other.n as i128)
+    }
+    ///Adds two r64s, widening internally - returns an Error if the reduced result can't be represented.
+    pub fn checked_add(self, other: r64) -> Result<Self, Error> { let (n, d) = self.wide_add(other); Self::from_wide(n, d) }
+    ///Subtracts two r64s, widening internally - returns an Error if the reduced result can't be represented.
+    pub fn checked_sub(self, other: r64) -> Result<Self, Error> { let (n, d) = self.wide_sub(other); Self::from_wide(n, d) }
+    ///Multiplies two r64s, widening internally - returns an Error if the reduced result can't be represented.
+    pub fn checked_mul(self, other: r64) -> Result<Self, Error> { let (n, d) = self.wide_mul(other); Self::from_wide(n, d) }
+    ///Divides two r64s, widening internally - returns an Error if dividing by zero or if the reduced result can't be represented.
+    pub fn checked_div(self, other: r64) -> Result<Self, Error> { let (n, d) = self.wide_div(other); Self::from_wide(n, d) }
+    ///Adds two r64s - on overflow returns the closest representable value instead.
+    pub fn saturating_add(self, other: r64) -> Self { self.overflowing_add(other).0 }
+    ///Subtracts two r64s - on overflow returns the closest representable value instead.
+    pub fn saturating_sub(self, other: r64) -> Self { self.overflowing_sub(other).0 }
+    ///Multiplies two r64s - on overflow returns the closest representable value instead.
+    pub fn saturating_mul(self, other: r64) -> Self { self.overflowing_mul(other).0 }
+    ///Divides two r64s - on overflow returns the closest representable value instead. Returns an Error if dividing by zero.
+    pub fn saturating_div(self, other: r64) -> Result<Self, Error> { Ok(self.overflowing_div(other)?.0) }
+    ///Adds two r64s - returns the saturated result and whether it overflowed. Wrapping has no meaningful rational
+    ///analogue, so unlike the integer versions this doesn't wrap.
+    pub fn overflowing_add(self, other: r64) -> (Self, bool) { let (n, d) = self.wide_add(other); Self::saturate_wide(n, d) }
+    ///Subtracts two r64s - returns the saturated result and whether it overflowed.
+    pub fn overflowing_sub(self, other: r64) -> (Self, bool) { let (n, d) = self.wide_sub(other); Self::saturate_wide(n, d) }
+    ///Multiplies two r64s - returns the saturated result and whether it overflowed.
+    pub fn overflowing_mul(self, other: r64) -> (Self, bool) { let (n, d) = self.wide_mul(other); Self::saturate_wide(n, d) }
+    ///Divides two r64s - returns the saturated result and whether it overflowed. Returns an Error if dividing by zero.
+    pub fn overflowing_div(self, other: r64) -> Result<(Self, bool), Error> {
+        if other.n == 0 { return Err(Error::DivideByZero); }
+        let (n, d) = self.wide_div(other);
+        Ok(Self::saturate_wide(n, d))
+    }
+    ///Returns the closest r64 to x with a denominator no larger than max_denominator, via its continued fraction.
+    ///The bound is tightened if needed so the numerator fits. Returns an Error if x is too large or not finite.
+    pub fn best_approximation(x: f64, max_denominator: u32) -> Result<Self, Error> {
+        if !x.is_finite() || x.abs() > i32::MAX as f64 { return Err(Error::NumeratorOverflow); }
+        if max_denominator == 0 { return Err(Error::DivideByZero); }
+        let cf = super::continued_fraction::ContinuedFraction::from(x);
+        let mut max = match x.abs() > 1.0 {
+            true => (max_denominator as f64).min((i32::MAX as f64 / x.abs()).floor()).max(1.0) as u128,
+            false => max_denominator as u128,
+        };
+        loop {
+            let (n, d) = cf.best_approximation(max);
+            match Self::from_wide(n, d) {
+                Err(Error::NumeratorOverflow) if max > 1 => max -= 1,
+                r => return r,
+            }
         }
     }
     pub fn surd_sqrt(&self) -> surd64 {
@@ -237,19 +509,25 @@ impl r64 {
     }
 }
 
+///Parses "3/4", "-7", "1.25", "-0.1(6)" (repeating decimal), "2 1/3" (mixed number), and the Display format "3 / 4".
+impl FromStr for r64 {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (n, d) = parse_wide(s)?;
+        Self::from_wide(n, d)
+    }
+}
+
 impl std::fmt::Display for r64 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{} / {}", self.n, self.d)
     }
 }
 
+///Closest r64 to the f64 - see best_approximation. Panics if out of bounds or not finite.
 impl From<f64> for r64 {
     fn from(other: f64) -> Self {
-        let (mut n, mut d) = super::factors::rat_approx_64(other, i32::MAX as f64);
-        while n.abs() > i32::MAX as f64 || d > u32::MAX as f64 {
-            n /= 2.0; n = n.round(); d /= 2.0; d = d.round();
-        }
-        Self::new_raw(n as i32, d as u32)
+        Self::best_approximation(other, u32::MAX).expect("Tried to convert an out of bounds or non-finite f64 to r64.")
     }
 }
 
@@ -276,37 +554,26 @@ impl Sqroot for r64 {
 }
 
 impl PartialOrd for r64 {
-    fn partial_cmp(&self, other: &r64) -> Option<Ordering> {
-        match (self - other).signum() {
-            1 => Some(Ordering::Greater),
-            0 => Some(Ordering::Equal),
-            -1 => Some(Ordering::Less),
-            _ => None,
-        }
-    }
+    fn partial_cmp(&self, other: &r64) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 impl Ord for r64 {
     fn cmp(&self, other: &r64) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        (self.n as i128 * other.d as i128).cmp(&(other.n as i128 * self.d as i128))
     }
 }
 
-impl Add<r64> for r64 { type Output = r64; fn add(self, other: r64) -> Self {
-    let d = super::factors::lcm32(self.d, other.d);
-    //eprintln!("denom: {}", d);
-    Self::new_unchecked((self.n * (d/self.d) as i32) + (other.n * (d/other.d) as i32), d as i32)    
-} }
-impl Sub<r64> for r64 { type Output = r64; fn sub(self, other: r64) -> Self { self + -other } }
-impl Mul<r64> for r64 { type Output = r64; fn mul(self, other: r64) -> Self { Self::new_unchecked(self.n * other.n, (self.d * other.d) as i32) } }
-impl Mul<i64> for r64 { type Output = r64; fn mul(self, other: i64) -> Self { Self::new_unchecked(self.n * other as i32, self.d as i32) } }
-impl Mul<r64> for i64 { type Output = r64; fn mul(self, other: r64) -> r64 { r64::new_unchecked(other.n * self as i32, other.d as i32) } }
-impl Mul<u64> for r64 { type Output = r64; fn mul(self, other: u64) -> Self { Self::new_unchecked(self.n * other as i32, self.d as i32) } }
-impl Mul<r64> for u64 { type Output = r64; fn mul(self, other: r64) -> r64 { r64::new_unchecked(other.n * self as i32, other.d as i32) } }
-impl Div<r64> for r64 { type Output = r64; fn div(self, other: r64) -> Self { self * other.reciprocal().unwrap() } }
-impl Div<i64> for r64 { type Output = r64; fn div(self, other: i64) -> Self { self * Self::new_unchecked(1, other as i32) } }
-impl Div<r64> for i64 { type Output = r64; fn div(self, other: r64) -> r64 { other * r64::new_unchecked(1, self as i32) } }
-impl Div<u64> for r64 { type Output = r64; fn div(self, other: u64) -> Self { self * Self::new_unchecked(1, other as i32) } }
-impl Div<r64> for u64 { type Output = r64; fn div(self, other: r64) -> r64 { other * r64::new_unchecked(1, self as i32) } }
+impl Add<r64> for r64 { type Output = r64; fn add(self, other: r64) -> Self { self.checked_add(other).unwrap() } }
+impl Sub<r64> for r64 { type Output = r64; fn sub(self, other: r64) -> Self { self.checked_sub(other).unwrap() } }
+impl Mul<r64> for r64 { type Output = r64; fn mul(self, other: r64) -> Self { self.checked_mul(other).unwrap() } }
+impl Mul<i64> for r64 { type Output = r64; fn mul(self, other: i64) -> Self { Self::from_wide(self.n as i128 * other as i128, self.d as i128).unwrap() } }
+impl Mul<r64> for i64 { type Output = r64; fn mul(self, other: r64) -> r64 { other * self } }
+impl Mul<u64> for r64 { type Output = r64; fn mul(self, other: u64) -> Self { Self::from_wide(self.n as i128 * other as i128, self.d as i128).unwrap() } }
+impl Mul<r64> for u64 { type Output = r64; fn mul(self, other: r64) -> r64 { other * self } }
+impl Div<r64> for r64 { type Output = r64; fn div(self, other: r64) -> Self { self.checked_div(other).unwrap() } }
+impl Div<i64> for r64 { type Output = r64; fn div(self, other: i64) -> Self { Self::from_wide(self.n as i128, self.d as i128 * other as i128).unwrap() } }
+impl Div<r64> for i64 { type Output = r64; fn div(self, other: r64) -> r64 { r64::from_wide(self as i128 * other.d as i128, other.n as i128).unwrap() } }
+impl Div<u64> for r64 { type Output = r64; fn div(self, other: u64) -> Self { Self::from_wide(self.n as i128, self.d as i128 * other as i128).unwrap() } }
+impl Div<r64> for u64 { type Output = r64; fn div(self, other: r64) -> r64 { r64::from_wide(self as i128 * other.d as i128, other.n as i128).unwrap() } }
 impl Neg for r64 { type Output = r64; fn neg(self) -> Self { Self::new_raw(-self.n, self.d) } }
 impl Add<&r64> for r64 { type Output = r64; fn add(self, other: &r64) -> r64 { self + *other } }
 impl Add<r64> for &r64 { type Output = r64; fn add(self, other: r64) -> r64 { *self + other } }
@@ -347,4 +614,205 @@ impl Div<&u64> for r64 { type Output = r64; fn div(self, other: &u64) -> r64 { s
 impl Div<u64> for &r64 { type Output = r64; fn div(self, other: u64) -> r64 { *self / other } }
 impl Div<&u64> for &r64 { type Output = r64; fn div(self, other: &u64) -> r64 { *self / *other } }
 impl DivAssign<u64> for r64 { fn div_assign(&mut self, other: u64) { *self = *self / other; } }
-impl DivAssign<&u64> for r64 { fn div_assign(&mut self, other: &u64) { *self = *self / other; } }
\ No newline at end of file
+impl DivAssign<&u64> for r64 { fn div_assign(&mut self, other: &u64) { *self = *self / other; } }
+///Arbitrary precision rational type - numerator is an ibig and denominator a ubig, so it grows as needed
+///and never overflows. Always kept in lowest terms. Not Copy, so clone where needed.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct rbig {
+    n: ibig,
+    d: ubig
+}
+impl rbig {
+    ///Returns the numerator (top half of the fraction) of the rbig.
+    pub fn numerator(&self) -> &ibig { &self.n }
+    ///Returns the denominator (bottom half of the fraction) of the rbig.
+    pub fn denominator(&self) -> &ubig { &self.d }
+    ///Returns the signum of the numerator
+    pub fn signum(&self) -> i32 { self.n.signum() }
+    ///Checks if positive
+    pub fn is_positive(&self) -> bool { self.n.is_positive() }
+    ///Returns a new rbig from the raw ibig and ubig
+    pub fn new_raw(n: ibig, d: ubig) -> Self { Self { n, d } }
+    ///Returns a new rbig based on numerator and denominator - will simplify down if possible, will panic if dividing by zero.
+    pub fn new_unchecked(numerator: ibig, denominator: ibig) -> Self {
+        if denominator.is_zero() { panic!("Denominator of rational type can never be zero! Tried to build {}/{}.", numerator, denominator); }
+        let negative = numerator.is_negative() != denominator.is_negative();
+        Self::reduce(ibig::from_parts(negative, numerator.into_magnitude()), denominator.into_magnitude())
+    }
+    ///Returns a new rbig based on numerator and denominator, wrapped in a result in case of division by zero - will simplify down if possible.
+    pub fn new(numerator: ibig, denominator: ibig) -> Result<Self, Error> {
+        if denominator.is_zero() { return Err(Error::DivideByZero); }
+        Ok(Self::new_unchecked(numerator, denominator))
+    }
+    ///Tries to return a new rbig based on sign and two strings, the integer and fractional parts.
+    pub fn int_dec(sign: bool, int: &str, dec: &str) -> Result<Self, Error> {
+        let total = int.to_owned() + dec;
+        let n = ibig::from_parts(!sign, total.parse::<ubig>()?);
+        Ok(Self::reduce(n, ubig::from(10u32).pow(dec.len() as u32)))
+    }
+    pub fn reciprocal(&self) -> Result<Self, Error> {
+        match self.n.is_zero() {
+            true => Err(Error::DivideByZero),
+            false => Ok(Self::new_raw(ibig::from_parts(self.n.is_negative(), self.d.clone()), self.n.magnitude().clone())),
+        }
+    }
+    pub fn surd_sqrt(&self) -> surdbig {
+        let coef = Self::new_raw(ibig::from(self.n.signum()), self.d.clone()).reduced();
+        surdbig::new(coef, self.n.magnitude() * &self.d)
+    }
+    ///Returns the floor of the square root of the absolute value of a ubig, as an rbig with `bits` binary digits after the point.
+    pub fn sqrt_approx(radicand: &ubig, bits: usize) -> Self {
+        Self::reduce(ibig::from((radicand << (2 * bits)).isqrt()), &ubig::identity() << bits)
+    }
+    fn reduced(self) -> Self { Self::reduce(self.n, self.d) }
+    fn reduce(n: ibig, d: ubig) -> Self {
+        if n.is_zero() { return Self::default(); }
+        let g = n.magnitude().gcd(&d);
+        match g.is_one() {
+            true => Self::new_raw(n, d),
+            false => Self::new_raw(ibig::from_parts(n.is_negative(), n.magnitude() / &g), &d / &g),
+        }
+    }
+}
+
+impl std::fmt::Display for rbig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} / {}", self.n, self.d)
+    }
+}
+
+///Exact conversion - every finite f64 is a dyadic rational. Panics on NaN or infinity.
+impl From<f64> for rbig {
+    fn from(other: f64) -> Self {
+        if !other.is_finite() { panic!("Tried to convert a non-finite f64 to rbig."); }
+        let bits = other.to_bits();
+        let negative = bits >> 63 == 1;
+        let exp = ((bits >> 52) & 0x7FF) as i64;
+        let frac = bits & 0xF_FFFF_FFFF_FFFF;
+        let (mantissa, exp) = match exp { 0 => (frac, -1074), _ => (frac | 1 << 52, exp - 1075) };
+        let m = ubig::from(mantissa);
+        match exp >= 0 {
+            true => Self::new_raw(ibig::from_parts(negative, &m << exp as usize), ubig::identity()),
+            false => Self::reduce(ibig::from_parts(negative, m), &ubig::identity() << (-exp) as usize),
+        }
+    }
+}
+
+impl From<f32> for rbig {
+    fn from(other: f32) -> Self { Self::from(other as f64) }
+}
+
+impl From<rbig> for f64 {
+    fn from(other: rbig) -> Self {
+        let shift = other.n.magnitude().bits().max(other.d.bits()).saturating_sub(1000);
+        let n = (other.n.magnitude() >> shift).to_f64();
+        let d = (&other.d >> shift).to_f64();
+        (other.n.signum() as f64) * n / d
+    }
+}
+
+impl From<r32> for rbig {
+    fn from(other: r32) -> Self { Self::new_raw(ibig::from(other.n), ubig::from(other.d)) }
+}
+
+impl From<r64> for rbig {
+    fn from(other: r64) -> Self { Self::new_raw(ibig::from(other.n), ubig::from(other.d)) }
+}
+
+impl From<ibig> for rbig {
+    fn from(other: ibig) -> Self { Self::new_raw(other, ubig::identity()) }
+}
+
+impl From<i64> for rbig {
+    fn from(other: i64) -> Self { Self::from(ibig::from(other)) }
+}
+
+impl From<i32> for rbig {
+    fn from(other: i32) -> Self { Self::from(ibig::from(other)) }
+}
+
+impl Identity for rbig {
+    fn identity() -> Self { Self::new_raw(ibig::identity(), ubig::identity()) }
+}
+
+impl Default for rbig {
+    fn default() -> Self { Self::new_raw(ibig::zero(), ubig::identity()) }
+}
+
+impl Absolute for rbig {
+    type Output = rbig;
+    fn absolute(&self) -> Self { Self::new_raw(self.n.absolute(), self.d.clone()) }
+}
+
+impl Sqroot for rbig {
+    type Output = surdbig;
+    fn sqroot(&self) -> surdbig { self.surd_sqrt() }
+}
+
+impl PartialOrd for rbig {
+    fn partial_cmp(&self, other: &rbig) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for rbig {
+    fn cmp(&self, other: &rbig) -> Ordering {
+        (&self.n * &ibig::from(other.d.clone())).cmp(&(&other.n * &ibig::from(self.d.clone())))
+    }
+}
+
+impl Add<&rbig> for &rbig { type Output = rbig; fn add(self, other: &rbig) -> rbig {
+    let n = &self.n * &ibig::from(other.d.clone()) + &other.n * &ibig::from(self.d.clone());
+    rbig::reduce(n, &self.d * &other.d)
+} }
+impl Sub<&rbig> for &rbig { type Output = rbig; fn sub(self, other: &rbig) -> rbig { self + &-other } }
+impl Mul<&rbig> for &rbig { type Output = rbig; fn mul(self, other: &rbig) -> rbig { rbig::reduce(&self.n * &other.n, &self.d * &other.d) } }
+impl Div<&rbig> for &rbig { type Output = rbig; fn div(self, other: &rbig) -> rbig { self * &other.reciprocal().unwrap() } }
+impl Mul<i64> for &rbig { type Output = rbig; fn mul(self, other: i64) -> rbig { self * &rbig::from(other) } }
+impl Div<i64> for &rbig { type Output = rbig; fn div(self, other: i64) -> rbig { self / &rbig::from(other) } }
+impl Mul<u64> for &rbig { type Output = rbig; fn mul(self, other: u64) -> rbig { self * &rbig::from(ibig::from(other)) } }
+impl Div<u64> for &rbig { type Output = rbig; fn div(self, other: u64) -> rbig { self / &rbig::from(ibig::from(other)) } }
+impl Neg for &rbig { type Output = rbig; fn neg(self) -> rbig { rbig::new_raw(-&self.n, self.d.clone()) } }
+impl Neg for rbig { type Output = rbig; fn neg(self) -> rbig { rbig::new_raw(-self.n, self.d) } }
+impl Mul<rbig> for i64 { type Output = rbig; fn mul(self, other: rbig) -> rbig { &other * self } }
+impl Mul<rbig> for u64 { type Output = rbig; fn mul(self, other: rbig) -> rbig { &other * self } }
+impl Div<rbig> for i64 { type Output = rbig; fn div(self, other: rbig) -> rbig { &rbig::from(self) / &other } }
+impl Div<rbig> for u64 { type Output = rbig; fn div(self, other: rbig) -> rbig { &rbig::from(ibig::from(self)) / &other } }
+impl Add<rbig> for rbig { type Output = rbig; fn add(self, other: rbig) -> rbig { &self + &other } }
+impl Add<&rbig> for rbig { type Output = rbig; fn add(self, other: &rbig) -> rbig { &self + other } }
+impl Add<rbig> for &rbig { type Output = rbig; fn add(self, other: rbig) -> rbig { self + &other } }
+impl AddAssign<rbig> for rbig { fn add_assign(&mut self, other: rbig) { *self = &*self + &other; } }
+impl AddAssign<&rbig> for rbig { fn add_assign(&mut self, other: &rbig) { *self = &*self + other; } }
+impl Sub<rbig> for rbig { type Output = rbig; fn sub(self, other: rbig) -> rbig { &self - &other } }
+impl Sub<&rbig> for rbig { type Output = rbig; fn sub(self, other: &rbig) -> rbig { &self - other } }
+impl Sub<rbig> for &rbig { type Output = rbig; fn sub(self, other: rbig) -> rbig { self - &other } }
+impl SubAssign<rbig> for rbig { fn sub_assign(&mut self, other: rbig) { *self = &*self - &other; } }
+impl SubAssign<&rbig> for rbig { fn sub_assign(&mut self, other: &rbig) { *self = &*self - other; } }
+impl Mul<rbig> for rbig { type Output = rbig; fn mul(self, other: rbig) -> rbig { &self * &other } }
+impl Mul<&rbig> for rbig { type Output = rbig; fn mul(self, other: &rbig) -> rbig { &self * other } }
+impl Mul<rbig> for &rbig { type Output = rbig; fn mul(self, other: rbig) -> rbig { self * &other } }
+impl MulAssign<rbig> for rbig { fn mul_assign(&mut self, other: rbig) { *self = &*self * &other; } }
+impl MulAssign<&rbig> for rbig { fn mul_assign(&mut self, other: &rbig) { *self = &*self * other; } }
+impl Mul<i64> for rbig { type Output = rbig; fn mul(self, other: i64) -> rbig { &self * other } }
+impl Mul<&i64> for rbig { type Output = rbig; fn mul(self, other: &i64) -> rbig { &self * *other } }
+impl Mul<&i64> for &rbig { type Output = rbig; fn mul(self, other: &i64) -> rbig { self * *other } }
+impl MulAssign<i64> for rbig { fn mul_assign(&mut self, other: i64) { *self = &*self * other; } }
+impl MulAssign<&i64> for rbig { fn mul_assign(&mut self, other: &i64) { *self = &*self * *other; } }
+impl Mul<u64> for rbig { type Output = rbig; fn mul(self, other: u64) -> rbig { &self * other } }
+impl Mul<&u64> for rbig { type Output = rbig; fn mul(self, other: &u64) -> rbig { &self * *other } }
+impl Mul<&u64> for &rbig { type Output = rbig; fn mul(self, other: &u64) -> rbig { self * *other } }
+impl MulAssign<u64> for rbig { fn mul_assign(&mut self, other: u64) { *self = &*self * other; } }
+impl MulAssign<&u64> for rbig { fn mul_assign(&mut self, other: &u64) { *self = &*self * *other; } }
+impl Div<rbig> for rbig { type Output = rbig; fn div(self, other: rbig) -> rbig { &self / &other } }
+impl Div<&rbig> for rbig { type Output = rbig; fn div(self, other: &rbig) -> rbig { &self / other } }
+impl Div<rbig> for &rbig { type Output = rbig; fn div(self, other: rbig) -> rbig { self / &other } }
+impl DivAssign<rbig> for rbig { fn div_assign(&mut self, other: rbig) { *self = &*self / &other; } }
+impl DivAssign<&rbig> for rbig { fn div_assign(&mut self, other: &rbig) { *self = &*self / other; } }
+impl Div<i64> for rbig { type Output = rbig; fn div(self, other: i64) -> rbig { &self / other } }
+impl Div<&i64> for rbig { type Output = rbig; fn div(self, other: &i64) -> rbig { &self / *other } }
+impl Div<&i64> for &rbig { type Output = rbig; fn div(self, other: &i64) -> rbig { self / *other } }
+impl DivAssign<i64> for rbig { fn div_assign(&mut self, other: i64) { *self = &*self / other; } }
+impl DivAssign<&i64> for rbig { fn div_assign(&mut self, other: &i64) { *self = &*self / *other; } }
+impl Div<u64> for rbig { type Output = rbig; fn div(self, other: u64) -> rbig { &self / other } }
+impl Div<&u64> for rbig { type Output = rbig; fn div(self, other: &u64) -> rbig { &self / *other } }
+impl Div<&u64> for &rbig { type Output = rbig; fn div(self, other: &u64) -> rbig { self / *other } }
+impl DivAssign<u64> for rbig { fn div_assign(&mut self, other: u64) { *self = &*self / other; } }
+impl DivAssign<&u64> for rbig { fn div_assign(&mut self, other: &u64) { *self = &*self / *other; } }
@@ -0,0 +1,55 @@
+use super::Integer;
+
+///Returns the floor of the square root of an integer, computed exactly. Panics if n is negative.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    if n.is_negative() { panic!("Tried to take the integer square root of a negative number."); }
+    T::from_u128(n.to_u128().isqrt())
+}
+///Returns the cube root of an integer rounded towards zero, computed exactly - so icbrt(-n) = -icbrt(n).
+pub fn icbrt<T: Integer>(n: T) -> T { iroot(n, 3) }
+///Returns the kth root of an integer rounded towards zero, computed exactly. Panics if k is zero, or if n is negative
+///and k is even.
+pub fn iroot<T: Integer>(n: T, k: u32) -> T {
+    if k == 0 { panic!("Tried to take the zeroth root of a number."); }
+    if n.is_negative() && k.is_multiple_of(2) { panic!("Tried to take an even root of a negative number."); }
+    let r = T::from_u128(iroot_u128(n.unsigned_abs().to_u128(), k));
+    match n.is_negative() {
+        true => T::ZERO - r,
+        false => r,
+    }
+}
+///Checks if an integer is the square of an integer - always false for negatives.
+pub fn is_perfect_square<T: Integer>(n: T) -> bool {
+    if n.is_negative() { return false; }
+    //Squares are 0, 1, 4 or 9 mod 16, which rules out three quarters of values without a root.
+    let n = n.to_u128();
+    if (1 << (n & 15)) & 0x0213 == 0 { return false; }
+    let r = n.isqrt();
+    r * r == n
+}
+///Returns (b, k) with b^k = n for the largest k >= 2 - None if n isn't a perfect power. Negative n can only be odd
+///powers of a negative base, and zero and one are powers of themselves for every k, so they're None.
+pub fn perfect_power<T: Integer>(n: T) -> Option<(T, u32)> {
+    let m = n.unsigned_abs().to_u128();
+    if m < 2 { return None; }
+    //The exponent is at most log2(m), and trying them largest first means the first match is the answer.
+    (2..=127 - m.leading_zeros()).rev()
+        .filter(|k| !n.is_negative() || k % 2 == 1)
+        .find(|k| iroot_u128(m, *k).checked_pow(*k) == Some(m))
+        .map(|k| (iroot(n, k), k))
+}
+
+///Exact floor of the kth root of n for k >= 1 - starts from the f64 estimate, then corrects it with exact powers.
+fn iroot_u128(n: u128, k: u32) -> u128 {
+    match k {
+        1 => return n,
+        2 => return n.isqrt(),
+        _ if n < 2 => return n,
+        _ => {}
+    }
+    //For k >= 3 the root is below 2^43, so the estimate is within a step or two.
+    let mut r = (n as f64).powf(1.0 / k as f64) as u128;
+    while r.checked_pow(k).is_none_or(|p| p > n) { r -= 1; }
+    while (r + 1).checked_pow(k).is_some_and(|p| p <= n) { r += 1; }
+    r
+}
@@ -1,10 +1,31 @@
-use super::{rational::*};
-use std::{ops::{Mul, MulAssign, Div, DivAssign, Neg}, fmt::{Display, Formatter}};
+use super::{rational::*, bigint::{ibig, ubig}, Identity};
+use std::{ops::{Mul, MulAssign, Div, DivAssign, Neg}, fmt::{Display, Formatter}, cmp::Ordering, hash::{Hash, Hasher}};
+
+///Compares two surds exactly, each given as (numerator, denominator, radicand) - i.e. n/d * sqrt(r). Compares the signs,
+///then the squared values n^2 * r / d^2 by cross-multiplying in ubig so nothing can overflow.
+fn cmp_exact(a: (i64, u64, u64), b: (i64, u64, u64)) -> Ordering {
+    let (sa, sb) = (a.0.signum(), b.0.signum());
+    if sa != sb || sa == 0 { return sa.cmp(&sb); }
+    let square = |(n, _, r): (i64, u64, u64), (_, d, _): (i64, u64, u64)| {
+        let (n, d) = (ubig::from(n.unsigned_abs()), ubig::from(d));
+        &(&n * &n) * &(&ubig::from(r) * &(&d * &d))
+    };
+    let ord = square(a, b).cmp(&square(b, a));
+    match sa > 0 { true => ord, false => ord.reverse() }
+}
+///Hash key agreeing with cmp_exact - the sign and the squared value n^2 * r / d^2, reduced as an rbig so every way of
+///storing the same surd gives the same key. Unlike simplifying with new, this never overflows or panics.
+fn hash_key((n, d, r): (i64, u64, u64)) -> (i64, Option<rbig>) {
+    let (m, d) = (ubig::from(n.unsigned_abs()), ubig::from(d));
+    let square = ibig::from_parts(false, &(&m * &m) * &ubig::from(r));
+    (n.signum(), rbig::new(square, ibig::from_parts(false, &d * &d)).ok())
+}
 
 ///Surd Type using 32 bit components - composed of a rational coefficient and a u32 radicand - i.e.
-///for coefficient A and radicand B, this is A * sqrt(B); Cannot be Added or Subtracted, but can be multiplied and divided.
+///for coefficient A and radicand B, this is A * sqrt(B); Cannot be Added or Subtracted, but can be multiplied and divided -
+///convert to a quadratic::QuadraticSurd to add and subtract.
 #[allow(non_camel_case_types)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug)]
 pub struct surd32 {
     ///Rational coefficient.
     pub coef: r32,
@@ -15,6 +36,8 @@ impl surd32 {
     ///Returns a new, simplified surd based on coefficient and radicand.
     pub fn new(coef: r32, radicand: u32) -> Self {
         if radicand == 0 { panic!("Radicand for a Surd should never be 0."); }
+        if coef == r32::default() { return Self { coef, radicand: 1 }; }
+        if super::roots::is_perfect_square(radicand) { return Self { coef: coef * super::roots::isqrt(radicand), radicand: 1 }; }
         let factors = super::factors::sqfac32(radicand);
         let sq_sum = factors.iter().map(|(s, _sq)| s).product::<u32>();
         Self { coef: coef * sq_sum, radicand: radicand / (sq_sum * sq_sum) }
@@ -50,6 +73,49 @@ impl From<surd32> for r32 {
         other.coef * r32::from((other.radicand as f32).sqrt())
     }
 }
+impl surd32 {
+    fn parts(&self) -> (i64, u64, u64) { (self.coef.numerator() as i64, self.coef.denominator() as u64, self.radicand as u64) }
+}
+///Exact ordering - compares signs, then squared values.
+impl Ord for surd32 {
+    fn cmp(&self, other: &surd32) -> Ordering { cmp_exact(self.parts(), other.parts()) }
+}
+impl PartialOrd for surd32 {
+    fn partial_cmp(&self, other: &surd32) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+///Equal when the values are - the fields are public, so the same value may be stored unsimplified.
+impl PartialEq for surd32 {
+    fn eq(&self, other: &surd32) -> bool { self.cmp(other) == Ordering::Equal }
+}
+impl Eq for surd32 {}
+///Hashes the sign and exact squared value, so equal values hash equally.
+impl Hash for surd32 {
+    fn hash<H: Hasher>(&self, state: &mut H) { hash_key(self.parts()).hash(state); }
+}
+impl PartialEq<r32> for surd32 {
+    fn eq(&self, other: &r32) -> bool { self.partial_cmp(other) == Some(Ordering::Equal) }
+}
+impl PartialOrd<r32> for surd32 {
+    fn partial_cmp(&self, other: &r32) -> Option<Ordering> { Some(cmp_exact(self.parts(), (other.numerator() as i64, other.denominator() as u64, 1_u64))) }
+}
+impl PartialEq<surd32> for r32 {
+    fn eq(&self, other: &surd32) -> bool { other == self }
+}
+impl PartialOrd<surd32> for r32 {
+    fn partial_cmp(&self, other: &surd32) -> Option<Ordering> { other.partial_cmp(self).map(Ordering::reverse) }
+}
+impl PartialEq<r64> for surd32 {
+    fn eq(&self, other: &r64) -> bool { self.partial_cmp(other) == Some(Ordering::Equal) }
+}
+impl PartialOrd<r64> for surd32 {
+    fn partial_cmp(&self, other: &r64) -> Option<Ordering> { Some(cmp_exact(self.parts(), (other.numerator() as i64, other.denominator() as u64, 1_u64))) }
+}
+impl PartialEq<surd32> for r64 {
+    fn eq(&self, other: &surd32) -> bool { other == self }
+}
+impl PartialOrd<surd32> for r64 {
+    fn partial_cmp(&self, other: &surd32) -> Option<Ordering> { other.partial_cmp(self).map(Ordering::reverse) }
+}
 impl Display for surd32 {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "({})*sqrt({})", self.coef, self.radicand)
@@ -120,9 +186,10 @@ impl DivAssign<&u32> for surd32 { fn div_assign(&mut self, other: &u32) { *self
 
 
 ///Surd Type using 64 bit components - composed of a rational coefficient and a u32 radicand - i.e.
-///for coefficient A and radicand B, this is A * sqrt(B); Cannot be Added or Subtracted, but can be multiplied and divided.
+///for coefficient A and radicand B, this is A * sqrt(B); Cannot be Added or Subtracted, but can be multiplied and divided -
+///convert to a quadratic::QuadraticSurd to add and subtract.
 #[allow(non_camel_case_types)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug)]
 pub struct surd64 {
     ///Rational coefficient.
     pub coef: r64,
@@ -133,6 +200,8 @@ impl surd64 {
     ///Returns a new, simplified surd based on coefficient and radicand.
     pub fn new(coef: r64, radicand: u64) -> Self {
         if radicand == 0 { panic!("Radicand for a Surd should never be 0."); }
+        if coef == r64::default() { return Self { coef, radicand: 1 }; }
+        if super::roots::is_perfect_square(radicand) { return Self { coef: coef * super::roots::isqrt(radicand), radicand: 1 }; }
         let factors = super::factors::sqfac64(radicand);
         let sq_sum = factors.iter().map(|(s, _sq)| s).product::<u64>();
         Self { coef: coef * sq_sum, radicand: radicand / (sq_sum * sq_sum) }
@@ -168,6 +237,49 @@ impl From<surd64> for r64 {
         other.coef * r64::from((other.radicand as f64).sqrt())
     }
 }
+impl surd64 {
+    fn parts(&self) -> (i64, u64, u64) { (self.coef.numerator() as i64, self.coef.denominator() as u64, self.radicand) }
+}
+///Exact ordering - compares signs, then squared values.
+impl Ord for surd64 {
+    fn cmp(&self, other: &surd64) -> Ordering { cmp_exact(self.parts(), other.parts()) }
+}
+impl PartialOrd for surd64 {
+    fn partial_cmp(&self, other: &surd64) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+///Equal when the values are - the fields are public, so the same value may be stored unsimplified.
+impl PartialEq for surd64 {
+    fn eq(&self, other: &surd64) -> bool { self.cmp(other) == Ordering::Equal }
+}
+impl Eq for surd64 {}
+///Hashes the sign and exact squared value, so equal values hash equally.
+impl Hash for surd64 {
+    fn hash<H: Hasher>(&self, state: &mut H) { hash_key(self.parts()).hash(state); }
+}
+impl PartialEq<r32> for surd64 {
+    fn eq(&self, other: &r32) -> bool { self.partial_cmp(other) == Some(Ordering::Equal) }
+}
+impl PartialOrd<r32> for surd64 {
+    fn partial_cmp(&self, other: &r32) -> Option<Ordering> { Some(cmp_exact(self.parts(), (other.numerator() as i64, other.denominator() as u64, 1_u64))) }
+}
+impl PartialEq<surd64> for r32 {
+    fn eq(&self, other: &surd64) -> bool { other == self }
+}
+impl PartialOrd<surd64> for r32 {
+    fn partial_cmp(&self, other: &surd64) -> Option<Ordering> { other.partial_cmp(self).map(Ordering::reverse) }
+}
+impl PartialEq<r64> for surd64 {
+    fn eq(&self, other: &r64) -> bool { self.partial_cmp(other) == Some(Ordering::Equal) }
+}
+impl PartialOrd<r64> for surd64 {
+    fn partial_cmp(&self, other: &r64) -> Option<Ordering> { Some(cmp_exact(self.parts(), (other.numerator() as i64, other.denominator() as u64, 1_u64))) }
+}
+impl PartialEq<surd64> for r64 {
+    fn eq(&self, other: &surd64) -> bool { other == self }
+}
+impl PartialOrd<surd64> for r64 {
+    fn partial_cmp(&self, other: &surd64) -> Option<Ordering> { other.partial_cmp(self).map(Ordering::reverse) }
+}
 impl Display for surd64 {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "({})*sqrt({})", self.coef, self.radicand)
@@ -235,3 +347,174 @@ impl Div<u64> for &surd64 { type Output = surd64; fn div(self, other: u64) -> su
 impl Div<&u64> for &surd64 { type Output = surd64; fn div(self, other: &u64) -> surd64 { *self / *other } }
 impl DivAssign<u64> for surd64 { fn div_assign(&mut self, other: u64) { *self = *self / other; } }
 impl DivAssign<&u64> for surd64 { fn div_assign(&mut self, other: &u64) { *self = *self / other; } }
+
+///Largest base tried when pulling square factors out of a surdbig radicand by trial division.
+const SQFAC_BIG_BOUND: u32 = 1000;
+
+///Surd Type using arbitrary precision components - composed of an rbig coefficient and a ubig radicand - i.e.
+///for coefficient A and radicand B, this is A * sqrt(B); Cannot be Added or Subtracted, but can be multiplied and divided -
+///convert to a quadratic::QuadraticSurd to add and subtract.
+///Radicands can't be fully factorised cheaply, so simplification only removes square factors below SQFAC_BIG_BOUND
+///and perfect square remainders - the radicand of a rational result is still always 1.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug)]
+pub struct surdbig {
+    ///Rational coefficient.
+    pub coef: rbig,
+    ///Radicand.
+    pub radicand: ubig,
+}
+impl surdbig {
+    ///Returns a new, simplified surd based on coefficient and radicand.
+    pub fn new(coef: rbig, radicand: ubig) -> Self {
+        if radicand.is_zero() { panic!("Radicand for a Surd should never be 0."); }
+        if coef == rbig::default() { return Self { coef, radicand: ubig::identity() }; }
+        let mut radicand = radicand;
+        let mut sq_sum = ubig::identity();
+        for s in 2..SQFAC_BIG_BOUND {
+            let sq = s * s;
+            if radicand < ubig::from(sq) { break; }
+            loop {
+                let (q, r) = radicand.div_rem_small(sq);
+                if r != 0 { break; }
+                radicand = q;
+                sq_sum *= ubig::from(s);
+            }
+        }
+        let root = radicand.isqrt();
+        if &root * &root == radicand {
+            sq_sum *= root;
+            radicand = ubig::identity();
+        }
+        Self { coef: coef * rbig::from(super::bigint::ibig::from(sq_sum)), radicand }
+    }
+    ///Returns the coefficient of the surd.
+    pub fn coef(&self) -> &rbig { &self.coef }
+    ///Returns the radicand of the surd.
+    pub fn radicand(&self) -> &ubig { &self.radicand }
+    ///Tries to convert the surd to a rational number - only possible if the radicand is 1.
+    pub fn rational(&self) -> Option<rbig> { match self.radicand.is_one() {
+        true => Some(self.coef.clone()), false => None
+    }}
+    ///Returns the squared surd as a rational number - surds squared are always rational.
+    pub fn squared(&self) -> rbig { (self * self).rational().expect("A squared surd should always return a rational number.") }
+}
+impl From<f64> for surdbig {
+    fn from(other: f64) -> Self {
+        Self::new(rbig::from(other), ubig::identity())
+    }
+}
+impl From<surdbig> for f64 {
+    fn from(other: surdbig) -> f64 {
+        f64::from(other.coef) * other.radicand.to_f64().sqrt()
+    }
+}
+impl From<rbig> for surdbig {
+    fn from(other: rbig) -> Self {
+        Self::new(other, ubig::identity())
+    }
+}
+///Approximates the irrational part to 64 binary places - exact when the radicand is 1.
+impl From<surdbig> for rbig {
+    fn from(other: surdbig) -> Self {
+        match other.radicand.is_one() {
+            true => other.coef,
+            false => other.coef * rbig::sqrt_approx(&other.radicand, 64),
+        }
+    }
+}
+impl surdbig {
+    ///The squared value coef^2 * radicand - the same for every way of writing the surd.
+    fn square(&self) -> rbig { &(&self.coef * &self.coef) * &rbig::from(ibig::from(self.radicand.clone())) }
+}
+///Exact ordering - compares signs, then squared values.
+impl Ord for surdbig {
+    fn cmp(&self, other: &surdbig) -> Ordering {
+        let (sa, sb) = (self.coef.signum(), other.coef.signum());
+        if sa != sb || sa == 0 { return sa.cmp(&sb); }
+        let ord = self.square().cmp(&other.square());
+        match sa > 0 { true => ord, false => ord.reverse() }
+    }
+}
+impl PartialOrd for surdbig {
+    fn partial_cmp(&self, other: &surdbig) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+///Equal when the values are - simplification can miss large square factors, so the same value may be stored differently.
+impl PartialEq for surdbig {
+    fn eq(&self, other: &surdbig) -> bool { self.cmp(other) == Ordering::Equal }
+}
+impl Eq for surdbig {}
+///Hashes the sign and squared value, so equal values hash equally however they are stored.
+impl Hash for surdbig {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coef.signum().hash(state);
+        self.square().hash(state);
+    }
+}
+impl PartialEq<rbig> for surdbig {
+    fn eq(&self, other: &rbig) -> bool { self.partial_cmp(other) == Some(Ordering::Equal) }
+}
+impl PartialOrd<rbig> for surdbig {
+    fn partial_cmp(&self, other: &rbig) -> Option<Ordering> { Some(self.cmp(&surdbig::from(other.clone()))) }
+}
+impl PartialEq<surdbig> for rbig {
+    fn eq(&self, other: &surdbig) -> bool { other == self }
+}
+impl PartialOrd<surdbig> for rbig {
+    fn partial_cmp(&self, other: &surdbig) -> Option<Ordering> { other.partial_cmp(self).map(Ordering::reverse) }
+}
+impl Display for surdbig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "({})*sqrt({})", self.coef, self.radicand)
+    }
+}
+impl Mul<&surdbig> for &surdbig {
+    type Output = surdbig;
+    fn mul(self, other: &surdbig) -> surdbig {
+        surdbig::new(&self.coef * &other.coef, &self.radicand * &other.radicand)
+    }
+}
+impl Div<&surdbig> for &surdbig {
+    type Output = surdbig;
+    //a*sqrt(b) / c*sqrt(d) = a/(c*d) * sqrt(b*d)
+    fn div(self, other: &surdbig) -> surdbig {
+        let d = rbig::from(super::bigint::ibig::from(other.radicand.clone()));
+        surdbig::new(&self.coef / &(&other.coef * &d), &self.radicand * &other.radicand)
+    }
+}
+impl Mul<&rbig> for &surdbig {
+    type Output = surdbig;
+    fn mul(self, other: &rbig) -> surdbig { surdbig { coef: &self.coef * other, radicand: self.radicand.clone() } }
+}
+impl Div<&rbig> for &surdbig {
+    type Output = surdbig;
+    fn div(self, other: &rbig) -> surdbig { surdbig { coef: &self.coef / other, radicand: self.radicand.clone() } }
+}
+impl Neg for &surdbig {
+    type Output = surdbig;
+    fn neg(self) -> surdbig { surdbig { coef: -&self.coef, radicand: self.radicand.clone() } }
+}
+impl Neg for surdbig {
+    type Output = surdbig;
+    fn neg(self) -> surdbig { surdbig { coef: -self.coef, radicand: self.radicand } }
+}
+impl Mul<surdbig> for surdbig { type Output = surdbig; fn mul(self, other: surdbig) -> surdbig { &self * &other } }
+impl Mul<&surdbig> for surdbig { type Output = surdbig; fn mul(self, other: &surdbig) -> surdbig { &self * other } }
+impl Mul<surdbig> for &surdbig { type Output = surdbig; fn mul(self, other: surdbig) -> surdbig { self * &other } }
+impl MulAssign<surdbig> for surdbig { fn mul_assign(&mut self, other: surdbig) { *self = &*self * &other; } }
+impl MulAssign<&surdbig> for surdbig { fn mul_assign(&mut self, other: &surdbig) { *self = &*self * other; } }
+impl Mul<rbig> for surdbig { type Output = surdbig; fn mul(self, other: rbig) -> surdbig { &self * &other } }
+impl Mul<&rbig> for surdbig { type Output = surdbig; fn mul(self, other: &rbig) -> surdbig { &self * other } }
+impl Mul<rbig> for &surdbig { type Output = surdbig; fn mul(self, other: rbig) -> surdbig { self * &other } }
+impl MulAssign<rbig> for surdbig { fn mul_assign(&mut self, other: rbig) { *self = &*self * &other; } }
+impl MulAssign<&rbig> for surdbig { fn mul_assign(&mut self, other: &rbig) { *self = &*self * other; } }
+impl Div<surdbig> for surdbig { type Output = surdbig; fn div(self, other: surdbig) -> surdbig { &self / &other } }
+impl Div<&surdbig> for surdbig { type Output = surdbig; fn div(self, other: &surdbig) -> surdbig { &self / other } }
+impl Div<surdbig> for &surdbig { type Output = surdbig; fn div(self, other: surdbig) -> surdbig { self / &other } }
+impl DivAssign<surdbig> for surdbig { fn div_assign(&mut self, other: surdbig) { *self = &*self / &other; } }
+impl DivAssign<&surdbig> for surdbig { fn div_assign(&mut self, other: &surdbig) { *self = &*self / other; } }
+impl Div<rbig> for surdbig { type Output = surdbig; fn div(self, other: rbig) -> surdbig { &self / &other } }
+impl Div<&rbig> for surdbig { type Output = surdbig; fn div(self, other: &rbig) -> surdbig { &self / other } }
+impl Div<rbig> for &surdbig { type Output = surdbig; fn div(self, other: rbig) -> surdbig { self / &other } }
+impl DivAssign<rbig> for surdbig { fn div_assign(&mut self, other: rbig) { *self = &*self / &other; } }
+impl DivAssign<&rbig> for surdbig { fn div_assign(&mut self, other: &rbig) { *self = &*self / other; } }
@@ -0,0 +1,60 @@
+use super::rational::r64;
+use super::quadratic::QuadraticSurd;
+use super::nested::NestedSurd;
+use super::factors::gcd64;
+
+///Returns the exact cosine of x*pi. Supported for multiples of pi/6, pi/4, pi/5 and pi/10 - cosines of multiples of
+///pi/10 need the nested surd sqrt(10 - 2*sqrt(5)), the rest lie in a quadratic field. Anything else (pi/12, pi/8...) is
+///None.
+pub fn cos_pi(x: r64) -> Option<NestedSurd<r64>> { cos_ratio(x.numerator() as i64, x.denominator() as i64) }
+
+///Returns the exact sine of x*pi, using sin(x*pi) = cos((1/2 - x)*pi). Supported for the same angles as cos_pi.
+pub fn sin_pi(x: r64) -> Option<NestedSurd<r64>> {
+    let (n, d) = (x.numerator() as i64, x.denominator() as i64);
+    cos_ratio(d - 2 * n, 2 * d)
+}
+
+///Returns the exact (sine, cosine) pair of x*pi - None unless both are supported.
+pub fn sin_cos_pi(x: r64) -> Option<(NestedSurd<r64>, NestedSurd<r64>)> {
+    match (sin_pi(x)?, cos_pi(x)?) {
+        (s, c) if s.compatible(&c) => Some((s, c)),
+        _ => None,
+    }
+}
+
+///Returns the exact tangent of x*pi - None if the sine or cosine is unsupported, or the cosine is zero.
+pub fn tan_pi(x: r64) -> Option<NestedSurd<r64>> {
+    let (s, c) = sin_cos_pi(x)?;
+    c.reciprocal().map(|r| s * r)
+}
+
+///Cosine of (n/d)*pi for d > 0.
+fn cos_ratio(n: i64, d: i64) -> Option<NestedSurd<r64>> {
+    //Cosine is even with period 2, so reduce to t/d in [0, 1].
+    let mut t = n.rem_euclid(2 * d);
+    if t > d { t = 2 * d - t; }
+    //cos((1 - t)*pi) = -cos(t*pi), so reduce to [0, 1/2].
+    let flip = 2 * t > d;
+    if flip { t = d - t; }
+    let g = gcd64(t as u64, d as u64) as i64;
+    let (zero, half, quarter) = (r64::default(), r64::new_raw(1, 2), r64::new_raw(1, 4));
+    let q = |a: r64, b: r64, n: u64| NestedSurd::from_quadratic(QuadraticSurd::new(a, b, n));
+    //cos(pi/10) and cos(3*pi/10) are multiples of sqrt(10 - 2*sqrt(5)) over Q(sqrt(5)).
+    let nested = |b: QuadraticSurd<r64>| NestedSurd::new(QuadraticSurd::default(), b, QuadraticSurd::new(r64::new_raw(10, 1), r64::new_raw(-2, 1), 5));
+    let v = match (t / g, d / g) {
+        (0, 1) => q(r64::new_raw(1, 1), zero, 1),
+        (1, 10) => nested(QuadraticSurd::new(r64::new_raw(1, 8), r64::new_raw(1, 8), 5)),
+        (1, 6) => q(zero, half, 3),
+        (1, 5) => q(quarter, quarter, 5),
+        (1, 4) => q(zero, half, 2),
+        (3, 10) => nested(QuadraticSurd::from_rational(quarter)),
+        (1, 3) => q(half, zero, 1),
+        (2, 5) => q(-quarter, quarter, 5),
+        (1, 2) => q(zero, zero, 1),
+        _ => return None,
+    };
+    match flip {
+        true => Some(-v),
+        false => Some(v),
+    }
+}
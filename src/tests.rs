@@ -1,7 +1,7 @@
 use std::f32::consts::PI;
 
-use crate::{geom::{vector::*, matrix::*, quaternion::{Quaternion, DualQuaternion}}, RotationOrder};
-use crate::num::Magnitude;
+use crate::{Quaternion, DualQuaternion, Vector, Matrix, VectorPrecise, RotationOrder};
+use crate::num::{Magnitude, Identity};
 
 fn equal_ish(a: f32, b: f32, d: f32) -> bool {
     (a - b).abs() < d
@@ -47,6 +47,194 @@ fn sqrt_test() {
     assert!(r32::new_unchecked(25, 7).surd_sqrt().squared() == r32::new_unchecked(25, 7));
 }
 #[test]
+fn rational_overflow_test() {
+    use crate::num::rational::*;
+    //Intermediate products overflow i16 but the reduced results fit.
+    let a = r32::new_unchecked(300, 301);
+    let b = r32::new_unchecked(301, 300);
+    assert!(a * b == r32::identity());
+    assert!(r32::new_unchecked(1, 300) + r32::new_unchecked(1, 600) == r32::new_unchecked(1, 200));
+    assert!(r32::new_unchecked(i16::MAX, 2) > r32::new_unchecked(-i16::MAX, 3));
+    assert!(matches!(r32::new_unchecked(i16::MAX, 1).checked_add(r32::identity()), Err(Error::NumeratorOverflow)));
+    assert!(matches!(r32::new_unchecked(1, 30000).checked_mul(r32::new_unchecked(1, 3)), Err(Error::DenominatorOverflow)));
+    assert!(matches!(r32::identity().checked_div(r32::default()), Err(Error::DivideByZero)));
+    assert!(r32::new_unchecked(i16::MAX, 1).saturating_add(r32::identity()) == r32::new_unchecked(i16::MAX, 1));
+    assert!(r32::new_unchecked(-i16::MAX, 1).saturating_mul(r32::new_unchecked(2, 1)) == r32::new_unchecked(-i16::MAX, 1));
+    let (r, overflowed) = r32::new_unchecked(1, 30000).overflowing_mul(r32::new_unchecked(1, 3));
+    //1/90000 is closer to 1/65535, a semiconvergent, than to the convergent 0.
+    assert!(overflowed);
+    assert!(r == r32::new_raw(1, u16::MAX));
+    assert!(r64::new_unchecked(1, i32::MAX).overflowing_mul(r64::new_unchecked(1, 3)) == (r64::new_raw(1, u32::MAX), true));
+    let (r, overflowed) = r32::new_unchecked(355, 113).overflowing_mul(r32::new_unchecked(1000, 999));
+    assert!(overflowed);
+    assert!((f64::from(r64::new_unchecked(r.numerator() as i32, r.denominator() as i32)) - 355000.0/112887.0).abs() < 1e-6);
+    assert!(r64::new_unchecked(i32::MAX, 7).checked_mul(r64::new_unchecked(7, i32::MAX)).unwrap() == r64::identity());
+    assert!(6_i32 / r32::new_unchecked(2, 3) == r32::new_unchecked(9, 1));
+}
+#[test]
+fn rational_parse_test() {
+    use crate::num::rational::*;
+    assert!("3/4".parse::<r32>().unwrap() == r32::new_unchecked(3, 4));
+    assert!("-7".parse::<r32>().unwrap() == r32::new_unchecked(-7, 1));
+    assert!("1.25".parse::<r32>().unwrap() == r32::new_unchecked(5, 4));
+    assert!("-0.1(6)".parse::<r32>().unwrap() == r32::new_unchecked(-1, 6));
+    assert!("0.(142857)".parse::<r64>().unwrap() == r64::new_unchecked(1, 7));
+    assert!("2 1/3".parse::<r32>().unwrap() == r32::new_unchecked(7, 3));
+    assert!("-2 1/3".parse::<r64>().unwrap() == r64::new_unchecked(-7, 3));
+    assert!(".5".parse::<r32>().unwrap() == r32::new_unchecked(1, 2));
+    for r in [r32::new_unchecked(-22, 7), r32::new_unchecked(5, 1), r32::default()] {
+        assert!(r.to_string().parse::<r32>().unwrap() == r);
+    }
+    assert!(matches!("".parse::<r32>(), Err(Error::Empty)));
+    assert!(matches!(" - ".parse::<r32>(), Err(Error::Empty)));
+    assert!(matches!("1/0".parse::<r32>(), Err(Error::DivideByZero)));
+    assert!(matches!("1/".parse::<r32>(), Err(Error::InvalidFraction)));
+    assert!(matches!("1.2(3".parse::<r32>(), Err(Error::InvalidDecimal)));
+    assert!(matches!("1.2.3".parse::<r32>(), Err(Error::InvalidDecimal)));
+    assert!(matches!("1a/2".parse::<r32>(), Err(Error::InvalidFraction)));
+    assert!(matches!("1/+2".parse::<r32>(), Err(Error::InvalidFraction)));
+    assert!(matches!("++2".parse::<r32>(), Err(Error::InvalidDecimal)));
+    assert!(matches!("12a".parse::<r32>(), Err(Error::InvalidDecimal)));
+    assert!(matches!("99999999999999999999".parse::<r64>(), Err(Error::NumeratorOverflow)));
+    assert!(matches!("1/99999999999999999999".parse::<r64>(), Err(Error::DenominatorOverflow)));
+    assert!(matches!("70000".parse::<r32>(), Err(Error::NumeratorOverflow)));
+    assert!(matches!("0.000001".parse::<r32>(), Err(Error::DenominatorOverflow)));
+}
+#[test]
+fn continued_fraction_test() {
+    use crate::num::rational::*;
+    use crate::num::continued_fraction::ContinuedFraction;
+    let cf = ContinuedFraction::from(r64::new_unchecked(415, 93));
+    assert!(cf.terms() == [4, 2, 6, 7]);
+    assert!(cf.to_string() == "[4; 2, 6, 7]");
+    assert!(cf.convergents().collect::<Vec<_>>() == vec![(4, 1), (9, 2), (58, 13), (415, 93)]);
+    assert!(ContinuedFraction::from(r32::new_unchecked(-7, 3)).terms() == [-3, 1, 2]);
+    let semi = ContinuedFraction::new(vec![0, 2, 3]).semiconvergents().collect::<Vec<_>>();
+    assert!(semi == vec![(0, 1), (1, 1), (1, 2), (1, 3), (2, 5), (3, 7)]);
+    let pi = ContinuedFraction::from(std::f64::consts::PI);
+    assert!(pi.terms()[..5] == [3, 7, 15, 1, 292]);
+    assert!(pi.best_approximation(10) == (22, 7));
+    assert!(pi.best_approximation(1000) == (355, 113));
+    //Semiconvergent 333/106 would lose to 22/7 here, but 311/99 is the best with denominator <= 100.
+    assert!(pi.best_approximation(100) == (311, 99));
+    assert!(r32::best_approximation(std::f64::consts::PI, 1000).unwrap() == r32::new_unchecked(355, 113));
+    assert!(r64::best_approximation(-0.3, 5).unwrap() == r64::new_unchecked(-1, 3));
+    assert!(r32::best_approximation(30000.5, 100).unwrap() == r32::new_unchecked(30000, 1));
+    assert!(matches!(r32::best_approximation(f64::NAN, 100), Err(Error::NumeratorOverflow)));
+    assert!(r32::from(0.1) == r32::new_unchecked(1, 10));
+    assert!(r64::from(0.75) == r64::new_unchecked(3, 4));
+}
+#[test]
+fn surd_ordering_test() {
+    use crate::num::rational::*;
+    use crate::num::surd::*;
+    let root2 = r32::new_unchecked(2, 1).surd_sqrt();
+    let root3 = r32::new_unchecked(3, 1).surd_sqrt();
+    let half_root8 = surd32::new(r32::new_unchecked(1, 2), 8);
+    assert!(root2 < root3);
+    assert!(-root2 > -root3);
+    assert!(-root3 < root2);
+    assert!(root2 == half_root8);
+    assert!(root2.cmp(&half_root8) == std::cmp::Ordering::Equal);
+    assert!(root2 > r32::new_unchecked(7, 5));
+    assert!(root2 < r32::new_unchecked(3, 2));
+    assert!(r64::new_unchecked(3, 2) > root2);
+    assert!(-root2 < r32::default());
+    assert!(surd32::new(r32::default(), 7) == r32::default());
+    assert!(r32::new_unchecked(4, 9).surd_sqrt() == r32::new_unchecked(2, 3));
+    let mut lengths = vec![root3, -root2, r32::new_unchecked(3, 2).surd_sqrt(), surd32::from(r32::identity())];
+    lengths.sort();
+    assert!(lengths == vec![-root2, surd32::from(r32::identity()), r32::new_unchecked(3, 2).surd_sqrt(), root3]);
+    //Squares that would overflow r64 are still compared exactly.
+    let a = surd64::new(r64::new_unchecked(i32::MAX, 3), 7);
+    let b = surd64::new(r64::new_unchecked(i32::MAX - 1, 3), 7);
+    assert!(a > b);
+    assert!(surdbig::new(rbig::from(2), 3u32.into()) > surdbig::new(rbig::from(3), 1u32.into()));
+    //Equality agrees with the ordering when square factors are left in the radicand, and so does hashing.
+    let (c, d) = (surdbig::new(rbig::from(1), (2 * 1009 * 1009u32).into()), surdbig::new(rbig::from(1009), 2u32.into()));
+    let unsimplified = surd32 { coef: r32::new_unchecked(1, 2), radicand: 8 };
+    assert!(c.radicand() != d.radicand());
+    assert!(c == d);
+    assert!(unsimplified == root2);
+    let big: std::collections::HashSet<surdbig> = [c.clone(), d.clone(), -c].into_iter().collect();
+    let small: std::collections::HashSet<surd32> = [root2, unsimplified, root3].into_iter().collect();
+    assert!(big.len() == 2);
+    assert!(small.len() == 2);
+    //Hashing never re-simplifies, so values that would overflow or have a zero radicand can still go in a set.
+    let overflowing = surd32 { coef: r32::new_unchecked(20000, 1), radicand: 8 };
+    let zero = surd32 { coef: r32::new_unchecked(1, 1), radicand: 0 };
+    let edge: std::collections::HashSet<surd32> = [overflowing, overflowing, zero, zero].into_iter().collect();
+    assert!(edge.len() == 2);
+}
+#[test]
+fn quadratic_surd_test() {
+    use crate::num::rational::*;
+    use crate::num::quadratic::QuadraticSurd;
+    use crate::num::Conjugate;
+    let half = r64::new_unchecked(1, 2);
+    let phi = QuadraticSurd::new(half, half, 5);
+    //phi^2 = phi + 1, and 1/phi = phi - 1
+    assert!(phi * phi == phi + r64::identity());
+    assert!(r64::identity() / phi == phi - r64::identity());
+    assert!(phi.norm() == r64::new_unchecked(-1, 1));
+    assert!(phi.conjugate() == QuadraticSurd::new(half, -half, 5));
+    assert!((f64::from(phi) - 1.618033988749895).abs() < 1e-12);
+    //sqrt(8) = 2*sqrt(2), and sqrt(2)*sqrt(2) is rational
+    let root8 = QuadraticSurd::new(r64::default(), r64::identity(), 8);
+    assert!(root8.b == r64::new_unchecked(2, 1));
+    assert!(root8.n == 2);
+    assert!((root8 * root8).rational() == Some(r64::new_unchecked(8, 1)));
+    assert!(QuadraticSurd::<r64>::from(r32::new_unchecked(25, 7).surd_sqrt()) == QuadraticSurd::<r64>::sqrt(r64::new_unchecked(25, 7)));
+    //Exact comparisons - 1.4 < sqrt(2) < 1.5 and 3 - sqrt(2) > 1.5
+    let root2 = QuadraticSurd::<r64>::sqrt(r64::new_unchecked(2, 1));
+    assert!(root2 > QuadraticSurd::from(r64::new_unchecked(7, 5)));
+    assert!(root2 < QuadraticSurd::from(r64::new_unchecked(3, 2)));
+    assert!(-root2 + r64::new_unchecked(3, 1) > QuadraticSurd::from(r64::new_unchecked(3, 2)));
+    assert!(root2.partial_cmp(&QuadraticSurd::<r64>::sqrt(r64::new_unchecked(3, 1))).is_none());
+    //Exact normalised vector, whose squared magnitude is exactly 1
+    let v = crate::geom::vector::Vector::<r64, 3>::new([r64::new_unchecked(1, 1), r64::new_unchecked(1, 1), r64::new_unchecked(2, 1)]).normalised_exact();
+    assert!(v[0] == QuadraticSurd::new(r64::default(), r64::new_unchecked(1, 6), 6));
+    assert!(v.data.iter().fold(QuadraticSurd::default(), |acc, c| acc + c * c) == QuadraticSurd::from(r64::identity()));
+}
+#[test]
+fn bigint_test() {
+    use crate::num::bigint::*;
+    let a = "123456789012345678901234567890".parse::<ubig>().unwrap();
+    let b = "987654321098765432109876543210".parse::<ubig>().unwrap();
+    let p = &a * &b;
+    assert!(p.to_string() == "121932631137021795226185032733622923332237463801111263526900");
+    assert!(&p / &a == b);
+    assert!((&p % &a).is_zero());
+    let (q, r) = (&p + &ubig::from(12345u32)).div_rem(&b);
+    assert!(q == a);
+    assert!(r == ubig::from(12345u32));
+    assert!(p.isqrt() < b);
+    assert!(ubig::from(u128::MAX).isqrt() == ubig::from(u64::MAX));
+    assert!((&ibig::from(-7) / &ibig::from(2)) == ibig::from(-3));
+    assert!((&ibig::from(-7) % &ibig::from(2)) == ibig::from(-1));
+    assert!("-42".parse::<ibig>().unwrap().to_string() == "-42");
+    assert!("12a".parse::<ubig>() == Err(ParseBigError::InvalidDigit));
+}
+#[test]
+fn rbig_test() {
+    use crate::num::rational::*;
+    use crate::geom::matrix::MatrixBig;
+    //4x4 Hilbert matrix - determinant is 1/6048000, which overflows r32 and r64 intermediates.
+    let h = MatrixBig::<4, 4>::new(std::array::from_fn(|i| std::array::from_fn(|j| rbig::new_unchecked(1.into(), ((i + j + 1) as i64).into()))));
+    assert!(h.det().unwrap() == rbig::new_unchecked(1.into(), 6048000.into()));
+    let inv = h.inverse().unwrap();
+    assert!(inv[0][0] == rbig::from(16));
+    assert!(inv[3][3] == rbig::from(2800));
+    assert!(inv[1][2] == rbig::from(-2700));
+    assert!(h.clone() * inv == MatrixBig::<4, 4>::identity());
+    assert!(rbig::from(0.1) + rbig::from(0.2) != rbig::from(0.3));
+    assert!(rbig::from(r32::new_unchecked(1, 10)) + rbig::from(r32::new_unchecked(2, 10)) == rbig::from(r32::new_unchecked(3, 10)));
+    assert!(f64::from(rbig::from(0.1)) == 0.1);
+    assert!(rbig::int_dec(false, "1", "25").unwrap() == rbig::new_unchecked((-5).into(), 4.into()));
+    assert!(rbig::new_unchecked(25.into(), 7.into()).surd_sqrt().squared() == rbig::new_unchecked(25.into(), 7.into()));
+    assert!(rbig::new_unchecked(9.into(), 4.into()).surd_sqrt().rational() == Some(rbig::new_unchecked(3.into(), 2.into())));
+}
+#[test]
 fn quaternion_composition() {
     let a = Quaternion::new(0.4, 0.3, 0.2, 0.5);
     let b = Quaternion::new(0.5, 0.4, 0.3, 0.6);
@@ -253,4 +441,810 @@ fn bit_test() {
     assert!(!f.0);
     assert!(f.1 == 133);
     assert!(f.2 == 8026849);
-}
\ No newline at end of file
+}
+//Generic Scalar Tests
+#[test]
+fn generic_scalar_test() {
+    use crate::geom::{vector::Vector as VectorT, matrix::Matrix as MatrixT};
+    use crate::num::rational::r64;
+    let m = MatrixT::<f64, 3, 3>::new([[22.0, 15.0, 5.0], [2.0, 45.0, 3.0], [42.0, 0.0, 10.0]]);
+    assert!((m.det().unwrap() - 2040.0).abs() < 0.0000001);
+    assert!((m.lup_det().unwrap() - 2040.0).abs() < 0.0000001);
+    let v = VectorT::<f64, 3>::new([1.0, 2.0, 3.0]);
+    assert!(((m * m.inverse().unwrap() * v) - v).sq_sum() < 0.0000001);
+    assert!((v.normalised().sq_sum() - 1.0).abs() < 0.0000001);
+    let r = |n, d| r64::new_unchecked(n, d);
+    let mp = MatrixT::<r64, 2, 2>::new([[r(1, 2), r(1, 3)], [r(1, 4), r(1, 5)]]);
+    assert!(mp.det().unwrap() == r(1, 60));
+    assert!(mp * mp.inverse().unwrap() == MatrixT::<r64, 2, 2>::identity());
+    let vp = VectorT::<r64, 2>::new([r(1, 2), r(2, 3)]);
+    assert!(vp.dot(vp) == r(25, 36));
+}
+#[test]
+fn double_precision_test() {
+    use crate::{QuaternionD, DualQuaternionD, Vector3d, Vector4d, Matrix4d};
+    let dq = DualQuaternionD::from_translate_rotate(Vector3d::new([100000.0, 3.0, 5.0]), QuaternionD::from_euler(0.5, 0.4, 0.3, RotationOrder::xyz())).normalise();
+    let point = Vector4d::new([100005.0, 4.0, 3.0, 1.0]);
+    let back = dq.inverse().transform(dq.transform(point));
+    assert!((back - point).sq_sum().sqrt() < 0.00001);
+    let trs = crate::geom::transforms::transform_mat::from_trs(Vector3d::new([0.0, 2.0, 0.0]), QuaternionD::from_z_angle(-std::f64::consts::PI/2.0), Vector3d::new([2.0, 2.0, 2.0]));
+    assert!((trs * Vector4d::new([1.0, 0.0, 0.0, 1.0]) - Vector4d::new([0.0, 0.0, 0.0, 1.0])).sq_sum() < 0.0000001);
+    assert!(Matrix4d::from(trs.to_f32()).to_f32() == trs.to_f32());
+    let q = Quaternion::new(0.4, 0.3, 0.2, 0.5);
+    assert!(QuaternionD::from(q).to_f32() == q);
+    let mat = Matrix::<4, 4>::new([[0.0, 1.0, 0.0, 0.0], [-0.5, 0.0, 0.5, 0.0], [1.0, -2.5, 2.0, -0.5], [-0.5, 1.5, -1.5, 0.5]]);
+    let spline = crate::SplineD::from(crate::Spline::<2>::new(mat, (0..4).map(|i| Vector::<2>::new([i as f32, 1.0])).collect()));
+    assert!((spline.calc(0.5).unwrap().to_f32() - spline.to_f32().calc(0.5).unwrap()).sq_sum() < 1e-10);
+    assert!(spline.to_f32().mat() == mat);
+    let f = crate::Float3::from(Vector3d::new([1.0, 2.0, 3.0]));
+    assert!(f.x == 1.0);
+    assert!(f.y == 2.0);
+    assert!(f.z == 3.0);
+}
+#[test]
+fn exact_trig_test() {
+    use crate::num::rational::*;
+    use crate::num::quadratic::QuadraticSurd;
+    use crate::num::nested::NestedSurd;
+    use crate::num::trig::*;
+    use crate::geom::transforms::EulerAxis;
+    let qs = |a: (i32, i32), b: (i32, i32), n: u64| QuadraticSurd::new(r64::new_unchecked(a.0, a.1), r64::new_unchecked(b.0, b.1), n);
+    let q = |a: (i32, i32), b: (i32, i32), n: u64| NestedSurd::from(qs(a, b, n));
+    assert!(cos_pi(r64::new_unchecked(1, 5)).unwrap() == q((1, 4), (1, 4), 5));
+    assert!(sin_pi(r64::new_unchecked(1, 10)).unwrap() == q((-1, 4), (1, 4), 5));
+    assert!(cos_pi(r64::new_unchecked(-7, 3)).unwrap() == q((1, 2), (0, 1), 1));
+    assert!(sin_pi(r64::new_unchecked(5, 4)).unwrap() == q((0, 1), (-1, 2), 2));
+    assert!(tan_pi(r64::new_unchecked(1, 3)).unwrap() == q((0, 1), (1, 1), 3));
+    //sin(pi/5) = sqrt(10 - 2*sqrt(5))/4 needs a nested surd.
+    let sin_fifth = sin_pi(r64::new_unchecked(1, 5)).unwrap();
+    assert!(sin_fifth == NestedSurd::new(QuadraticSurd::default(), qs((1, 4), (0, 1), 1), qs((10, 1), (-2, 1), 5)));
+    assert!(sin_fifth.quadratic().is_none());
+    assert!(sin_fifth > q((1, 2), (0, 1), 1));
+    assert!(sin_fifth < cos_pi(r64::new_unchecked(1, 5)).unwrap());
+    assert!(-sin_fifth < NestedSurd::default());
+    assert!((f64::from(tan_pi(r64::new_unchecked(2, 5)).unwrap()) - (2.0 * std::f64::consts::PI / 5.0).tan()).abs() < 1e-12);
+    assert!(cos_pi(r64::new_unchecked(1, 12)).is_none());
+    assert!(sin_cos_pi(r64::new_unchecked(1, 8)).is_none());
+    assert!(tan_pi(r64::new_unchecked(1, 2)).is_none());
+    for (k, d) in (0..24).map(|k| (k, 12)).chain((0..20).map(|k| (k, 10))) {
+        //Odd multiples of pi/12 need surds nested over Q(sqrt(3)), which aren't supported.
+        let Some((s, c)) = sin_cos_pi(r64::new_unchecked(k, d)) else { assert!(d == 12); assert!(k % 2 == 1); continue; };
+        assert!(&s * &s + &c * &c == NestedSurd::identity());
+        let angle = k as f64 * std::f64::consts::PI / d as f64;
+        assert!((f64::from(c) - angle.cos()).abs() < 1e-12);
+        assert!((f64::from(s) - angle.sin()).abs() < 1e-12);
+    }
+    let rot = crate::MatrixPrecise2::rotation_pi(r32::new_unchecked(1, 4)).unwrap();
+    let v = crate::VectorNested2::from(crate::VectorPrecise2::new([r32::identity(), r32::default()]));
+    assert!(rot * v == crate::VectorNested2::new([q((0, 1), (1, 2), 2), q((0, 1), (1, 2), 2)]));
+    let (mut full, mut fifth) = (crate::MatrixNested2::identity(), crate::MatrixNested2::identity());
+    for _ in 0..12 { full = full * crate::MatrixPrecise2::rotation_pi(r32::new_unchecked(1, 6)).unwrap(); }
+    for _ in 0..10 { fifth = fifth * crate::MatrixPrecise2::rotation_pi(r32::new_unchecked(1, 5)).unwrap(); }
+    assert!(full == crate::MatrixNested2::identity());
+    assert!(fifth == crate::MatrixNested2::identity());
+    let z = crate::MatrixPrecise3::rotation_pi(EulerAxis::Z, r32::new_unchecked(1, 2)).unwrap();
+    let x = crate::VectorNested3::from(crate::VectorPrecise3::new([r32::identity(), r32::default(), r32::default()]));
+    assert!(z * x == crate::VectorNested3::from(crate::VectorPrecise3::new([r32::default(), r32::identity(), r32::default()])));
+    let (y, r) = (crate::MatrixPrecise3::rotation_pi(EulerAxis::Y, r32::new_unchecked(2, 5)).unwrap(), crate::MatrixPrecise3::rotation_pi(EulerAxis::Y, r32::new_unchecked(-2, 5)).unwrap());
+    assert!(y * r == crate::MatrixNested3::identity());
+    assert!(y.transpose() == r);
+}
+#[test]
+fn primality_test() {
+    use crate::num::factors::*;
+    let sieve = (0..2000_u32).map(|n| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)).collect::<Vec<_>>();
+    assert!((0..2000_u32).all(|n| is_prime32(n) == sieve[n as usize] && is_prime64(n as u64) == sieve[n as usize]));
+    //Strong pseudoprimes to several bases.
+    for n in [3215031751_u64, 4759123141, 1122004669633, 3825123056546413051] { assert!(!is_prime64(n)); }
+    assert!(is_prime32(u32::MAX - 4));
+    assert!(!is_prime32(u32::MAX));
+    assert!(is_prime64(u64::MAX - 58));
+    assert!(!is_prime64(u64::MAX));
+    assert!(is_prime128((1 << 89) - 1));
+    assert!(is_prime128((1 << 127) - 1));
+    assert!(!is_prime128(((1 << 61) - 1) * ((1 << 67) - 1)));
+    assert!(!is_prime128(18446744073709551557 * 18446744073709551557));
+    assert!(is_prime128(u128::MAX - 158));
+    assert!(next_prime32(13) == Some(17));
+    assert!(next_prime32(0) == Some(2));
+    assert!(next_prime32(u32::MAX - 4).is_none());
+    assert!(prev_prime32(17) == Some(13));
+    assert!(prev_prime32(3) == Some(2));
+    assert!(prev_prime32(2).is_none());
+    assert!(next_prime64(u32::MAX as u64) == Some(4294967311));
+    assert!(prev_prime128(1 << 89) == Some((1 << 89) - 1));
+    assert!(nth_prime(1) == 2);
+    assert!(nth_prime(10) == 29);
+    assert!(nth_prime(1000) == 7919);
+    let mut f = fac64(600851475143);
+    f.sort();
+    assert!(f == vec![71, 839, 1471, 6857]);
+    //Prime cofactors that used to overflow the rho iteration.
+    let mut f = fac64(9223372036854775783 * 2);
+    f.sort();
+    assert!(f == vec![2, 9223372036854775783]);
+    assert!(fac32(u32::MAX - 4) == vec![u32::MAX - 4]);
+}
+#[test]
+fn prime_sieve_test() {
+    use crate::num::factors::*;
+    assert!(PrimeSieve::new(0, 30).collect::<Vec<_>>() == vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert!(PrimeSieve::new(10, 10).next().is_none());
+    assert!(PrimeSieve::new(20, 10).next().is_none());
+    assert!(SMALL_PRIMES.iter().map(|p| *p as u64).eq(PrimeSieve::new(0, 1000)));
+    //Several segments, checked against Miller-Rabin.
+    let lo = 1_000_000_000;
+    assert!(PrimeSieve::new(lo, lo + 100_000).eq((lo..lo + 100_000).filter(|n| is_prime64(*n))));
+    assert!(prime_pi(1) == 0);
+    assert!(prime_pi(2) == 1);
+    assert!(prime_pi(100) == 25);
+    assert!(prime_pi(1_000_000) == 78498);
+    assert!(fac64(2 * 3 * 997 * 1009 * 4294967311) == vec![2, 3, 997, 1009, 4294967311]);
+    assert!(fac32(0) == vec![0]);
+}
+#[test]
+fn arithmetic_function_test() {
+    use crate::num::arithmetic::*;
+    use crate::num::factors::gcd64;
+    for n in 1..=500_u64 {
+        let divisors = (1..=n).filter(|d| n % d == 0).collect::<Vec<_>>();
+        let mut found = divisors64(n).collect::<Vec<_>>();
+        found.sort();
+        assert!(found == divisors);
+        assert!(totient64(n) == (1..=n).filter(|k| gcd64(*k, n) == 1).count() as u64);
+        assert!(divisor_count64(n) == divisors.len() as u64);
+        assert!(divisor_count32(n as u32) == divisors.len() as u64);
+        assert!(divisor_sum64(n) == divisors.iter().sum::<u64>() as u128);
+        let squarefree = (2..=n).all(|d| n % (d * d) != 0);
+        let omega = divisors.iter().filter(|d| **d > 1 && (2..**d).all(|q| *d % q != 0)).count();
+        assert!(mobius64(n) == match squarefree { true => 1 - 2 * (omega as i32 % 2), false => 0 });
+        let units = (1..=n).filter(|a| gcd64(*a, n) == 1).collect::<Vec<_>>();
+        let lambda = (1..=n).find(|m| units.iter().all(|a| crate::num::factors::powmod64(*a, *m, n) == 1 % n)).unwrap();
+        assert!(carmichael64(n) == lambda);
+        assert!(carmichael32(n as u32) as u64 == lambda);
+    }
+    assert!(carmichael64(561) == 80);
+    assert!(totient32(u32::MAX) == 2147483648);
+    assert!(divisors32(1).collect::<Vec<_>>() == vec![1]);
+    //Beyond u64 - (2^31 - 1)(2^61 - 1) is a product of two primes.
+    let n = ((1_u128 << 31) - 1) * ((1 << 61) - 1);
+    assert!(divisor_count128(n) == 4);
+    assert!(mobius128(n) == 1);
+    assert!(totient128(n) == ((1 << 31) - 2) * ((1 << 61) - 2));
+    assert!(divisor_sum128(n) == Some(1 << 92));
+    assert!(divisor_sum128(u128::MAX).is_none());
+    assert!(mobius128(u128::MAX) == -1);
+    assert!(divisors128(n).count() == 4);
+    assert!(carmichael128(n) == (1 << 61) - 2);
+}
+#[test]
+fn modular_test() {
+    use crate::num::modular::*;
+    type M7 = ModInt<7>;
+    let (a, b) = (M7::new(5), M7::from_i64(-3));
+    assert!(b.value() == 4);
+    assert!((a + b).value() == 2);
+    assert!((a - b).value() == 1);
+    assert!((a * b).value() == 6);
+    assert!((-a).value() == 2);
+    assert!(a / b * b == a);
+    assert!(a.inverse() == Some(M7::new(3)));
+    assert!(M7::default().inverse().is_none());
+    assert!(a.pow(6) == M7::new(1));
+    assert!(M7::new(3).pow(0).value() == 1);
+    assert!(ModInt::<1>::new(5).pow(0).value() == 0);
+    const P: u64 = 18446744073709551557;
+    let x = ModInt::<P>::new(P - 2);
+    assert!(x.pow(P - 1).value() == 1);
+    assert!((x * x.inverse().unwrap()).value() == 1);
+    assert!((x + x).value() == P - 4);
+    let mut c = DynModInt::new(10, 12);
+    c += DynModInt::new(5, 12);
+    c *= DynModInt::new(5, 12);
+    assert!(c.value() == 3);
+    assert!(c.inverse().is_none());
+    assert!(DynModInt::new(5, 12).inverse() == Some(DynModInt::new(5, 12)));
+    assert!(DynModInt::new(3, 1 << 40).pow(1 << 40).value() == crate::num::factors::powmod64(3, 1 << 40, 1 << 40));
+    assert!(std::panic::catch_unwind(|| DynModInt::new(1, 5) + DynModInt::new(1, 6)).is_err());
+    for n in [3, 1_000_000_007, u64::MAX] {
+        let mont = Montgomery::new(n).unwrap();
+        for (a, b) in [(2, 3), (n - 1, n - 1), (123456789, 987654321)] {
+            assert!(mont.from_mont(mont.mul(mont.to_mont(a), mont.to_mont(b))) == crate::num::factors::mulmod64(a, b, n));
+        }
+    }
+    assert!(Montgomery::new(10).is_none());
+    assert!(mod_inverse(3, 11) == Some(4));
+    assert!(mod_inverse(6, 9).is_none());
+    assert!(mod_inverse(3, 0).is_none());
+    assert!(crt(&[(2, 3), (3, 5), (2, 7)]) == Some((23, 105)));
+    assert!(crt(&[(1, 4), (3, 6)]) == Some((9, 12)));
+    assert!(crt(&[(1, 4), (2, 6)]).is_none());
+    assert!(crt(&[]) == Some((0, 1)));
+    let (x, _l) = crt(&[(1, u64::MAX), (2, u64::MAX - 1)]).unwrap();
+    assert!(x % u64::MAX as u128 == 1);
+    assert!(x % (u64::MAX - 1) as u128 == 2);
+}
+#[test]
+fn generic_integer_test() {
+    use crate::num::factors::*;
+    fn euclid(a: u128, b: u128) -> u128 { match b { 0 => a, _ => euclid(b, a % b) } }
+    for a in 0..60_u64 {
+        for b in 0..60_u64 {
+            assert!(gcd(a, b) as u128 == euclid(a as u128, b as u128));
+            assert!(gcd(-(a as i64), b as i64) == gcd(a, b));
+            let (g, x, y) = extended_gcd(a as i32 - 30, b as i32);
+            assert!(g == gcd(a as i32 - 30, b as i32));
+            assert!((a as i32 - 30) * x + b as i32 * y == g as i32);
+        }
+    }
+    assert!(gcd(i8::MIN, 0) == 128_u8);
+    assert!(gcd(u128::MAX, u128::MAX - 1) == 1);
+    assert!(gcd(0_u8, 0) == 0);
+    assert!(lcm(4_u8, 6) == Some(12));
+    assert!(lcm(200_u8, 3).is_none());
+    assert!(lcm(-4_i32, 6) == Some(12));
+    assert!(lcm(0_u16, 7) == Some(0));
+    assert!(lcm32(65536, 65536) == 65536);
+    assert!(lcm64(1 << 40, 3 << 20) == 3 << 40);
+    for (a, b) in [(u64::MAX, u64::MAX - 1), (u64::MAX - 58, 1 << 63), (240, 46)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert!(g == gcd(a, b));
+        assert!(a as i128 * x as i128 + b as i128 * y as i128 == g as i128);
+    }
+    let (a, b) = (u128::MAX, u128::MAX - 2);
+    let (g, x, y) = extended_gcd(a, b);
+    assert!(g == 1);
+    assert!(a.wrapping_mul(x as u128).wrapping_add(b.wrapping_mul(y as u128)) == 1);
+    assert!(fac(-360_i32) == vec![2, 2, 2, 3, 3, 5]);
+    assert!(fac(255_u8) == vec![3, 5, 17]);
+    assert!(fac(1_usize) == vec![1]);
+    assert!(prho(91_u16) == (7, 13) || prho(91_u16) == (13, 7));
+}
+#[test]
+fn fac128_test() {
+    use crate::num::factors::*;
+    let (p, q) = (1099511627791_u128, 154742504910672534362390461_u128);
+    assert!(fac128(p * q) == vec![p, q]);
+    assert!(fac128(u128::MAX).iter().all(|f| is_prime128(*f)));
+    let (a, b) = prho128(p * q);
+    assert!(a * b == p * q);
+    assert!(a > 1);
+    assert!(b > 1);
+    assert!(prho128(q) == (0, 0));
+    assert!(fac128(p * p * 3) == vec![3, p, p]);
+    assert!(fac128((1 << 89) - 1) == vec![(1 << 89) - 1]);
+    assert!(fac64(u64::MAX) == vec![3, 5, 17, 257, 641, 65537, 6700417]);
+    assert!(fac64(4294967291 * 4294967279) == vec![4294967279, 4294967291]);
+    //Every factor is prime, including the cofactors where the old fixed iteration limit gave up.
+    for n in (1_u64 << 62..(1 << 62) + 2000).step_by(7) {
+        let f = fac64(n);
+        assert!(f.iter().product::<u64>() == n);
+        assert!(f.iter().all(|p| is_prime64(*p)));
+    }
+    assert!(powmod128(3, (1 << 89) - 2, (1 << 89) - 1) == 1);
+}
+#[test]
+fn integer_root_test() {
+    use crate::num::roots::*;
+    use crate::num::Sqroot;
+    //f64 rounds 4000000001^2 - 1 up to a square, which the old Sqroot got wrong.
+    let n = 4000000001_u64.pow(2) - 1;
+    assert!(n.sqroot() == 4000000000);
+    assert!(isqrt(n + 1) == 4000000001);
+    assert!(u128::MAX.sqroot() == u64::MAX as u128);
+    assert!(isqrt(0_u8) == 0);
+    assert!(isqrt(255_u8) == 15);
+    assert!((-4_i32).sqroot() == 0);
+    assert!(isqrt(i64::MAX) == 3037000499);
+    for n in 0..2000_u32 {
+        let (r, c) = (isqrt(n), icbrt(n));
+        assert!(r * r <= n);
+        assert!((r + 1) * (r + 1) > n);
+        assert!(c * c * c <= n);
+        assert!((c + 1).pow(3) > n);
+        assert!(is_perfect_square(n) == (r * r == n));
+        assert!(icbrt(-(n as i32)) == -(c as i32));
+    }
+    assert!(iroot(u128::MAX, 3) == 6981463658331);
+    assert!(iroot(u128::MAX, 127) == 2);
+    assert!(iroot(1_u64 << 63, 63) == 2);
+    assert!(iroot(-1000_i64, 3) == -10);
+    assert!(iroot(7_u8, 1) == 7);
+    assert!(icbrt(u64::MAX) == 2642245);
+    assert!(!is_perfect_square(-4_i32));
+    assert!(is_perfect_square(u64::MAX as u128 * u64::MAX as u128));
+    assert!(!is_perfect_square(u128::MAX));
+    assert!(perfect_power(64_u32) == Some((2, 6)));
+    assert!(perfect_power(-64_i32) == Some((-4, 3)));
+    assert!(perfect_power(72_u32).is_none());
+    assert!(perfect_power(1_u8).is_none());
+    assert!(perfect_power(3_u128.pow(80)) == Some((3, 80)));
+    assert!(perfect_power(-32_i8) == Some((-2, 5)));
+    let big = crate::num::surd::surd64::new(crate::num::rational::r64::identity(), (1 << 30 | 1) * (1 << 30 | 1));
+    assert!(big.radicand == 1);
+    assert!(big.coef == crate::num::rational::r64::new_unchecked(1 << 30 | 1, 1));
+}
+#[test]
+fn qr_least_squares_test() {
+    use crate::geom::matrix::Matrix;
+    use crate::geom::vector::Vector;
+    use crate::num::rational::r32;
+    let a = Matrix::<f64, 4, 3>::new([[1.0, 2.0, 0.5], [3.0, -1.0, 2.0], [0.0, 4.0, 1.0], [2.0, 2.0, -3.0]]);
+    let (q, r) = a.qr();
+    let (qtq, qr) = (q.transpose() * q, q * r);
+    for i in 0..4 { for j in 0..4 { assert!((qtq[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12); } }
+    for i in 0..4 { for j in 0..3 { assert!((qr[i][j] - a[i][j]).abs() < 1e-12); assert!(j >= i || r[i][j] == 0.0); } }
+    //An exactly consistent system is solved exactly, an inconsistent one satisfies the normal equations.
+    let x = Vector::new([1.0, -2.0, 0.5]);
+    let sol = a.solve_least_squares(a * x);
+    for i in 0..3 { assert!((sol[i] - x[i]).abs() < 1e-12); }
+    let b = Vector::new([1.0, 0.0, 2.0, -1.0]);
+    let res = b - a * a.solve_least_squares(b);
+    assert!((a.transpose() * res).data.iter().all(|v| v.abs() < 1e-12));
+    //The third column is the sum of the first two, so the rank is 2.
+    let d = Matrix::<f64, 4, 3>::new([[1.0, 2.0, 3.0], [0.0, 1.0, 1.0], [4.0, -1.0, 3.0], [2.0, 2.0, 4.0]]);
+    let (q, r, p, rank) = d.qr_pivoted();
+    let (qr, dp) = (q * r, d * p);
+    assert!(rank == 2);
+    assert!(a.qr_pivoted().3 == 3);
+    assert!(Matrix::<f32, 3, 2>::default().qr_pivoted().3 == 0);
+    for i in 0..4 { for j in 0..3 { assert!((qr[i][j] - dp[i][j]).abs() < 1e-12); } }
+    let sol = d.solve_least_squares(d * x);
+    assert!((d * sol - d * x).data.iter().all(|v| v.abs() < 1e-12));
+    //Exact Gram-Schmidt keeps rational components and gives exactly orthogonal columns.
+    let m: Matrix<r32, 3, 3> = Matrix::<f32, 3, 3>::new([[1.0, 1.0, 0.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]).into();
+    let g = m.gram_schmidt();
+    assert!(g.col(1) == Vector::new([r32::new_raw(1, 2), r32::new_raw(-1, 2), r32::identity()]));
+    for i in 0..3 { for j in 0..i { assert!(g.col(i).dot(g.col(j)) == r32::default()); } }
+    let n = m.gram_schmidt_normalised().unwrap();
+    assert!((0..3).all(|i| n.col(i).dot(n.col(i)) == crate::num::quadratic::QuadraticSurd::identity()));
+    assert!(d.to_f32().convert::<r32>().gram_schmidt().col(2) == Vector::default());
+    assert!(Matrix::<r32, 3, 2>::default().gram_schmidt_normalised().is_none());
+}
+#[test]
+fn eigen_test() {
+    use crate::geom::matrix::Matrix;
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    //Inertia tensor of a box-like body - symmetric, with known eigenvalues 1, 2 and 4.
+    let a = Matrix::<f64, 3, 3>::new([[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 4.0]]);
+    let b = Matrix::<f64, 3, 3>::new([[3.0, 0.0, 1.0], [0.0, 2.0, 0.0], [1.0, 0.0, 3.0]]) * a;
+    let (values, vectors) = a.symmetric_eigen();
+    assert!(close(values[0], 1.0));
+    assert!(close(values[1], 3.0));
+    assert!(close(values[2], 4.0));
+    let (vtv, av) = (vectors.transpose() * vectors, a * vectors);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!(close(vtv[i][j], if i == j { 1.0 } else { 0.0 }));
+            assert!(close(av[i][j], vectors[i][j] * values[j]));
+        }
+    }
+    let c = Matrix::<f64, 4, 4>::new([[4.0, -2.0, 1.0, 0.5], [-2.0, 3.0, 0.0, 1.0], [1.0, 0.0, 2.0, -1.0], [0.5, 1.0, -1.0, 5.0]]);
+    let (values, vectors) = c.symmetric_eigen();
+    let trace = values.sum();
+    assert!(close(trace, 14.0));
+    assert!(values[0] <= values[1]);
+    assert!(values[1] <= values[2]);
+    assert!(values[2] <= values[3]);
+    let qr = c.eigenvalues().unwrap();
+    for i in 0..4 { assert!(close(qr[i], values[i])); assert!(close((c * vectors.col(i) - vectors.col(i) * values[i]).sq_sum(), 0.0)); }
+    //Non-symmetric with real eigenvalues - similar to diag(1, 2, 3) - and with a complex pair.
+    let p = Matrix::<f64, 3, 3>::new([[1.0, 2.0, 0.0], [0.0, 1.0, 1.0], [1.0, 0.0, 1.0]]);
+    let d = Matrix::<f64, 3, 3>::new([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]);
+    let e = (p * d * p.inverse().unwrap()).eigenvalues().unwrap();
+    assert!(close(e[0], 1.0));
+    assert!(close(e[1], 2.0));
+    assert!(close(e[2], 3.0));
+    assert!(b.eigenvalues().is_some());
+    //Companion matrix of (x - 1)(x - 2)(x - 3)(x - 4)(x - 5).
+    let g = Matrix::<f64, 5, 5>::new([[15.0, -85.0, 225.0, -274.0, 120.0], [1.0, 0.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0, 0.0]]).eigenvalues().unwrap();
+    for i in 0..5 { assert!((g[i] - (i + 1) as f64).abs() < 1e-6); }
+    assert!(Matrix::<f64, 2, 2>::new([[0.0, -1.0], [1.0, 0.0]]).eigenvalues().is_none());
+    let f = Matrix::<f32, 2, 2>::new([[0.0, 1.0], [1.0, 0.0]]).eigenvalues().unwrap();
+    assert!(f[0] == -1.0);
+    assert!(f[1] == 1.0);
+    assert!(Matrix::<f64, 3, 3>::identity().eigenvalues() == Some(crate::geom::vector::Vector::new([1.0; 3])));
+}
+#[test]
+fn svd_test() {
+    use crate::geom::matrix::Matrix;
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    fn check<const R: usize, const C: usize>(a: Matrix<f64, R, C>) {
+        let (u, s, vt) = a.svd();
+        let (usv, utu, vvt) = (u * s * vt, u.transpose() * u, vt * vt.transpose());
+        for i in 0..R { for j in 0..C { assert!((usv[i][j] - a[i][j]).abs() < 1e-9); assert!(i == j || s[i][j] == 0.0); } }
+        for i in 0..R { for j in 0..R { assert!((utu[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-9); } }
+        for i in 0..C { for j in 0..C { assert!((vvt[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-9); } }
+        for i in 1..R.min(C) { assert!(s[i][i] <= s[i-1][i-1]); assert!(s[i][i] >= 0.0); }
+        //The Moore-Penrose conditions A A+ A = A and A+ A A+ = A+.
+        let p = a.pseudo_inverse();
+        let (apa, pap) = (a * p * a, p * a * p);
+        for i in 0..R { for j in 0..C { assert!((apa[i][j] - a[i][j]).abs() < 1e-9); assert!((pap[j][i] - p[j][i]).abs() < 1e-9); } }
+    }
+    let a = Matrix::<f64, 3, 2>::new([[3.0, 0.0], [0.0, -4.0], [0.0, 0.0]]);
+    check(a);
+    check(a.transpose());
+    check(Matrix::<f64, 4, 3>::new([[1.0, 2.0, 3.0], [0.0, 1.0, 1.0], [4.0, -1.0, 3.0], [2.0, 2.0, 4.0]]));
+    check(Matrix::<f64, 3, 3>::new([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]));
+    check(Matrix::<f64, 2, 4>::default());
+    let s = a.singular_values();
+    assert!(close(s[0], 4.0));
+    assert!(close(s[1], 3.0));
+    assert!(close(a.spectral_norm(), 4.0));
+    assert!(close(a.condition_number(), 4.0 / 3.0));
+    //Singular matrices have no inverse, but the pseudo-inverse still solves in the least squares sense.
+    let b = Matrix::<f64, 3, 3>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+    assert!(b.lup_inverse().is_none());
+    assert!(b.rank(1e-9) == 2);
+    assert!(b.condition_number() > 1e9);
+    assert!(a.rank(1e-9) == 2);
+    let p = Matrix::<f64, 2, 2>::new([[4.0, 7.0], [2.0, 6.0]]);
+    let (pi, inv) = (p.pseudo_inverse(), p.inverse().unwrap());
+    for i in 0..2 { for j in 0..2 { assert!(close(pi[i][j], inv[i][j])); } }
+    assert!(Matrix::<f64, 2, 2>::default().condition_number() == f64::INFINITY);
+    assert!(Matrix::<f32, 2, 2>::identity().condition_number() == 1.0);
+    assert!(Matrix::<f64, 3, 3>::identity().rank(0.5) == 3);
+}
+#[test]
+fn cholesky_ldlt_test() {
+    use crate::geom::matrix::{Matrix, DecompositionError};
+    use crate::geom::vector::Vector;
+    use crate::num::rational::r32;
+    let a = Matrix::<f64, 3, 3>::new([[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]]);
+    let l = a.cholesky().unwrap();
+    assert!(l == Matrix::new([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]));
+    assert!(l * l.transpose() == a);
+    let (ld, d) = a.ldlt().unwrap();
+    assert!(ld == Matrix::new([[1.0, 0.0, 0.0], [3.0, 1.0, 0.0], [-4.0, 5.0, 1.0]]));
+    assert!(d == Vector::new([4.0, 1.0, 9.0]));
+    let x = Vector::new([1.0, -2.0, 0.5]);
+    let (c, l) = (a.cholesky_solve(a * x).unwrap(), a.ldlt_solve(a * x).unwrap());
+    let (ci, li) = (a.cholesky_inverse().unwrap() * a, a.ldlt_inverse().unwrap() * a);
+    for i in 0..3 {
+        assert!((c[i] - x[i]).abs() < 1e-9);
+        assert!((l[i] - x[i]).abs() < 1e-9);
+        for j in 0..3 { assert!((ci[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-9); assert!((li[i][j] - ci[i][j]).abs() < 1e-9); }
+    }
+    //Indefinite matrices have an LDL^T but no Cholesky, and asymmetric ones have neither.
+    let b = Matrix::<f64, 2, 2>::new([[1.0, 2.0], [2.0, 1.0]]);
+    assert!(b.cholesky() == Err(DecompositionError::NotPositiveDefinite(1)));
+    assert!(b.ldlt().unwrap().1 == Vector::new([1.0, -3.0]));
+    let n = Matrix::<f32, 2, 2>::new([[1.0, 2.0], [0.0, 1.0]]);
+    assert!(n.cholesky() == Err(DecompositionError::NotSymmetric));
+    assert!(n.ldlt() == Err(DecompositionError::NotSymmetric));
+    assert!(Matrix::<f64, 2, 2>::new([[0.0, 1.0], [1.0, 0.0]]).ldlt() == Err(DecompositionError::ZeroPivot(0)));
+    //Exact rational LDL^T - the inverse of the Hilbert matrix has integer entries.
+    let h = Matrix::<r32, 3, 3>::new(std::array::from_fn(|i| std::array::from_fn(|j| r32::new_raw(1, (i + j + 1) as u16))));
+    let (l, d) = h.ldlt().unwrap();
+    assert!(d == Vector::new([r32::identity(), r32::new_raw(1, 12), r32::new_raw(1, 180)]));
+    assert!(l[2][1] == r32::identity());
+    let hi = h.ldlt_inverse().unwrap();
+    assert!(hi == Matrix::<f32, 3, 3>::new([[9.0, -36.0, 30.0], [-36.0, 192.0, -180.0], [30.0, -180.0, 180.0]]).into());
+    assert!(h * hi == Matrix::identity());
+    assert!(h.ldlt_solve(h * Vector::new([r32::identity(); 3])).unwrap() == Vector::new([r32::identity(); 3]));
+}
+#[test]
+fn lup_pivoting_test() {
+    use crate::geom::matrix::{Matrix, Pivoting};
+    use crate::geom::vector::Vector;
+    use crate::num::rational::r32;
+    //The largest pivots by absolute value are negative - the old signed comparison picked the wrong rows.
+    let a = Matrix::<f64, 3, 3>::new([[1.0, 2.0, 3.0], [-10.0, 1.0, 0.0], [2.0, -8.0, 4.0]]);
+    let det = a.det().unwrap();
+    let lup = a.lup_pivoted(Pivoting::Partial);
+    let (pa, lu) = (lup.p * a, lup.l * lup.u);
+    assert!(lup.u[0][0] == -10.0);
+    assert!(lup.parity == 1);
+    assert!(lup.u[1][1] == -7.8);
+    assert!((lup.det() - det).abs() < 1e-9);
+    assert!((a.lup_det().unwrap() - det).abs() < 1e-9);
+    for i in 0..3 { for j in 0..3 { assert!((pa[i][j] - lu[i][j]).abs() < 1e-12); assert!(j > i || lup.l[i][j].abs() <= 1.0); } }
+    let full = a.lup_pivoted(Pivoting::Full);
+    let (paq, lu) = (full.p * a * full.q, full.l * full.u);
+    for i in 0..3 { for j in 0..3 { assert!((paq[i][j] - lu[i][j]).abs() < 1e-12); } }
+    assert!((full.det() - det).abs() < 1e-9);
+    assert!(full.growth >= 1.0);
+    assert!(full.min_pivot > 1.0);
+    let x = Vector::new([1.0, 2.0, -1.0]);
+    let (s, t) = (full.solve(a * x).unwrap(), a.lup_sub(a * x).unwrap());
+    for i in 0..3 { assert!((s[i] - x[i]).abs() < 1e-12); assert!((t[i] - x[i]).abs() < 1e-12); }
+    //A zero in the top left no longer makes the decomposition fail, and singular matrices report a zero pivot.
+    let b = Matrix::<f64, 2, 2>::new([[0.0, 1.0], [1.0, 0.0]]);
+    assert!(b.lup().is_some());
+    assert!(b.lup_det() == Some(-1.0));
+    assert!(b.lup_inverse() == Some(b));
+    let c = Matrix::<f64, 3, 3>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+    let lup = c.lup_pivoted(Pivoting::Full);
+    assert!(c.lup().is_none());
+    assert!(lup.is_singular());
+    assert!(lup.det() == 0.0);
+    assert!(lup.solve(x).is_none());
+    //A nearly singular matrix has a tiny smallest pivot relative to its entries.
+    let d = Matrix::<f64, 2, 2>::new([[1.0, 1.0], [1.0, 1.0 + 1e-10]]).lup_pivoted(Pivoting::Partial);
+    assert!(!d.is_singular());
+    assert!(d.min_pivot < 1e-9);
+    assert!(d.growth <= 1.0);
+    //Exact rational LUP gives the exact determinant and inverse.
+    let r: Matrix<r32, 3, 3> = Matrix::<f32, 3, 3>::new([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]).into();
+    assert!(r.lup_det() == Some(r32::new_raw(4, 1)));
+    assert!(r.lup_pivoted(Pivoting::Full).det() == r32::new_raw(4, 1));
+    assert!(r * r.lup_inverse().unwrap() == Matrix::identity());
+}
+#[test]
+fn lup_scale_test() {
+    use crate::geom::matrix::{Matrix, Pivoting};
+    use crate::geom::vector::Vector;
+    //A well conditioned matrix with small entries is not singular - only an exactly zero pivot is.
+    let a = Matrix::<f32, 3, 3>::new([[2e-6, 1e-6, 0.0], [1e-6, 3e-6, 1e-6], [0.0, 1e-6, 4e-6]]);
+    let x = Vector::new([1.0, -2.0, 0.5]);
+    let s = a.lup_sub(a * x).unwrap();
+    assert!(a.lup().is_some());
+    assert!(!a.lup_pivoted(Pivoting::Full).is_singular());
+    for i in 0..3 { assert!((s[i] - x[i]).abs() < 1e-4); }
+    //The determinant scales with the cube of the entries, so it is no longer used to reject the inverse.
+    let c = a * 1e-3;
+    let (ai, ci) = (a.lup_inverse().unwrap(), c.lup_inverse().unwrap());
+    let (ia, ic) = (a * ai, c * ci);
+    for i in 0..3 { for j in 0..3 {
+        let e = if i == j { 1.0 } else { 0.0 };
+        assert!((ia[i][j] - e).abs() < 1e-4);
+        assert!((ic[i][j] - e).abs() < 1e-4);
+    } }
+    let (c, l) = (a.cholesky_solve(a * x).unwrap(), a.ldlt_solve(a * x).unwrap());
+    for i in 0..3 { assert!((c[i] - x[i]).abs() < 1e-4); assert!((l[i] - x[i]).abs() < 1e-4); }
+    //A singular matrix with large entries is still caught.
+    let b = Matrix::<f32, 2, 2>::new([[1e6, 2e6], [2e6, 4e6]]);
+    assert!(b.lup().is_none());
+    assert!(b.lup_sub(Vector::new([1.0, 2.0])).is_none());
+    assert!(b.lup_inverse().is_none());
+    assert!(b.cholesky().is_err());
+    assert!(b.ldlt().is_err());
+    //Badly scaled but invertible matrices are left for the caller to judge through the pivot diagnostics.
+    let trs = Matrix::<f32, 4, 4>::new([[0.01, 0.0, 0.0, 1000.0], [0.0, 0.01, 0.0, 0.0], [0.0, 0.0, 0.01, 0.0], [0.0, 0.0, 0.0, 1.0]]);
+    assert!(trs.lup().is_some());
+    assert!(trs.lup_sub(Vector::new([1.0, 0.0, 0.0, 1.0])).is_some());
+    assert!(trs.lup_inverse().unwrap()[0][3] == -100000.0);
+    let d = Matrix::<f32, 2, 2>::new([[1.0, 0.0], [0.0, 1e-5]]);
+    assert!(d.lup_inverse().unwrap()[1][1] == 1e5);
+    assert!(d.lup_pivoted(Pivoting::Partial).min_pivot == 1e-5);
+    assert!(d.cholesky().is_ok());
+    assert!(d.ldlt().is_ok());
+}
+#[test]
+fn dynamic_matrix_test() {
+    use crate::geom::dmatrix::{DMatrix, DimensionError};
+    use crate::geom::dvector::DVector;
+    use crate::geom::matrix::Matrix;
+    use crate::geom::vector::Vector;
+    use crate::num::rational::r32;
+    //A path graph Laplacian plus the identity, with its size only known at runtime.
+    let n = 5;
+    let mut a = DMatrix::<f64>::identity(n);
+    for i in 0..n - 1 {
+        a[i][i] += 1.0; a[i+1][i+1] += 1.0;
+        a[i][i+1] -= 1.0; a[i+1][i] -= 1.0;
+    }
+    let x = DVector::new((0..n).map(|i| i as f64 - 1.5).collect());
+    let b = a.clone() * x.clone();
+    let (s, inv) = (a.lup_sub(b.clone()).unwrap(), a.inverse().unwrap());
+    assert!((s - x.clone()).sq_sum() < 1e-20);
+    assert!(((inv.clone() * b) - x).sq_sum() < 1e-20);
+    let ai = a.clone() * inv;
+    assert!((0..n).all(|i| (0..n).all(|j| (ai[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12)));
+    let (l, u) = a.lu().unwrap();
+    let (l2, u2, p) = a.lup().unwrap();
+    assert!((l.clone() * u.clone() - a.clone()).as_slice().iter().all(|v| v.abs() < 1e-12));
+    assert!((p * a.clone() - l2 * u2).as_slice().iter().all(|v| v.abs() < 1e-12));
+    //Agrees with the fixed size matrix, and converts both ways with checked dimensions.
+    let m = Matrix::<f64, 3, 3>::new([[0.0, 2.0, 1.0], [-4.0, 1.0, 3.0], [2.0, 5.0, -2.0]]);
+    let d = DMatrix::from(m);
+    assert!((d.det().unwrap() - m.det().unwrap()).abs() < 1e-12);
+    assert!(d.lu().is_none());
+    assert!(d.lup().is_some());
+    assert!(Matrix::<f64, 3, 3>::try_from(d.clone()) == Ok(m));
+    assert!(d[1] == [-4.0, 1.0, 3.0]);
+    assert!(d.transpose().col(0) == d.row(0));
+    assert!(Matrix::<f64, 3, 2>::try_from(d.clone()) == Err(DimensionError::Shape { expected: (3, 2), found: (3, 3) }));
+    assert!(Vector::<f64, 3>::try_from(DVector::from(Vector::new([1.0, 2.0, 3.0]))) == Ok(Vector::new([1.0, 2.0, 3.0])));
+    assert!(Vector::<f64, 2>::try_from(DVector::new(vec![1.0])) == Err(DimensionError::Length { expected: 2, found: 1 }));
+    assert!(DMatrix::new(2, 2, vec![1.0; 3]) == Err(DimensionError::Length { expected: 4, found: 3 }));
+    assert!(d.multiply(&DMatrix::zeros(2, 3)).is_none());
+    assert!(DMatrix::from_rows(vec![vec![1.0, 2.0], vec![3.0]]) == Err(DimensionError::RaggedRow { row: 1, expected: 2, found: 1 }));
+    //Small entries don't make a well conditioned matrix singular.
+    let small = DMatrix::from(Matrix::<f32, 3, 3>::new([[2e-6, 1e-6, 0.0], [1e-6, 3e-6, 1e-6], [0.0, 1e-6, 4e-6]]));
+    assert!(small.lup().is_some());
+    assert!(small.inverse().is_some());
+    //Nor does a badly scaled one - only an exactly zero pivot is rejected.
+    let scaled = DMatrix::from(Matrix::<f32, 2, 2>::new([[1.0, 0.0], [0.0, 1e-5]]));
+    assert!(scaled.inverse().map(|i| i[1][1]) == Some(1e5));
+    let singular = DMatrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    assert!(singular.inverse().is_none());
+    assert!(singular.det() == Some(0.0));
+    assert!(DMatrix::<f64>::zeros(2, 3).det().is_none());
+    //Exact rational determinant and inverse.
+    let r: DMatrix<r32> = DMatrix::from(Matrix::<r32, 3, 3>::from(Matrix::<f32, 3, 3>::new([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]])));
+    assert!(r.det() == Some(r32::new_raw(4, 1)));
+    assert!(r.clone() * r.inverse().unwrap() == DMatrix::identity(3));
+}
+#[test]
+fn sparse_solver_test() {
+    use crate::geom::sparse::{CooMatrix, CsrMatrix};
+    use crate::geom::dvector::DVector;
+    //Assemble a 2D Poisson matrix on a 30x30 grid edge by edge, so diagonal entries are pushed several times.
+    let n = 30;
+    let mut coo = CooMatrix::<f64>::new(n * n, n * n);
+    for i in 0..n * n { coo.push(i, i, 0.01); }
+    for y in 0..n {
+        for x in 0..n {
+            let i = y * n + x;
+            for j in [(x + 1 < n).then(|| i + 1), (y + 1 < n).then(|| i + n)].into_iter().flatten() {
+                coo.push(i, i, 1.0); coo.push(j, j, 1.0);
+                coo.push(i, j, -1.0); coo.push(j, i, -1.0);
+            }
+        }
+    }
+    let a = coo.to_csr();
+    assert!(a.nnz() == n * n + 4 * n * (n - 1));
+    assert!(a.get(0, 0) == 2.01);
+    assert!(a.get(n + 1, n + 1) == 4.01);
+    assert!(a.get(0, 5) == 0.0);
+    assert!(a.transpose() == a);
+    assert!(a.to_csc().to_csr() == a);
+    assert!(coo.to_csc().get(1, 0) == -1.0);
+    assert!(coo.len() > a.nnz());
+    let x = DVector::new((0..n * n).map(|i| ((i * 7) % 11) as f64 - 5.0).collect());
+    let b = &a * &x;
+    let cg = a.conjugate_gradient(&b, 1e-10, 2000);
+    assert!(cg.converged);
+    assert!(cg.residual <= 1e-10);
+    assert!((cg.x - x.clone()).sq_sum() < 1e-8);
+    let gs = a.gauss_seidel(&b, 1e-6, 20);
+    assert!(!gs.converged);
+    assert!(gs.iterations == 20);
+    assert!(gs.residual > 1e-6);
+    //A non-symmetric convection-diffusion matrix for BiCGSTAB and Gauss-Seidel, checked against the dense solve.
+    let m = 50;
+    let mut coo = CooMatrix::<f64>::new(m, m);
+    for i in 0..m {
+        coo.push(i, i, 4.0);
+        if i > 0 { coo.push(i, i - 1, -1.5); }
+        if i + 1 < m { coo.push(i, i + 1, -0.5); }
+    }
+    let (c, csc) = (coo.to_csr(), coo.to_csc());
+    let y = DVector::new((0..m).map(|i| (i as f64).sin()).collect());
+    let b = &c * &y;
+    assert!((&csc * &y - b.clone()).sq_sum() < 1e-24);
+    assert!(c.transpose().get(0, 1) == -1.5);
+    assert!(csc.transpose().get(1, 0) == -0.5);
+    let (bi, gs) = (c.bicgstab(&b, 1e-12, 200), c.gauss_seidel(&b, 1e-12, 200));
+    let dense = c.to_dense().lup_sub(b.clone()).unwrap();
+    assert!(bi.converged);
+    assert!(gs.converged);
+    assert!(bi.iterations < 50);
+    assert!((bi.x - dense.clone()).sq_sum() < 1e-18);
+    assert!((gs.x - dense).sq_sum() < 1e-18);
+    assert!(CsrMatrix::from(&csc.to_dense()) == c);
+    assert!(c.to_dense() == csc.to_dense());
+    assert!(c.diagonal() == DVector::new(vec![4.0; m]));
+    let zero = c.conjugate_gradient(&DVector::zeros(m), 1e-12, 10);
+    assert!(zero.converged);
+    assert!(zero.iterations == 0);
+    assert!(zero.x == DVector::zeros(m));
+}
+#[test]
+fn matrix_function_test() {
+    use crate::geom::matrix::Matrix;
+    use crate::geom::vector::Vector;
+    use crate::geom::quaternion::Quaternion;
+    use crate::geom::transforms::transform_mat;
+    fn close<const L: usize>(a: Matrix<f64, L, L>, b: Matrix<f64, L, L>, tol: f64) -> bool {
+        (0..L).all(|i| (0..L).all(|j| (a[i][j] - b[i][j]).abs() < tol))
+    }
+    //General path - a nilpotent and a diagonalisable matrix with known exponentials.
+    let n = Matrix::<f64, 2, 2>::new([[0.0, 1.0], [0.0, 0.0]]);
+    assert!(close(n.exp(), Matrix::new([[1.0, 1.0], [0.0, 1.0]]), 1e-14));
+    let d = Matrix::<f64, 2, 2>::new([[3.0, 0.0], [0.0, -2.0]]);
+    assert!(close(d.exp(), Matrix::new([[3.0_f64.exp(), 0.0], [0.0, (-2.0_f64).exp()]]), 1e-12));
+    let a = Matrix::<f64, 3, 3>::new([[0.5, 1.0, -0.3], [0.2, -0.4, 0.8], [1.1, 0.0, 0.3]]);
+    assert!(close(a.exp().log().unwrap(), a, 1e-10));
+    assert!(close(a.exp() * (a * -1.0).exp(), Matrix::identity(), 1e-12));
+    let s = Matrix::<f64, 3, 3>::new([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+    let r = s.sqrt().unwrap();
+    assert!(close(r * r, s, 1e-12));
+    assert!(close(s.log().unwrap().exp(), s, 1e-10));
+    assert!(close(r, r.transpose(), 1e-12));
+    assert!((Matrix::<f64, 2, 2>::identity() * -1.0).sqrt().is_none());
+    assert!(Matrix::<f64, 2, 2>::default().log().is_none());
+    //Rotation fast paths - the log is the axis times the angle, and the square root halves the angle.
+    let axis = Vector::new([1.0, 2.0, 2.0]) / 3.0;
+    let rot = Quaternion::<f64>::from_axis_angle(axis, 1.2).quat_to_matrix();
+    let log = rot.log().unwrap();
+    assert!((log[2][1] - 0.4).abs() < 1e-12);
+    assert!((log[0][2] - 0.8).abs() < 1e-12);
+    assert!(close(log.exp(), rot, 1e-12));
+    let half = Quaternion::<f64>::from_axis_angle(axis, 0.6).quat_to_matrix();
+    assert!(close(rot.sqrt().unwrap(), half, 1e-12));
+    let flip = Quaternion::<f64>::from_axis_angle(axis, std::f64::consts::PI).quat_to_matrix();
+    assert!(close(flip.log().unwrap().exp(), flip, 1e-12));
+    assert!(close(Matrix::<f64, 3, 3>::identity().log().unwrap(), Matrix::default(), 1e-15));
+    //Rigid transforms - interpolating halfway with exp(log(T)/2) equals the square root, and squares back to T.
+    let t = transform_mat::from_trs(Vector::new([1.0, -2.0, 0.5]), Quaternion::from_axis_angle(axis, 2.5), Vector::new([1.0; 3]));
+    let (log, root) = (t.log().unwrap(), t.sqrt().unwrap());
+    assert!(close(log.exp(), t, 1e-12));
+    assert!(close(root * root, t, 1e-12));
+    assert!(close((log * 0.5).exp(), root, 1e-12));
+    //A scaled transform is not rigid, so takes the general path.
+    let ts = transform_mat::from_trs(Vector::new([1.0, 0.0, 0.0]), Quaternion::from_axis_angle(axis, 0.7), Vector::new([2.0, 1.0, 0.5]));
+    let (log, root) = (ts.log().unwrap(), ts.sqrt().unwrap());
+    assert!(close(log.exp(), ts, 1e-9));
+    assert!(close(root * root, ts, 1e-10));
+    let tf = transform_mat::from_trs(Vector::new([0.5, 0.2, 0.1]), crate::Quaternion::from_axis_angle(crate::Vector3::new([0.0, 0.0, 1.0]), 0.5), crate::Vector3::new([1.0; 3]));
+    let back = tf.log().unwrap().exp();
+    assert!((0..4).all(|i| (0..4).all(|j| (back[i][j] - tf[i][j]).abs() < 1e-5)));
+}
+#[test]
+fn matrix_operator_test() {
+    use crate::geom::matrix::Matrix;
+    use crate::geom::vector::Vector;
+    use crate::num::rational::r32;
+    let a = Matrix::<f64, 2, 3>::new([[1.0, -2.0, 3.0], [4.0, 5.0, -6.0]]);
+    let b = Matrix::<f64, 2, 3>::new([[0.5, 1.0, 0.0], [2.0, -1.0, 1.0]]);
+    let sum = Matrix::new([[1.5, -1.0, 3.0], [6.0, 4.0, -5.0]]);
+    assert!(a + b == sum);
+    assert!(&a + b == sum);
+    assert!(a + &b == sum);
+    assert!(&a + &b == sum);
+    assert!(sum - b == a);
+    assert!(&sum - &b == a);
+    assert!(-a == a * -1.0);
+    assert!(-&a == &a * &-1.0);
+    assert!(a / 2.0 == 0.5 * a);
+    assert!(&a / &2.0 == &0.5 * &a);
+    assert!((a + a) == 2.0 * &a);
+    let (mut c, sq) = (a, Matrix::<f64, 3, 3>::new([[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]]));
+    c += b; c -= &a; assert!(c == b);
+    c *= 2.0; c /= &4.0; c *= &sq;
+    assert!(c == b * 0.5 * sq);
+    assert!(&a * &sq == a * sq);
+    {
+        let mut r = &mut c;
+        r += &b; r -= b; r *= &2.0;
+    }
+    assert!(c == b * sq);
+    //Element-wise functions.
+    assert!(a.hadamard(&b) == Matrix::new([[0.5, -2.0, 0.0], [8.0, -5.0, -6.0]]));
+    assert!(a.map(|x| x.abs()).fold(0.0, |s, x| s + x) == 21.0);
+    assert!(a.zip_map(&b, |x, y| x.max(y)) == Matrix::new([[1.0, 1.0, 3.0], [4.0, 5.0, 1.0]]));
+    assert!(a.map(|x| x as i32)[1][2] == -6);
+    //Norms and trace.
+    assert!(a.norm_1() == 9.0);
+    assert!(a.norm_inf() == 15.0);
+    assert!((a.frobenius_norm() - 91.0_f64.sqrt()).abs() < 1e-12);
+    let s = Matrix::<f64, 3, 3>::new([[2.0, 1.0, 0.0], [1.0, 3.0, 0.0], [0.0, 0.0, 4.0]]);
+    assert!(s.trace() == 9.0);
+    assert!((s.trace() - s.eigenvalues().unwrap().sum()).abs() < 1e-12);
+    //Kronecker product, swaps and iterators.
+    let k: Matrix<f64, 4, 6> = Matrix::<f64, 2, 2>::identity().kronecker(&a);
+    assert!(k[0][0] == 1.0);
+    assert!(k[3][5] == -6.0);
+    assert!(k[0][3] == 0.0);
+    assert!(k[2][3] == 1.0);
+    let k2: Matrix<f64, 2, 6> = Matrix::<f64, 1, 2>::new([[1.0, -1.0]]).kronecker(&a);
+    assert!(k2.row(0) == Vector::new([1.0, -2.0, 3.0, -1.0, 2.0, -3.0]));
+    let mut m = a;
+    m.swap_rows(0, 1); m.swap_cols(0, 2);
+    assert!(m == Matrix::new([[-6.0, 5.0, 4.0], [3.0, -2.0, 1.0]]));
+    assert!(m.rows().count() == 2);
+    assert!(a.cols().map(|c| c.sum()).collect::<Vec<_>>() == vec![5.0, 3.0, -3.0]);
+    assert!(a.rows().map(|r| r.sum()).sum::<f64>() == 5.0);
+    //The same operators work exactly on MatrixPrecise.
+    let p: Matrix<r32, 2, 3> = a.to_f32().into();
+    let q: Matrix<r32, 2, 3> = b.to_f32().into();
+    assert!(p + q - q == p);
+    assert!((p - p) == Matrix::default());
+    assert!(p.norm_inf() == r32::new_raw(15, 1));
+    assert!(-(-p) == p);
+    assert!(p.hadamard(&q)[0][0] == r32::new_raw(1, 2));
+    assert!((p / r32::new_raw(2, 1))[0][0] == r32::new_raw(1, 2));
+}